@@ -0,0 +1,847 @@
+//! Every widget of the crate with knobs for their builders.
+//!
+//! The content scrolls above a [`FloatingPanel`] and a [`BottomSheet`]. An [`AnchoredOverlay`]
+//! and a [`Debounced`] are building blocks for the authors of widgets, so they are not shown on
+//! their own: the menus of the pick lists are anchored overlays.
+//!
+//! Run it with `cargo run --example gallery`, and add `--features qrcode` to encode a real QR
+//! code.
+//!
+//! [`AnchoredOverlay`]: iced_ext::AnchoredOverlay
+//! [`Debounced`]: iced_ext::Debounced
+use std::fmt;
+
+use iced::alignment;
+use iced::time::{Duration, Instant};
+use iced::widget::{button, column, container, image, pick_list, row, rule, scrollable, slider, text, toggler};
+use iced::{Element, Length, Point, Rectangle, Size, Theme};
+
+use iced_ext::bottom_sheet::Snap;
+use iced_ext::card_input::Card;
+use iced_ext::floating_panel::Placement;
+use iced_ext::grid_picker::Cell;
+use iced_ext::hold_button::Indicator;
+use iced_ext::keypad;
+use iced_ext::measurement_input::Unit;
+use iced_ext::on_screen_keyboard::{self, Keymap};
+use iced_ext::phone_input::PhoneNumber;
+use iced_ext::progress_bar_ext::{self, Scale, StyleFn, TextPosition};
+use iced_ext::qr_code::Matrix;
+use iced_ext::schedule_builder::Schedule;
+use iced_ext::signature_pad::{Signature, Stroke};
+use iced_ext::tree_table::{Column, Node};
+use iced_ext::week_planner::WeekPlan;
+use iced_ext::{
+    AnimatedList, BottomSheet, CardInput, Cropper, FloatingPanel, FontPicker, GridPicker, HoldButton, Keypad, Marquee,
+    MeasurementInput, MultiPickList, OnScreenKeyboard, PhoneInput, ProgressBar, QrCode, ScheduleBuilder, SignaturePad,
+    SliderBank, SquareRadio, TreeTable, VirtualList, WeekPlanner,
+};
+
+pub fn main() -> iced::Result {
+    iced::application(Gallery::default, Gallery::update, Gallery::view)
+        .title("iced_ext gallery")
+        .theme(Gallery::theme)
+        .run()
+}
+
+struct Gallery {
+    theme: Theme,
+    letter: Option<Letter>,
+    fruits: Vec<Fruit>,
+    pin_selected: bool,
+    menu_backdrop: bool,
+    menu_columns: u8,
+    progress: f32,
+    alignment: Align,
+    percentage: bool,
+    reversed: bool,
+    log_scale: bool,
+    thresholds: bool,
+    text_position: Position,
+    paused: bool,
+    errored: bool,
+    countdown: Instant,
+    countdowns: u32,
+    settings: Vec<bool>,
+    font: Option<&'static str>,
+    photo: image::Handle,
+    crop: Rectangle,
+    square_crop: bool,
+    signature: Signature,
+    seats: Vec<Cell>,
+    week: WeekPlan,
+    sheet: Snap,
+    panel: Placement,
+    show_panel: bool,
+    holds: u32,
+    hold_bar: bool,
+    items: Vec<u32>,
+    next_item: u32,
+    qr_code: Matrix,
+    number: String,
+    entered_number: String,
+    typed: String,
+    entered_text: String,
+    files: Vec<Node<File>>,
+    levels: Vec<f32>,
+    linked: bool,
+    schedule: Schedule,
+    weight: f32,
+    weight_unit: Unit,
+    phone: PhoneNumber,
+    card: Card,
+}
+
+impl Default for Gallery {
+    fn default() -> Self {
+        Self {
+            theme: Theme::Dark,
+            letter: Some(Letter::A),
+            fruits: vec![Fruit::Apple],
+            pin_selected: false,
+            menu_backdrop: false,
+            menu_columns: 1,
+            progress: 40.0,
+            alignment: Align::Center,
+            percentage: true,
+            reversed: false,
+            log_scale: false,
+            thresholds: false,
+            text_position: Position::Inside,
+            paused: false,
+            errored: false,
+            countdown: Instant::now(),
+            countdowns: 0,
+            settings: vec![false; 500],
+            font: None,
+            photo: photo(),
+            crop: Rectangle::new(Point::new(80.0, 50.0), Size::new(320.0, 200.0)),
+            square_crop: false,
+            signature: Signature::new(),
+            seats: vec![Cell { row: 1, column: 2 }],
+            week: WeekPlan::new(),
+            sheet: Snap::Closed,
+            panel: Placement::new((240.0, 160.0)),
+            show_panel: false,
+            holds: 0,
+            hold_bar: false,
+            items: vec![1, 2, 3],
+            next_item: 4,
+            qr_code: qr_code(),
+            number: String::new(),
+            entered_number: String::new(),
+            typed: String::new(),
+            entered_text: String::new(),
+            files: files(),
+            levels: vec![0.5; 5],
+            linked: false,
+            schedule: Schedule::default(),
+            weight: 70.0,
+            weight_unit: Unit::Kilograms,
+            phone: PhoneNumber::default(),
+            card: Card::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ThemeChanged(Theme),
+    Letter(Letter),
+    Fruit(Fruit, bool),
+    ClearFruits,
+    PinSelected(bool),
+    MenuBackdrop(bool),
+    MenuColumns(u8),
+    Progress(f32),
+    Alignment(Align),
+    Percentage(bool),
+    Reversed(bool),
+    LogScale(bool),
+    Thresholds(bool),
+    TextPosition(Position),
+    Paused(bool),
+    Errored(bool),
+    RestartCountdown,
+    CountdownComplete,
+    Setting(usize),
+    Font(&'static str),
+    Cropped(Rectangle),
+    SquareCrop(bool),
+    Stroke(Stroke),
+    UndoStroke,
+    ClearSignature,
+    Seats(Vec<Cell>, bool),
+    Week(WeekPlan),
+    Sheet(Snap),
+    Panel(Placement),
+    ShowPanel(bool),
+    Held,
+    HoldBar(bool),
+    AddItem,
+    RemoveItem(u32),
+    Keypad(keypad::Key),
+    Keyboard(on_screen_keyboard::Input),
+    Level(usize, f32),
+    Linked(bool),
+    Schedule(Schedule),
+    Weight(f32, Unit),
+    Phone(PhoneNumber),
+    Card(Card),
+}
+
+impl Gallery {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ThemeChanged(theme) => self.theme = theme,
+            Message::Letter(letter) => self.letter = Some(letter),
+            Message::Fruit(fruit, true) => self.fruits.push(fruit),
+            Message::Fruit(fruit, false) => self.fruits.retain(|selected| *selected != fruit),
+            Message::ClearFruits => self.fruits.clear(),
+            Message::PinSelected(pin_selected) => self.pin_selected = pin_selected,
+            Message::MenuBackdrop(menu_backdrop) => self.menu_backdrop = menu_backdrop,
+            Message::MenuColumns(menu_columns) => self.menu_columns = menu_columns,
+            Message::Progress(progress) => self.progress = progress,
+            Message::Alignment(alignment) => self.alignment = alignment,
+            Message::Percentage(percentage) => self.percentage = percentage,
+            Message::Reversed(reversed) => self.reversed = reversed,
+            Message::LogScale(log_scale) => self.log_scale = log_scale,
+            Message::Thresholds(thresholds) => self.thresholds = thresholds,
+            Message::TextPosition(text_position) => self.text_position = text_position,
+            Message::Paused(paused) => self.paused = paused,
+            Message::Errored(errored) => self.errored = errored,
+            Message::RestartCountdown => self.countdown = Instant::now(),
+            Message::CountdownComplete => self.countdowns += 1,
+            Message::Setting(index) => {
+                if let Some(setting) = self.settings.get_mut(index) {
+                    *setting = !*setting;
+                }
+            }
+            Message::Font(font) => self.font = Some(font),
+            Message::Cropped(crop) => self.crop = crop,
+            Message::SquareCrop(square_crop) => self.square_crop = square_crop,
+            Message::Stroke(stroke) => self.signature.push(stroke),
+            Message::UndoStroke => {
+                let _ = self.signature.undo();
+            }
+            Message::ClearSignature => self.signature.clear(),
+            Message::Seats(cells, true) => {
+                self.seats.extend(cells);
+                self.seats.sort();
+                self.seats.dedup();
+            }
+            Message::Seats(cells, false) => self.seats.retain(|seat| !cells.contains(seat)),
+            Message::Week(week) => self.week = week,
+            Message::Sheet(sheet) => self.sheet = sheet,
+            Message::Panel(panel) => self.panel = panel,
+            Message::ShowPanel(show_panel) => self.show_panel = show_panel,
+            Message::Held => self.holds += 1,
+            Message::HoldBar(hold_bar) => self.hold_bar = hold_bar,
+            Message::AddItem => {
+                self.items.push(self.next_item);
+                self.next_item += 1;
+            }
+            Message::RemoveItem(item) => self.items.retain(|other| *other != item),
+            Message::Keypad(key) => {
+                if key.apply(&mut self.number) {
+                    self.entered_number = std::mem::take(&mut self.number);
+                }
+            }
+            Message::Keyboard(input) => {
+                if input.apply(&mut self.typed) {
+                    self.entered_text = std::mem::take(&mut self.typed);
+                }
+            }
+            Message::Level(index, level) => {
+                if let Some(slot) = self.levels.get_mut(index) {
+                    *slot = level;
+                }
+            }
+            Message::Linked(linked) => self.linked = linked,
+            Message::Schedule(schedule) => self.schedule = schedule,
+            Message::Weight(weight, unit) => {
+                self.weight = weight;
+                self.weight_unit = unit;
+            }
+            Message::Phone(phone) => self.phone = phone,
+            Message::Card(card) => self.card = card,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let theme = row![
+            text("Theme"),
+            pick_list(Theme::ALL, Some(&self.theme), Message::ThemeChanged)
+        ]
+        .spacing(10);
+
+        let square_radio = column![
+            text("Square Radio").size(20),
+            row(Letter::ALL.map(|letter| {
+                Element::from(SquareRadio::new(letter, self.letter, Message::Letter).label(letter.to_string()))
+            }))
+            .spacing(20),
+        ]
+        .spacing(10);
+
+        let multi_pick_list = column![
+            text("Multi Pick List").size(20),
+            MultiPickList::new(&Fruit::ALL[..], &self.fruits[..], |fruit| Message::Fruit(fruit, true))
+                .on_toggle(Message::Fruit)
+                .placeholder("Pick some fruits...")
+                .width(250)
+                .pin_selected(self.pin_selected)
+                .menu_backdrop(self.menu_backdrop)
+                .menu_columns(usize::from(self.menu_columns))
+                .option_badge(|fruit| fruit.is_seasonal().then(|| String::from("new")))
+                .empty_text("No fruits")
+                .menu_footer(
+                    button("Clear selection")
+                        .on_press(Message::ClearFruits)
+                        .width(Length::Fill)
+                ),
+            toggler(self.pin_selected)
+                .label("Pin selected")
+                .on_toggle(Message::PinSelected),
+            toggler(self.menu_backdrop)
+                .label("Menu backdrop")
+                .on_toggle(Message::MenuBackdrop),
+            row![
+                text(format!("Columns: {}", self.menu_columns)),
+                slider(1..=3, self.menu_columns, Message::MenuColumns).width(150),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10);
+
+        let thresholds: Vec<(f32, StyleFn<'_, Theme>)> = if self.thresholds {
+            vec![
+                (75.0, Box::new(progress_bar_ext::warning)),
+                (90.0, Box::new(progress_bar_ext::danger)),
+            ]
+        } else {
+            Vec::new()
+        };
+
+        let progress_bar = column![
+            text("Progress Bar Ext").size(20),
+            ProgressBar::new(0.0..=100.0, self.progress)
+                .alignment(self.alignment.into())
+                .percentage(self.percentage)
+                .reversed(self.reversed)
+                .scale(if self.log_scale { Scale::Log } else { Scale::Linear })
+                .thresholds(thresholds)
+                .text_position(self.text_position.into())
+                .paused(self.paused)
+                .errored(self.errored),
+            slider(0.0..=100.0, self.progress, Message::Progress),
+            row![
+                pick_list(Align::ALL, Some(self.alignment), Message::Alignment),
+                pick_list(Position::ALL, Some(self.text_position), Message::TextPosition),
+                toggler(self.percentage)
+                    .label("Percentage")
+                    .on_toggle(Message::Percentage),
+            ]
+            .spacing(10),
+            row![
+                toggler(self.reversed).label("Reversed").on_toggle(Message::Reversed),
+                toggler(self.log_scale)
+                    .label("Logarithmic")
+                    .on_toggle(Message::LogScale),
+                toggler(self.thresholds)
+                    .label("Thresholds")
+                    .on_toggle(Message::Thresholds),
+            ]
+            .spacing(10),
+            row![
+                toggler(self.paused).label("Paused").on_toggle(Message::Paused),
+                toggler(self.errored).label("Errored").on_toggle(Message::Errored),
+            ]
+            .spacing(10),
+            ProgressBar::new(0.0..=1.0, 0.0)
+                .countdown(Duration::from_secs(5))
+                .started_at(self.countdown)
+                .on_complete(Message::CountdownComplete),
+            row![
+                button("Restart countdown").on_press(Message::RestartCountdown),
+                text(format!("Completed {} times", self.countdowns)),
+            ]
+            .spacing(10)
+            .align_y(alignment::Vertical::Center),
+        ]
+        .spacing(10);
+
+        let virtual_list = column![
+            text("Virtual List").size(20),
+            container(scrollable(VirtualList::new(self.settings.len(), 24, |index| {
+                SquareRadio::new(true, Some(self.settings[index]), move |_| Message::Setting(index))
+                    .label(format!("Setting #{index}"))
+                    .into()
+            })))
+            .height(200),
+        ]
+        .spacing(10);
+
+        let font_picker = column![
+            text("Font Picker").size(20),
+            FontPicker::new(&FONTS, self.font, Message::Font).placeholder("Pick a font..."),
+        ]
+        .spacing(10);
+
+        let cropper = column![
+            text("Cropper").size(20),
+            Cropper::new(self.photo.clone(), self.crop, Message::Cropped).aspect_ratio(self.square_crop.then_some(1.0)),
+            text(format!(
+                "{:.0} x {:.0} at ({:.0}, {:.0})",
+                self.crop.width, self.crop.height, self.crop.x, self.crop.y
+            )),
+            toggler(self.square_crop).label("Square").on_toggle(Message::SquareCrop),
+        ]
+        .spacing(10);
+
+        let signature_pad = column![
+            text("Signature Pad").size(20),
+            container(SignaturePad::new(&self.signature, Message::Stroke)).height(150),
+            row![
+                button("Undo").on_press(Message::UndoStroke),
+                button("Clear").on_press(Message::ClearSignature),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10);
+
+        let grid_picker = column![
+            text("Grid Picker").size(20),
+            GridPicker::new(5, 8, &self.seats, Message::Seats)
+                .row_labels(["A", "B", "C", "D", "E"])
+                .disabled(|cell| (cell.row + cell.column) % 7 == 0)
+                .legend("Available", "Selected", "Taken"),
+            text(format!("{} seats selected", self.seats.len())),
+        ]
+        .spacing(10);
+
+        let week_planner = column![
+            text("Week Planner").size(20),
+            WeekPlanner::new(&self.week, Message::Week).snap(15).hour_height(12),
+        ]
+        .spacing(10);
+
+        let bottom_sheet = column![
+            text("Bottom Sheet").size(20),
+            row![
+                button("Peek").on_press(Message::Sheet(Snap::Peek)),
+                button("Half").on_press(Message::Sheet(Snap::Half)),
+                button("Full").on_press(Message::Sheet(Snap::Full)),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10);
+
+        let floating_panel = column![
+            text("Floating Panel").size(20),
+            toggler(self.show_panel)
+                .label("Show panel")
+                .on_toggle(Message::ShowPanel),
+        ]
+        .spacing(10);
+
+        let hold_button = column![
+            text("Hold Button").size(20),
+            HoldButton::new(text("Hold to confirm"), Message::Held)
+                .duration(Duration::from_secs(1))
+                .indicator(if self.hold_bar { Indicator::Bar } else { Indicator::Ring }),
+            text(format!("Confirmed {} times", self.holds)),
+            toggler(self.hold_bar)
+                .label("Bar indicator")
+                .on_toggle(Message::HoldBar),
+        ]
+        .spacing(10);
+
+        let animated_list = column![
+            text("Animated List").size(20),
+            AnimatedList::with_children(self.items.iter().map(|&item| {
+                let child = row![
+                    text(format!("Item #{item}")).width(Length::Fill),
+                    button("Remove").on_press(Message::RemoveItem(item)),
+                ]
+                .align_y(alignment::Vertical::Center);
+
+                (item, Element::from(child))
+            }))
+            .spacing(5),
+            button("Add item").on_press(Message::AddItem),
+        ]
+        .spacing(10);
+
+        let marquee = column![
+            text("Marquee").size(20),
+            container(Marquee::new("Breaking news: a headline too long for its box scrolls by itself").speed(40.0))
+                .width(250),
+        ]
+        .spacing(10);
+
+        let qr_code = column![text("QR Code").size(20), QrCode::new(&self.qr_code).module_size(6),].spacing(10);
+
+        let keypad = column![
+            text("Keypad").size(20),
+            text(format!("Number: {}", self.number)),
+            Keypad::new(Message::Keypad),
+            text(format!("Entered: {}", self.entered_number)),
+        ]
+        .spacing(10);
+
+        let on_screen_keyboard = column![
+            text("On Screen Keyboard").size(20),
+            text(format!("Text: {}", self.typed)),
+            OnScreenKeyboard::new(Message::Keyboard).keymap(Keymap::qwerty()),
+            text(format!("Entered: {}", self.entered_text)),
+        ]
+        .spacing(10);
+
+        let tree_table = column![
+            text("Tree Table").size(20),
+            TreeTable::new(&self.files)
+                .column(
+                    Column::new("Name", |file: &File| file.name.to_owned())
+                        .sortable(|a: &File, b: &File| a.name.cmp(b.name))
+                        .width(Length::Fill),
+                )
+                .column(
+                    Column::new("Size", |file: &File| format!("{} KB", file.size))
+                        .sortable(|a: &File, b: &File| a.size.cmp(&b.size))
+                        .width(100)
+                        .align_x(alignment::Horizontal::Right),
+                ),
+        ]
+        .spacing(10);
+
+        let slider_bank = column![
+            text("Slider Bank").size(20),
+            SliderBank::new(0.0..=1.0, &self.levels, Message::Level)
+                .labels(["60", "250", "1k", "4k", "16k"])
+                .step(0.05)
+                .linked(if self.linked { 0.5 } else { 0.0 }),
+            toggler(self.linked).label("Linked").on_toggle(Message::Linked),
+        ]
+        .spacing(10);
+
+        let schedule_builder = column![
+            text("Schedule Builder").size(20),
+            ScheduleBuilder::new(&self.schedule, Message::Schedule),
+            text(self.schedule.summary()),
+        ]
+        .spacing(10);
+
+        let measurement_input = column![
+            text("Measurement Input").size(20),
+            MeasurementInput::new(self.weight, self.weight_unit, Message::Weight),
+        ]
+        .spacing(10);
+
+        let phone_input = column![
+            text("Phone Input").size(20),
+            PhoneInput::new(&self.phone, Message::Phone),
+            text(format!("E.164: {}", self.phone.e164())),
+        ]
+        .spacing(10);
+
+        let card_input = column![
+            text("Card Input").size(20),
+            CardInput::new(&self.card, Message::Card),
+            text(format!("Valid: {}", self.card.is_valid())),
+        ]
+        .spacing(10);
+
+        let content = column![
+            theme,
+            rule::horizontal(1),
+            square_radio,
+            rule::horizontal(1),
+            multi_pick_list,
+            rule::horizontal(1),
+            progress_bar,
+            rule::horizontal(1),
+            virtual_list,
+            rule::horizontal(1),
+            font_picker,
+            rule::horizontal(1),
+            cropper,
+            rule::horizontal(1),
+            signature_pad,
+            rule::horizontal(1),
+            grid_picker,
+            rule::horizontal(1),
+            week_planner,
+            rule::horizontal(1),
+            bottom_sheet,
+            rule::horizontal(1),
+            floating_panel,
+            rule::horizontal(1),
+            hold_button,
+            rule::horizontal(1),
+            animated_list,
+            rule::horizontal(1),
+            marquee,
+            rule::horizontal(1),
+            qr_code,
+            rule::horizontal(1),
+            keypad,
+            rule::horizontal(1),
+            on_screen_keyboard,
+            rule::horizontal(1),
+            tree_table,
+            rule::horizontal(1),
+            slider_bank,
+            rule::horizontal(1),
+            schedule_builder,
+            rule::horizontal(1),
+            measurement_input,
+            rule::horizontal(1),
+            phone_input,
+            rule::horizontal(1),
+            card_input,
+        ]
+        .spacing(20)
+        .padding(20)
+        .max_width(600);
+
+        let gallery = Element::from(scrollable(container(content).center_x(Length::Fill)));
+
+        let gallery = if self.show_panel {
+            let panel = column![
+                text("Drag the title bar to move the panel, and its corner to resize it."),
+                button("Close").on_press(Message::ShowPanel(false)),
+            ]
+            .spacing(10);
+
+            FloatingPanel::new(gallery, "Floating Panel", panel, self.panel, Message::Panel).into()
+        } else {
+            gallery
+        };
+
+        let sheet = column![
+            text("Bottom Sheet").size(20),
+            text("Drag the handle to move the sheet between its snap points."),
+            button("Close").on_press(Message::Sheet(Snap::Closed)),
+        ]
+        .spacing(10)
+        .padding(20);
+
+        BottomSheet::new(gallery, sheet, self.sheet, Message::Sheet)
+            .peek_height(80)
+            .into()
+    }
+
+    fn theme(&self) -> Theme {
+        self.theme.clone()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Letter {
+    A,
+    B,
+    C,
+}
+
+impl Letter {
+    const ALL: [Letter; 3] = [Letter::A, Letter::B, Letter::C];
+}
+
+impl fmt::Display for Letter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = match self {
+            Letter::A => "A",
+            Letter::B => "B",
+            Letter::C => "C",
+        };
+
+        f.write_str(letter)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fruit {
+    Apple,
+    Banana,
+    Cherry,
+    Grape,
+    Mango,
+    Orange,
+    Peach,
+    Pear,
+}
+
+impl Fruit {
+    const ALL: [Fruit; 8] = [
+        Fruit::Apple,
+        Fruit::Banana,
+        Fruit::Cherry,
+        Fruit::Grape,
+        Fruit::Mango,
+        Fruit::Orange,
+        Fruit::Peach,
+        Fruit::Pear,
+    ];
+
+    fn is_seasonal(self) -> bool {
+        matches!(self, Fruit::Cherry | Fruit::Peach)
+    }
+}
+
+impl fmt::Display for Fruit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fruit = match self {
+            Fruit::Apple => "Apple",
+            Fruit::Banana => "Banana",
+            Fruit::Cherry => "Cherry",
+            Fruit::Grape => "Grape",
+            Fruit::Mango => "Mango",
+            Fruit::Orange => "Orange",
+            Fruit::Peach => "Peach",
+            Fruit::Pear => "Pear",
+        };
+
+        f.write_str(fruit)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+impl Align {
+    const ALL: [Align; 3] = [Align::Left, Align::Center, Align::Right];
+}
+
+impl From<Align> for alignment::Horizontal {
+    fn from(align: Align) -> Self {
+        match align {
+            Align::Left => alignment::Horizontal::Left,
+            Align::Center => alignment::Horizontal::Center,
+            Align::Right => alignment::Horizontal::Right,
+        }
+    }
+}
+
+impl fmt::Display for Align {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let align = match self {
+            Align::Left => "Left",
+            Align::Center => "Center",
+            Align::Right => "Right",
+        };
+
+        f.write_str(align)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Inside,
+    Above,
+    Below,
+    Trailing,
+}
+
+impl Position {
+    const ALL: [Position; 4] = [Position::Inside, Position::Above, Position::Below, Position::Trailing];
+}
+
+impl From<Position> for TextPosition {
+    fn from(position: Position) -> Self {
+        match position {
+            Position::Inside => TextPosition::Inside,
+            Position::Above => TextPosition::Above,
+            Position::Below => TextPosition::Below,
+            Position::Trailing => TextPosition::Trailing,
+        }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let position = match self {
+            Position::Inside => "Inside",
+            Position::Above => "Above",
+            Position::Below => "Below",
+            Position::Trailing => "Trailing",
+        };
+
+        f.write_str(position)
+    }
+}
+
+const FONTS: [&str; 6] = [
+    "Fira Sans",
+    "Inter",
+    "JetBrains Mono",
+    "Noto Serif",
+    "Roboto",
+    "Source Code Pro",
+];
+
+struct File {
+    name: &'static str,
+    size: u64,
+}
+
+fn files() -> Vec<Node<File>> {
+    let file = |name, size| Node::new(File { name, size });
+    let folder = |name, children: Vec<Node<File>>| Node::with_children(File { name, size: 0 }, children);
+
+    vec![
+        folder(
+            "src",
+            vec![
+                file("lib.rs", 4),
+                file("main.rs", 2),
+                folder("widget", vec![file("button.rs", 12)]),
+            ],
+        ),
+        folder("assets", vec![file("icon.png", 48), file("logo.svg", 6)]),
+        file("Cargo.toml", 1),
+        file("README.md", 3),
+    ]
+}
+
+fn photo() -> image::Handle {
+    const WIDTH: u32 = 480;
+    const HEIGHT: u32 = 300;
+
+    let pixels = (0..HEIGHT)
+        .flat_map(|y| (0..WIDTH).map(move |x| (x, y)))
+        .flat_map(|(x, y)| [(x * 255 / WIDTH) as u8, (y * 255 / HEIGHT) as u8, 160, 255])
+        .collect::<Vec<u8>>();
+
+    image::Handle::from_rgba(WIDTH, HEIGHT, pixels)
+}
+
+fn qr_code() -> Matrix {
+    #[cfg(feature = "qrcode")]
+    if let Ok(matrix) = Matrix::encode("https://github.com/ljsoph/iced-ext") {
+        return matrix;
+    }
+
+    // Without the `qrcode` feature, only the finder patterns in three of the corners are drawn.
+    const WIDTH: usize = 21;
+
+    let is_finder = |x: usize, y: usize| {
+        let is_edge = x == 0 || x == 6 || y == 0 || y == 6;
+        let is_center = (2..=4).contains(&x) && (2..=4).contains(&y);
+
+        x < 7 && y < 7 && (is_edge || is_center)
+    };
+
+    Matrix::new(
+        WIDTH,
+        (0..WIDTH * WIDTH).map(|index| {
+            let (x, y) = (index % WIDTH, index / WIDTH);
+
+            is_finder(x, y) || is_finder(WIDTH - 1 - x, y) || is_finder(x, WIDTH - 1 - y)
+        }),
+    )
+}
@@ -0,0 +1,249 @@
+//! Anchor an overlay to a target, like the dropdown of a pick list or a tooltip.
+//!
+//! [`space`] finds the side of a target with the most room, and [`position`] places content of
+//! a given size on that side, moved back inside the viewport if it would overflow it. An
+//! [`AnchoredOverlay`] does both for any [`Element`], so widgets only build their content in
+//! [`Widget::overlay`].
+//!
+//! [`Widget::overlay`]: iced_core::Widget::overlay
+use iced_core::Clipboard;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Vector;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::touch;
+use iced_core::widget::Operation;
+use iced_core::widget::Tree;
+
+/// The default space kept between an [`AnchoredOverlay`] and the top and bottom edges of the
+/// viewport.
+pub const DEFAULT_MARGIN: f32 = 8.0;
+
+/// The priority of an overlay, ordering the overlays of the crate open at the same time.
+///
+/// Overlays with a higher priority are drawn above the others, so a tooltip never hides under
+/// the menu it belongs to, and a toast stays above everything else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Priority {
+    /// A sheet or a panel floating above the content, like a [`BottomSheet`] or a
+    /// [`FloatingPanel`], at the default index of the overlays of iced.
+    ///
+    /// [`BottomSheet`]: crate::BottomSheet
+    /// [`FloatingPanel`]: crate::FloatingPanel
+    Panel,
+    /// A menu opened from a target, like the one of a [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: crate::MultiPickList
+    #[default]
+    Menu,
+    /// A tooltip describing what is under the cursor.
+    Tooltip,
+    /// A toast, above every other overlay.
+    Toast,
+}
+
+impl Priority {
+    /// Returns the z-index of an overlay with the [`Priority`].
+    pub const fn index(self) -> f32 {
+        match self {
+            Priority::Panel => 1.0,
+            Priority::Menu => 2.0,
+            Priority::Tooltip => 3.0,
+            Priority::Toast => f32::MAX,
+        }
+    }
+}
+
+/// Where an overlay is placed relative to its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    /// The overlay is below its target.
+    Below,
+    /// The overlay is above its target.
+    Above,
+}
+
+/// Returns the side of the given target with the most room in the viewport, preferring above
+/// when both have as much, and the largest [`Size`] an overlay can take there.
+///
+/// The overlay keeps the margin from the top and bottom edges of the viewport, and can take
+/// its whole width.
+pub fn space(target: Rectangle, viewport: Size, margin: f32) -> (Placement, Size) {
+    let below = (viewport.height - (target.y + target.height) - margin).max(0.0);
+    let above = (target.y - margin).max(0.0);
+
+    if below > above {
+        (Placement::Below, Size::new(viewport.width, below))
+    } else {
+        (Placement::Above, Size::new(viewport.width, above))
+    }
+}
+
+/// Returns the position of an overlay of the given [`Size`] on the given side of its target.
+///
+/// The overlay is aligned with the left edge of its target, and moved left when it would
+/// overflow the right edge of the viewport.
+pub fn position(target: Rectangle, size: Size, placement: Placement, viewport: Size) -> Point {
+    let x = target.x.min(viewport.width - size.width).max(0.0);
+
+    let y = match placement {
+        Placement::Below => target.y + target.height,
+        Placement::Above => target.y - size.height,
+    };
+
+    Point::new(x, y)
+}
+
+/// An overlay showing an [`Element`] next to a target, above or below it depending on the room
+/// left in the viewport.
+pub struct AnchoredOverlay<'a, 'b, Message, Theme, Renderer> {
+    content: &'a mut Element<'b, Message, Theme, Renderer>,
+    tree: &'a mut Tree,
+    target: Rectangle,
+    margin: f32,
+    priority: Priority,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> AnchoredOverlay<'a, 'b, Message, Theme, Renderer> {
+    /// Creates a new [`AnchoredOverlay`] showing the given content, with its [`Tree`], next to
+    /// the given target in absolute coordinates.
+    ///
+    /// The target is usually the bounds of the owner, moved by the translation given to
+    /// [`Widget::overlay`].
+    ///
+    /// [`Widget::overlay`]: iced_core::Widget::overlay
+    pub fn new(content: &'a mut Element<'b, Message, Theme, Renderer>, tree: &'a mut Tree, target: Rectangle) -> Self {
+        Self {
+            content,
+            tree,
+            target,
+            margin: DEFAULT_MARGIN,
+            priority: Priority::default(),
+        }
+    }
+
+    /// Sets the space kept between the [`AnchoredOverlay`] and the top and bottom edges of the
+    /// viewport.
+    pub fn margin(mut self, margin: impl Into<Pixels>) -> Self {
+        self.margin = margin.into().0;
+        self
+    }
+
+    /// Sets the [`Priority`] of the [`AnchoredOverlay`] among the other overlays of the window.
+    ///
+    /// By default, it is [`Priority::Menu`].
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> iced_core::Overlay<Message, Theme, Renderer>
+    for AnchoredOverlay<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let (placement, space) = space(self.target, bounds, self.margin);
+        let limits = layout::Limits::new(Size::ZERO, space);
+
+        let node = self.content.as_widget_mut().layout(self.tree, renderer, &limits);
+        let size = node.size();
+
+        node.move_to(position(self.target, size, placement, bounds))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        self.content
+            .as_widget_mut()
+            .update(self.tree, event, layout, cursor, renderer, clipboard, shell, &bounds);
+
+        // Presses and scrolls over the overlay must not reach the widgets below it.
+        let is_pointer_event = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_) | mouse::Event::WheelScrolled { .. })
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+
+        if is_pointer_event && cursor.is_over(bounds) {
+            shell.capture_event();
+        }
+    }
+
+    fn mouse_interaction(&self, layout: Layout<'_>, cursor: mouse::Cursor, renderer: &Renderer) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(self.tree, layout, cursor, &layout.bounds(), renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+
+        renderer.with_layer(bounds, |renderer| {
+            self.content
+                .as_widget()
+                .draw(self.tree, renderer, theme, defaults, layout, cursor, &bounds);
+        });
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        self.content
+            .as_widget_mut()
+            .operate(self.tree, layout, renderer, operation);
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let bounds = layout.bounds();
+
+        self.content
+            .as_widget_mut()
+            .overlay(self.tree, layout, renderer, &bounds, Vector::ZERO)
+    }
+
+    fn index(&self) -> f32 {
+        self.priority.index()
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> From<AnchoredOverlay<'a, 'b, Message, Theme, Renderer>>
+    for overlay::Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+    'b: 'a,
+{
+    fn from(anchored_overlay: AnchoredOverlay<'a, 'b, Message, Theme, Renderer>) -> Self {
+        overlay::Element::new(Box::new(anchored_overlay))
+    }
+}
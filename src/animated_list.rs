@@ -0,0 +1,525 @@
+//! Animate the rows of a list as they come and go.
+//!
+//! An [`AnimatedList`] lays out keyed rows in a column, and compares their keys with the ones of
+//! the previous view. New rows unfold and slide in, removed rows leave a gap that collapses, and
+//! rows that changed places glide from where they were, instead of the whole list jumping.
+use iced_core::Clipboard;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::time::Duration;
+use iced_core::time::Instant;
+use iced_core::widget::Operation;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+use iced_core::window;
+
+/// How far a new row slides in from, horizontally.
+const SLIDE_DISTANCE: f32 = 24.0;
+
+/// A column of keyed rows animating their insertions, removals and moves.
+///
+/// The key of a row identifies it across views, so it must be unique within the list.
+pub struct AnimatedList<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+{
+    keys: Vec<Key>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    width: Length,
+    spacing: f32,
+    duration: Duration,
+}
+
+impl<'a, Key, Message, Theme, Renderer> AnimatedList<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: renderer::Renderer,
+{
+    /// The default duration of the animations of an [`AnimatedList`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+    /// Creates an empty [`AnimatedList`].
+    pub fn new() -> Self {
+        Self::with_children(std::iter::empty())
+    }
+
+    /// Creates a new [`AnimatedList`] with the given rows and their keys.
+    pub fn with_children(children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>) -> Self {
+        let (keys, children) = children.into_iter().unzip();
+
+        Self {
+            keys,
+            children,
+            width: Length::Fill,
+            spacing: 0.0,
+            duration: Self::DEFAULT_DURATION,
+        }
+    }
+
+    /// Adds a row with the given key at the end of the [`AnimatedList`].
+    pub fn push(mut self, key: Key, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.keys.push(key);
+        self.children.push(child.into());
+        self
+    }
+
+    /// Sets the width of the [`AnimatedList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the vertical space between the rows of the [`AnimatedList`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the duration of the animations of the [`AnimatedList`].
+    ///
+    /// A zero duration disables them.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Default for AnimatedList<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: renderer::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The progress of an animation, started on the first frame after it was scheduled.
+#[derive(Debug, Clone, Copy, Default)]
+struct Animation {
+    started: Option<Instant>,
+    progress: f32,
+}
+
+impl Animation {
+    /// Advances the animation to the given instant, returning whether it is still running.
+    fn advance(&mut self, now: Instant, duration: Duration) -> bool {
+        let started = *self.started.get_or_insert(now);
+
+        self.progress = if duration.is_zero() {
+            1.0
+        } else {
+            (now.saturating_duration_since(started).as_secs_f32() / duration.as_secs_f32()).min(1.0)
+        };
+
+        self.progress < 1.0
+    }
+
+    /// Returns the progress of the animation, easing out.
+    fn eased(&self) -> f32 {
+        1.0 - (1.0 - self.progress).powi(3)
+    }
+}
+
+/// A row that changed places, gliding from where it was.
+#[derive(Debug, Clone, Copy)]
+struct Move<Key> {
+    key: Key,
+    from: f32,
+    /// The distance to the new place of the row, known once it has been laid out.
+    offset: Option<f32>,
+    animation: Animation,
+}
+
+/// The gap left by a removed row, collapsing after the row that preceded it.
+#[derive(Debug, Clone, Copy)]
+struct Gap<Key> {
+    after: Option<Key>,
+    height: f32,
+    animation: Animation,
+}
+
+/// The top and height of a row in the last layout.
+#[derive(Debug, Clone, Copy)]
+struct Row {
+    y: f32,
+    height: f32,
+}
+
+struct State<Key> {
+    keys: Vec<Key>,
+    rows: Vec<Row>,
+    entering: Vec<(Key, Animation)>,
+    moving: Vec<Move<Key>>,
+    gaps: Vec<Gap<Key>>,
+}
+
+impl<Key> State<Key>
+where
+    Key: Copy + PartialEq,
+{
+    fn is_animating(&self) -> bool {
+        !self.entering.is_empty() || !self.moving.is_empty() || !self.gaps.is_empty()
+    }
+
+    /// Returns how much of the row with the given key is revealed, from `0.0` to `1.0`.
+    fn reveal(&self, key: Key) -> f32 {
+        self.entering
+            .iter()
+            .find(|(entering, _)| *entering == key)
+            .map_or(1.0, |(_, animation)| animation.eased())
+    }
+
+    /// Returns the vertical distance between the row with the given key and its new place.
+    fn offset(&self, key: Key) -> f32 {
+        self.moving
+            .iter()
+            .find(|moving| moving.key == key)
+            .and_then(|moving| Some(moving.offset? * (1.0 - moving.animation.eased())))
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the height of the gaps collapsing after the row with the given key.
+    fn gaps_after(&self, key: Option<Key>) -> f32 {
+        self.gaps
+            .iter()
+            .filter(|gap| gap.after == key)
+            .map(|gap| gap.height * (1.0 - gap.animation.eased()))
+            .sum()
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AnimatedList<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            keys: self.keys.clone(),
+            rows: Vec::new(),
+            entering: Vec::new(),
+            moving: Vec::new(),
+            gaps: Vec::new(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(|child| Tree::new(child.as_widget())).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let Tree { state, children, .. } = tree;
+        let state = state.downcast_mut::<State<Key>>();
+
+        // The trees follow their keys, so rows keep their state when they move.
+        let mut previous: Vec<Option<Tree>> = std::mem::take(children).into_iter().map(Some).collect();
+
+        *children = self
+            .keys
+            .iter()
+            .zip(&self.children)
+            .map(|(key, child)| {
+                let reused = state
+                    .keys
+                    .iter()
+                    .position(|previous| previous == key)
+                    .and_then(|index| previous.get_mut(index))
+                    .and_then(Option::take);
+
+                match reused {
+                    Some(mut tree) => {
+                        tree.diff(child.as_widget());
+                        tree
+                    }
+                    None => Tree::new(child.as_widget()),
+                }
+            })
+            .collect();
+
+        if state.keys == self.keys || self.duration.is_zero() {
+            state.keys.clone_from(&self.keys);
+            return;
+        }
+
+        for (index, key) in state.keys.iter().enumerate() {
+            if self.keys.contains(key) {
+                continue;
+            }
+
+            let after = state.keys[..index]
+                .iter()
+                .rev()
+                .find(|previous| self.keys.contains(previous))
+                .copied();
+
+            for gap in &mut state.gaps {
+                if gap.after == Some(*key) {
+                    gap.after = after;
+                }
+            }
+
+            if let Some(row) = state.rows.get(index) {
+                state.gaps.push(Gap {
+                    after,
+                    height: row.height + self.spacing,
+                    animation: Animation::default(),
+                });
+            }
+        }
+
+        // Only the rows whose order changed among the rows that stayed are moving; the others
+        // follow the gaps and new rows around them.
+        let kept = state.keys.iter().filter(|key| self.keys.contains(key));
+        let order = self.keys.iter().filter(|key| state.keys.contains(key));
+
+        for (key, _) in kept.zip(order).filter(|(kept, order)| kept != order) {
+            let position = state.keys.iter().position(|previous| previous == key);
+
+            if let Some(row) = position.and_then(|position| state.rows.get(position)) {
+                let from = row.y + state.offset(*key);
+
+                state.moving.retain(|moving| moving.key != *key);
+                state.moving.push(Move {
+                    key: *key,
+                    from,
+                    offset: None,
+                    animation: Animation::default(),
+                });
+            }
+        }
+
+        for key in &self.keys {
+            if !state.keys.contains(key) {
+                state.entering.push((*key, Animation::default()));
+            }
+        }
+
+        state.entering.retain(|(key, _)| self.keys.contains(key));
+        state.moving.retain(|moving| self.keys.contains(&moving.key));
+        state.keys.clone_from(&self.keys);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let Tree { state, children, .. } = tree;
+        let state = state.downcast_mut::<State<Key>>();
+
+        let limits = limits.width(self.width);
+        let child_limits = layout::Limits::new(Size::ZERO, Size::new(limits.max().width, f32::INFINITY));
+
+        let mut y = state.gaps_after(None);
+        let mut intrinsic_width: f32 = 0.0;
+
+        state.rows.clear();
+
+        let nodes = self
+            .keys
+            .iter()
+            .zip(&mut self.children)
+            .zip(children)
+            .enumerate()
+            .map(|(index, ((key, child), tree))| {
+                let node = child.as_widget_mut().layout(tree, renderer, &child_limits);
+                let size = node.size();
+                let reveal = state.reveal(*key);
+
+                if let Some(moving) = state.moving.iter_mut().find(|moving| moving.key == *key)
+                    && moving.offset.is_none()
+                {
+                    moving.offset = Some(moving.from - y);
+                }
+
+                state.rows.push(Row { y, height: size.height });
+                intrinsic_width = intrinsic_width.max(size.width);
+
+                let node = node.move_to((0.0, y));
+                let spacing = if index + 1 < self.keys.len() { self.spacing } else { 0.0 };
+
+                y += (size.height + spacing) * reveal + state.gaps_after(Some(*key));
+
+                node
+            })
+            .collect();
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(intrinsic_width, y));
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let Tree { state, children, .. } = tree;
+        let state = state.downcast_mut::<State<Key>>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && state.is_animating()
+        {
+            let duration = self.duration;
+
+            state
+                .entering
+                .retain_mut(|(_, animation)| animation.advance(*now, duration));
+            state
+                .moving
+                .retain_mut(|moving| moving.animation.advance(*now, duration));
+            state.gaps.retain_mut(|gap| gap.animation.advance(*now, duration));
+
+            shell.invalidate_layout();
+
+            if state.is_animating() {
+                shell.request_redraw();
+            }
+        }
+
+        for ((child, tree), layout) in self.children.iter_mut().zip(children).zip(layout.children()) {
+            child
+                .as_widget_mut()
+                .update(tree, event, layout, cursor, renderer, clipboard, shell, viewport);
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+
+        for (((key, child), tree), layout) in self
+            .keys
+            .iter()
+            .zip(&self.children)
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            let bounds = layout.bounds();
+            let reveal = state.reveal(*key);
+            let offset = state.offset(*key);
+
+            if reveal >= 1.0 && offset == 0.0 {
+                if bounds.intersects(viewport) {
+                    child
+                        .as_widget()
+                        .draw(tree, renderer, theme, style, layout, cursor, viewport);
+                }
+
+                continue;
+            }
+
+            let translation = Vector::new((1.0 - reveal) * SLIDE_DISTANCE, offset);
+            let draw = |renderer: &mut Renderer| {
+                renderer.with_translation(translation, |renderer| {
+                    child
+                        .as_widget()
+                        .draw(tree, renderer, theme, style, layout, cursor, viewport);
+                });
+            };
+
+            // A new row unfolds from its top, hiding what does not fit yet.
+            if reveal < 1.0 {
+                let clip_bounds = Rectangle {
+                    height: bounds.height * reveal,
+                    ..bounds
+                };
+
+                if let Some(clip_bounds) = clip_bounds.intersection(viewport) {
+                    renderer.with_layer(clip_bounds, draw);
+                }
+            } else {
+                draw(renderer);
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((child, tree), layout) in self.children.iter_mut().zip(&mut tree.children).zip(layout.children()) {
+                child.as_widget_mut().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<AnimatedList<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(list: AnimatedList<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
@@ -0,0 +1,792 @@
+//! Slide a sheet of content up from the bottom of the window.
+//!
+//! A [`BottomSheet`] wraps the content below it and shows the sheet in an overlay, resting at
+//! one of its [`Snap`] points. Dragging the handle of the sheet resizes it, and releasing it
+//! settles on the closest point, or dismisses the sheet when flung downwards. The sheet only
+//! moves to the point it settles on once the application passes back the published [`Snap`].
+use iced_core::Background;
+use iced_core::Border;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shadow;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Theme;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::border;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::time::Duration;
+use iced_core::time::Instant;
+use iced_core::touch;
+use iced_core::widget::Operation;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+use iced_core::window;
+
+use crate::anchored_overlay::Priority;
+
+/// A point a [`BottomSheet`] rests at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Snap {
+    /// The sheet is hidden.
+    #[default]
+    Closed,
+    /// The sheet shows its top, at the peek height of the [`BottomSheet`].
+    Peek,
+    /// The sheet covers half of the window.
+    Half,
+    /// The sheet covers the window, except for a small margin at the top.
+    Full,
+}
+
+impl Snap {
+    /// Every [`Snap`], from the lowest to the highest.
+    pub const ALL: [Snap; 4] = [Snap::Closed, Snap::Peek, Snap::Half, Snap::Full];
+}
+
+/// A sheet of content sliding up from the bottom of the window, above the content below it.
+pub struct BottomSheet<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    base: Element<'a, Message, Theme, Renderer>,
+    sheet: Element<'a, Message, Theme, Renderer>,
+    snap: Snap,
+    on_snap: Box<dyn Fn(Snap) -> Message + 'a>,
+    peek_height: f32,
+    animation: Duration,
+    backdrop: bool,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> BottomSheet<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// The default height of a [`BottomSheet`] at [`Snap::Peek`].
+    pub const DEFAULT_PEEK_HEIGHT: f32 = 120.0;
+
+    /// The default duration of the animation of a [`BottomSheet`] settling on a [`Snap`].
+    pub const DEFAULT_ANIMATION: Duration = Duration::from_millis(250);
+
+    /// Creates a new [`BottomSheet`] showing the sheet above the base content at the given
+    /// [`Snap`], and the message to produce with the one it settles on.
+    pub fn new(
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        sheet: impl Into<Element<'a, Message, Theme, Renderer>>,
+        snap: Snap,
+        on_snap: impl Fn(Snap) -> Message + 'a,
+    ) -> Self {
+        Self {
+            base: base.into(),
+            sheet: sheet.into(),
+            snap,
+            on_snap: Box::new(on_snap),
+            peek_height: Self::DEFAULT_PEEK_HEIGHT,
+            animation: Self::DEFAULT_ANIMATION,
+            backdrop: true,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the height of the sheet at [`Snap::Peek`].
+    pub fn peek_height(mut self, peek_height: impl Into<Pixels>) -> Self {
+        self.peek_height = peek_height.into().0;
+        self
+    }
+
+    /// Sets the duration of the animation of the sheet settling on a [`Snap`].
+    ///
+    /// A zero duration moves the sheet at once.
+    pub fn animation(mut self, animation: Duration) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// Sets whether a backdrop dims the content below the sheet while it is shown.
+    ///
+    /// Clicking the backdrop closes the sheet without reaching the content below it.
+    pub fn backdrop(mut self, backdrop: bool) -> Self {
+        self.backdrop = backdrop;
+        self
+    }
+
+    /// Sets the style of the [`BottomSheet`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`BottomSheet`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// Returns the height of the sheet at the given [`Snap`] in a window of the given height.
+fn height(snap: Snap, peek_height: f32, window_height: f32) -> f32 {
+    let full = (window_height - FULL_MARGIN).max(0.0);
+
+    match snap {
+        Snap::Closed => 0.0,
+        Snap::Peek => peek_height.min(full),
+        Snap::Half => (window_height / 2.0).min(full),
+        Snap::Full => full,
+    }
+}
+
+/// The height of the strip at the top of the sheet holding its handle.
+const HANDLE_AREA: f32 = 24.0;
+
+/// The size of the handle drawn at the top of the sheet.
+const HANDLE_SIZE: Size = Size::new(36.0, 4.0);
+
+/// The space left above the sheet at [`Snap::Full`].
+const FULL_MARGIN: f32 = 48.0;
+
+/// The downward speed of a released drag dismissing the sheet, in pixels per second.
+const FLING_VELOCITY: f32 = 1200.0;
+
+/// How long the cursor has to rest before a release stops counting as a fling.
+const FLING_TIMEOUT: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Default)]
+struct State {
+    snap: Snap,
+    height: f32,
+    window: Size,
+    animation: Option<Animation>,
+    drag: Option<Drag>,
+}
+
+/// The sheet moving from a height to the one of its [`Snap`].
+#[derive(Debug, Clone, Copy)]
+struct Animation {
+    from: f32,
+    started: Option<Instant>,
+}
+
+/// The handle of the sheet being dragged.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// The distance between the cursor and the top of the sheet.
+    grab: f32,
+    /// The last position of the cursor and when it got there.
+    last: (f32, Instant),
+    /// The vertical speed of the cursor, in pixels per second, positive downwards.
+    velocity: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for BottomSheet<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.base), Tree::new(&self.sheet)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.base, &self.sheet]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.base.as_widget().size()
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.base
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.base.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.base
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.base
+            .as_widget()
+            .draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        self.base
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree { state, children, .. } = tree;
+        let state = state.downcast_mut::<State>();
+        let (base, sheet) = children.split_at_mut(1);
+
+        // The sheet moves from wherever it is whenever the application picks another snap.
+        if state.snap != self.snap {
+            state.snap = self.snap;
+            state.animation = (!self.animation.is_zero()).then_some(Animation {
+                from: state.height,
+                started: None,
+            });
+        }
+
+        if state.animation.is_none() && state.drag.is_none() {
+            state.height = height(self.snap, self.peek_height, state.window.height);
+        }
+
+        let is_shown = self.snap != Snap::Closed || state.height > 0.0 || state.animation.is_some();
+
+        if !is_shown {
+            return self
+                .base
+                .as_widget_mut()
+                .overlay(&mut base[0], layout, renderer, viewport, translation);
+        }
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            state,
+            sheet: &mut self.sheet,
+            tree: &mut sheet[0],
+            snap: self.snap,
+            on_snap: self.on_snap.as_ref(),
+            peek_height: self.peek_height,
+            animation: self.animation,
+            backdrop: self.backdrop,
+            class: &self.class,
+        })))
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    state: &'a mut State,
+    sheet: &'a mut Element<'b, Message, Theme, Renderer>,
+    tree: &'a mut Tree,
+    snap: Snap,
+    on_snap: &'a dyn Fn(Snap) -> Message,
+    peek_height: f32,
+    animation: Duration,
+    backdrop: bool,
+    class: &'a Theme::Class<'b>,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Returns the bounds of the sheet in the window.
+    fn bounds(&self) -> Rectangle {
+        let window = self.state.window;
+
+        Rectangle {
+            x: 0.0,
+            y: window.height - self.state.height,
+            width: window.width,
+            height: self.state.height,
+        }
+    }
+
+    /// Returns the bounds of the strip holding the handle of the sheet.
+    fn handle_bounds(&self) -> Rectangle {
+        Rectangle {
+            height: HANDLE_AREA.min(self.state.height),
+            ..self.bounds()
+        }
+    }
+
+    /// Returns the [`Snap`] a drag released at the current height settles on.
+    fn settle(&self, velocity: f32) -> Snap {
+        let window_height = self.state.window.height;
+        let current = self.state.height;
+
+        if velocity > FLING_VELOCITY {
+            return Snap::Closed;
+        }
+
+        if velocity < -FLING_VELOCITY {
+            return Snap::ALL
+                .into_iter()
+                .find(|snap| height(*snap, self.peek_height, window_height) > current)
+                .unwrap_or(Snap::Full);
+        }
+
+        Snap::ALL
+            .into_iter()
+            .min_by(|a, b| {
+                let distance = |snap| (height(snap, self.peek_height, window_height) - current).abs();
+
+                distance(*a).total_cmp(&distance(*b))
+            })
+            .unwrap_or(self.snap)
+    }
+
+    /// Starts dragging the handle or dismisses the sheet from its backdrop, returning whether
+    /// the press was handled.
+    fn press(&mut self, position: Point, shell: &mut Shell<'_, Message>) -> bool {
+        let bounds = self.bounds();
+
+        if self.handle_bounds().contains(position) {
+            self.state.drag = Some(Drag {
+                grab: position.y - bounds.y,
+                last: (position.y, Instant::now()),
+                velocity: 0.0,
+            });
+            self.state.animation = None;
+            shell.capture_event();
+
+            return true;
+        }
+
+        if self.backdrop && !bounds.contains(position) {
+            // The backdrop swallows the press, so the content below the sheet is left alone.
+            if self.snap != Snap::Closed {
+                shell.publish((self.on_snap)(Snap::Closed));
+            }
+
+            shell.capture_event();
+
+            return true;
+        }
+
+        false
+    }
+
+    fn drag_to(&mut self, y: f32) {
+        let Some(drag) = &mut self.state.drag else {
+            return;
+        };
+
+        let now = Instant::now();
+        let elapsed = now.saturating_duration_since(drag.last.1).as_secs_f32();
+
+        if elapsed > 0.0 {
+            drag.velocity = (y - drag.last.0) / elapsed;
+        }
+
+        drag.last = (y, now);
+
+        let full = height(Snap::Full, self.peek_height, self.state.window.height);
+        self.state.height = (self.state.window.height - (y - drag.grab)).clamp(0.0, full);
+    }
+
+    fn release(&mut self, shell: &mut Shell<'_, Message>) {
+        let Some(drag) = self.state.drag.take() else {
+            return;
+        };
+
+        // A cursor that stopped before the release does not fling the sheet.
+        let velocity = if drag.last.1.elapsed() > FLING_TIMEOUT {
+            0.0
+        } else {
+            drag.velocity
+        };
+
+        let snap = self.settle(velocity);
+
+        if snap != self.snap {
+            shell.publish((self.on_snap)(snap));
+        }
+
+        // The sheet settles on the current snap until the application picks the new one.
+        self.state.animation = (!self.animation.is_zero()).then_some(Animation {
+            from: self.state.height,
+            started: None,
+        });
+        shell.request_redraw();
+    }
+}
+
+impl<Message, Theme, Renderer> iced_core::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.state.window = bounds;
+
+        if self.state.animation.is_none() && self.state.drag.is_none() {
+            self.state.height = height(self.snap, self.peek_height, bounds.height);
+        }
+
+        let sheet = self.bounds();
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(sheet.width, (sheet.height - HANDLE_AREA).max(0.0)),
+        );
+
+        let content = self
+            .sheet
+            .as_widget_mut()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(sheet.x, sheet.y + HANDLE_AREA));
+
+        layout::Node::with_children(bounds, vec![content])
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = self.bounds();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(animation) = &mut self.state.animation {
+                    let started = *animation.started.get_or_insert(*now);
+                    let progress = now.saturating_duration_since(started).as_secs_f32() / self.animation.as_secs_f32();
+
+                    // Eases out cubically, so the sheet slows down as it settles.
+                    let eased = 1.0 - (1.0 - progress.min(1.0)).powi(3);
+                    let target = height(self.snap, self.peek_height, self.state.window.height);
+
+                    self.state.height = animation.from + (target - animation.from) * eased;
+
+                    if progress < 1.0 {
+                        shell.request_redraw();
+                    } else {
+                        self.state.animation = None;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position()
+                    && self.press(position, shell)
+                {
+                    return;
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+                if self.press(*position, shell) {
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.state.drag.is_some() =>
+            {
+                self.drag_to(position.y);
+                shell.capture_event();
+                shell.request_redraw();
+                return;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if self.state.drag.is_some() =>
+            {
+                self.release(shell);
+                shell.capture_event();
+                return;
+            }
+            _ => {}
+        }
+
+        if let Some(content_layout) = layout.children().next() {
+            self.sheet.as_widget_mut().update(
+                self.tree,
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                &bounds,
+            );
+        }
+
+        // Presses and scrolls over the sheet must not reach the content below it.
+        let is_pointer_event = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_) | mouse::Event::WheelScrolled { .. })
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+
+        if is_pointer_event && cursor.is_over(bounds) {
+            shell.capture_event();
+        }
+    }
+
+    fn mouse_interaction(&self, layout: Layout<'_>, cursor: mouse::Cursor, renderer: &Renderer) -> mouse::Interaction {
+        if self.state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if cursor.is_over(self.handle_bounds()) {
+            return mouse::Interaction::Grab;
+        }
+
+        layout
+            .children()
+            .next()
+            .map_or_else(mouse::Interaction::default, |content_layout| {
+                self.sheet
+                    .as_widget()
+                    .mouse_interaction(self.tree, content_layout, cursor, &self.bounds(), renderer)
+            })
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let status = if self.state.drag.is_some() {
+            Status::Dragged
+        } else {
+            Status::Active
+        };
+        let style = theme.style(self.class, status);
+        let bounds = self.bounds();
+
+        if self.backdrop {
+            // The backdrop fades in as the sheet rises.
+            let full = height(Snap::Full, self.peek_height, self.state.window.height);
+            let opacity = if full > 0.0 {
+                (bounds.height / full).min(1.0)
+            } else {
+                0.0
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::with_size(self.state.window),
+                    ..renderer::Quad::default()
+                },
+                Color {
+                    a: style.backdrop.a * opacity,
+                    ..style.backdrop
+                },
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let handle = self.handle_bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: handle.center_x() - HANDLE_SIZE.width / 2.0,
+                    y: handle.center_y() - HANDLE_SIZE.height / 2.0,
+                    width: HANDLE_SIZE.width,
+                    height: HANDLE_SIZE.height.min(handle.height),
+                },
+                border: border::rounded(HANDLE_SIZE.height / 2.0),
+                ..renderer::Quad::default()
+            },
+            style.handle_color,
+        );
+
+        if let Some(content_layout) = layout.children().next() {
+            renderer.with_layer(bounds, |renderer| {
+                self.sheet
+                    .as_widget()
+                    .draw(self.tree, renderer, theme, defaults, content_layout, cursor, &bounds);
+            });
+        }
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let bounds = self.bounds();
+
+        // The overlays of the content, like the menu of a pick list, open on top of the sheet.
+        layout.children().next().and_then(|content_layout| {
+            self.sheet
+                .as_widget_mut()
+                .overlay(self.tree, content_layout, renderer, &bounds, Vector::ZERO)
+        })
+    }
+
+    fn index(&self) -> f32 {
+        Priority::Panel.index()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<BottomSheet<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(bottom_sheet: BottomSheet<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(bottom_sheet)
+    }
+}
+
+/// The possible status of a [`BottomSheet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The sheet is resting or settling on a [`Snap`].
+    Active,
+    /// The handle of the sheet is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`BottomSheet`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the sheet.
+    pub background: Background,
+    /// The [`Border`] of the sheet.
+    pub border: Border,
+    /// The [`Shadow`] cast by the sheet.
+    pub shadow: Shadow,
+    /// The [`Color`] of the handle at the top of the sheet.
+    pub handle_color: Color,
+    /// The [`Color`] of the backdrop when the sheet is at [`Snap::Full`], when enabled.
+    pub backdrop: Color,
+}
+
+/// The theme catalog of a [`BottomSheet`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`BottomSheet`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`BottomSheet`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let radius = 12.0;
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            radius: border::Radius {
+                top_left: radius,
+                top_right: radius,
+                bottom_right: 0.0,
+                bottom_left: 0.0,
+            },
+            ..Border::default()
+        },
+        shadow: Shadow {
+            color: Color { a: 0.2, ..Color::BLACK },
+            offset: Vector::new(0.0, -2.0),
+            blur_radius: 16.0,
+        },
+        handle_color: match status {
+            Status::Active => palette.background.strong.color,
+            Status::Dragged => palette.primary.base.color,
+        },
+        backdrop: Color { a: 0.4, ..Color::BLACK },
+    }
+}
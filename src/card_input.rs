@@ -0,0 +1,542 @@
+//! Type the number, expiry, and security code of a payment card.
+//!
+//! A [`CardInput`] lays out three masked fields in a row. The number is grouped as it is typed,
+//! following its [`Brand`], which is named next to it. The expiry is kept to `MM/YY`, and the
+//! security code to the length the brand uses. A field holding a wrong value is outlined, and a
+//! single message below the fields names all of them.
+use std::fmt;
+use std::rc::Rc;
+
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Length;
+use iced_core::Theme;
+use iced_core::alignment;
+use iced_core::text;
+use iced_widget::Column;
+use iced_widget::Row;
+use iced_widget::text_input;
+
+/// The most digits a card number has.
+const MAX_DIGITS: usize = 19;
+
+/// The brand of a payment card, told by the first digits of its number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Brand {
+    /// Numbers starting with `4`.
+    Visa,
+    /// Numbers starting with `51` to `55`, or `2221` to `2720`.
+    Mastercard,
+    /// Numbers starting with `34` or `37`.
+    AmericanExpress,
+    /// Numbers starting with `6011`, `644` to `649`, or `65`.
+    Discover,
+}
+
+impl Brand {
+    /// Returns the [`Brand`] of a card number starting with the given digits, if it is known.
+    pub fn detect(digits: &str) -> Option<Brand> {
+        let prefix = |length: usize| digits.get(..length)?.parse::<u32>().ok();
+
+        if digits.starts_with('4') {
+            Some(Brand::Visa)
+        } else if matches!(prefix(2), Some(34 | 37)) {
+            Some(Brand::AmericanExpress)
+        } else if matches!(prefix(2), Some(51..=55)) || matches!(prefix(4), Some(2221..=2720)) {
+            Some(Brand::Mastercard)
+        } else if prefix(4) == Some(6011) || matches!(prefix(3), Some(644..=649)) || prefix(2) == Some(65) {
+            Some(Brand::Discover)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the sizes of the groups the numbers of the [`Brand`] are written in.
+    pub fn groups(self) -> &'static [usize] {
+        match self {
+            Brand::AmericanExpress => &[4, 6, 5],
+            Brand::Visa | Brand::Mastercard | Brand::Discover => &[4, 4, 4, 4],
+        }
+    }
+
+    /// Returns the amount of digits of the numbers of the [`Brand`].
+    pub fn length(self) -> usize {
+        self.groups().iter().sum()
+    }
+
+    /// Returns the amount of digits of the security codes of the [`Brand`].
+    pub fn cvc_length(self) -> usize {
+        match self {
+            Brand::AmericanExpress => 4,
+            Brand::Visa | Brand::Mastercard | Brand::Discover => 3,
+        }
+    }
+}
+
+impl fmt::Display for Brand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let brand = match self {
+            Brand::Visa => "Visa",
+            Brand::Mastercard => "Mastercard",
+            Brand::AmericanExpress => "American Express",
+            Brand::Discover => "Discover",
+        };
+
+        f.write_str(brand)
+    }
+}
+
+/// A field of a [`Card`] holding a wrong value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The number belongs to no known [`Brand`], or fails its checksum once complete.
+    Number,
+    /// The month of the expiry is not between `01` and `12`.
+    Expiry,
+    /// The security code is longer than the [`Brand`] allows.
+    Cvc,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = match self {
+            Error::Number => "card number",
+            Error::Expiry => "expiry date",
+            Error::Cvc => "security code",
+        };
+
+        f.write_str(field)
+    }
+}
+
+/// A payment card, as typed in a [`CardInput`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Card {
+    /// The number, grouped with spaces.
+    pub number: String,
+    /// The expiry, as `MM/YY`.
+    pub expiry: String,
+    /// The security code.
+    pub cvc: String,
+}
+
+impl Card {
+    /// Returns the digits of the number of the [`Card`].
+    pub fn digits(&self) -> String {
+        digits(&self.number)
+    }
+
+    /// Returns the [`Brand`] of the [`Card`], if its number starts like a known one.
+    pub fn brand(&self) -> Option<Brand> {
+        Brand::detect(&self.digits())
+    }
+
+    /// Returns the month and the year, without its century, of the expiry of the [`Card`] once
+    /// it is complete and valid.
+    ///
+    /// Whether it has passed is left to the application, which knows the date.
+    pub fn expiry(&self) -> Option<(u8, u8)> {
+        let digits = digits(&self.expiry);
+
+        if digits.len() != 4 {
+            return None;
+        }
+
+        let month = digits[..2].parse().ok().filter(|month| (1..=12).contains(month))?;
+        let year = digits[2..].parse().ok()?;
+
+        Some((month, year))
+    }
+
+    /// Returns whether every field of the [`Card`] has all of its digits.
+    pub fn is_complete(&self) -> bool {
+        let brand = self.brand();
+        let number = self.digits().len();
+
+        brand.is_some_and(|brand| number == brand.length())
+            && digits(&self.expiry).len() == 4
+            && brand.is_some_and(|brand| self.cvc.len() == brand.cvc_length())
+    }
+
+    /// Returns the fields of the [`Card`] holding a wrong value, in the order they are laid out.
+    ///
+    /// Fields still being typed are only wrong once they cannot become right.
+    pub fn errors(&self) -> Vec<Error> {
+        let number = self.digits();
+        let brand = Brand::detect(&number);
+        let expiry = digits(&self.expiry);
+
+        // Every known brand is told apart by its first four digits.
+        let is_number_wrong = match brand {
+            Some(brand) => number.len() == brand.length() && !luhn(&number),
+            None => number.len() >= 4,
+        };
+        let is_expiry_wrong = match expiry.len() {
+            0 => false,
+            1 => !expiry.starts_with(['0', '1']),
+            _ => !matches!(expiry[..2].parse::<u8>(), Ok(1..=12)),
+        };
+        let is_cvc_wrong = brand.is_some_and(|brand| self.cvc.len() > brand.cvc_length());
+
+        [
+            (is_number_wrong, Error::Number),
+            (is_expiry_wrong, Error::Expiry),
+            (is_cvc_wrong, Error::Cvc),
+        ]
+        .into_iter()
+        .filter_map(|(is_wrong, error)| is_wrong.then_some(error))
+        .collect()
+    }
+
+    /// Returns whether the [`Card`] is complete and none of its fields is wrong.
+    pub fn is_valid(&self) -> bool {
+        self.is_complete() && self.errors().is_empty()
+    }
+
+    /// Returns the message naming every wrong field of the [`Card`], if any, like
+    /// `"Check the card number and the expiry date."`.
+    pub fn message(&self) -> Option<String> {
+        let errors = self.errors();
+        let (last, rest) = errors.split_last()?;
+
+        let fields = if rest.is_empty() {
+            format!("the {last}")
+        } else {
+            let rest: Vec<_> = rest.iter().map(|error| format!("the {error}")).collect();
+
+            format!("{} and the {last}", rest.join(", "))
+        };
+
+        Some(format!("Check {fields}."))
+    }
+}
+
+/// Returns the digits of the given text.
+fn digits(text: &str) -> String {
+    text.chars().filter(char::is_ascii_digit).collect()
+}
+
+/// Returns whether the given digits pass the Luhn checksum of card numbers.
+fn luhn(digits: &str) -> bool {
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|digit| digit.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| match (index % 2 == 1, digit * 2) {
+            (true, doubled) if doubled > 9 => doubled - 9,
+            (true, doubled) => doubled,
+            (false, _) => digit,
+        })
+        .sum();
+
+    !digits.is_empty() && sum % 10 == 0
+}
+
+/// Keeps the digits of a typed number, up to the length of its [`Brand`], grouped with spaces.
+fn mask_number(input: &str) -> String {
+    let digits = digits(input);
+    let brand = Brand::detect(&digits);
+    let groups = brand.map_or(&[4, 4, 4, 4, 3][..], Brand::groups);
+    let length = brand.map_or(MAX_DIGITS, Brand::length);
+
+    let mut masked = String::new();
+    let mut rest = &digits[..digits.len().min(length)];
+
+    for &group in groups {
+        if rest.is_empty() {
+            break;
+        }
+
+        let (head, tail) = rest.split_at(group.min(rest.len()));
+
+        if !masked.is_empty() {
+            masked.push(' ');
+        }
+
+        masked.push_str(head);
+        rest = tail;
+    }
+
+    masked
+}
+
+/// Keeps the digits of a typed expiry as `MM/YY`, padding a month that can only have one digit.
+fn mask_expiry(input: &str) -> String {
+    let mut digits = digits(input);
+
+    if digits.len() == 1 && !digits.starts_with(['0', '1']) {
+        digits.insert(0, '0');
+    }
+
+    digits.truncate(4);
+
+    if digits.len() > 2 {
+        digits.insert(2, '/');
+    }
+
+    digits
+}
+
+/// Keeps the digits of a typed security code, up to the given length.
+fn mask_cvc(input: &str, length: usize) -> String {
+    digits(input).chars().take(length).collect()
+}
+
+/// The number, expiry, and security code fields of a [`Card`], with its brand and the message
+/// naming its wrong fields.
+pub struct CardInput<'a, Message> {
+    card: &'a Card,
+    on_change: Rc<dyn Fn(Card) -> Message + 'a>,
+    width: Length,
+    spacing: f32,
+}
+
+impl<'a, Message> CardInput<'a, Message> {
+    /// The default spacing between the fields of a [`CardInput`].
+    pub const DEFAULT_SPACING: f32 = 4.0;
+
+    /// Creates a new [`CardInput`] editing the given [`Card`], and the message to produce with
+    /// the changed one.
+    pub fn new(card: &'a Card, on_change: impl Fn(Card) -> Message + 'a) -> Self {
+        Self {
+            card,
+            on_change: Rc::new(on_change),
+            width: Length::Fixed(200.0),
+            spacing: Self::DEFAULT_SPACING,
+        }
+    }
+
+    /// Sets the width of the number field of the [`CardInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the fields of the [`CardInput`].
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CardInput<'a, Message>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + text_input::Catalog + iced_widget::text::Catalog + 'a,
+    <Theme as text_input::Catalog>::Class<'a>: From<text_input::StyleFn<'a, Theme>>,
+    <Theme as iced_widget::text::Catalog>::Class<'a>: From<iced_widget::text::StyleFn<'a, Theme>>,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(input: CardInput<'a, Message>) -> Self {
+        let card = input.card;
+        let brand = card.brand();
+        let errors = card.errors();
+
+        // Wrong fields keep the style of the theme, with its invalid color on their border.
+        let outline = |is_wrong: bool| {
+            move |theme: &Theme, status: text_input::Status| {
+                let mut style = text_input::Catalog::style(theme, &<Theme as text_input::Catalog>::default(), status);
+
+                if is_wrong {
+                    style.border.color = Catalog::style(theme, &<Theme as Catalog>::default()).invalid;
+                }
+
+                style
+            }
+        };
+
+        let number = {
+            let change = Rc::clone(&input.on_change);
+            let current = card.clone();
+
+            iced_widget::text_input("1234 5678 9012 3456", &card.number)
+                .on_input(move |typed| {
+                    change(Card {
+                        number: mask_number(&typed),
+                        ..current.clone()
+                    })
+                })
+                .style(outline(errors.contains(&Error::Number)))
+                .width(input.width)
+        };
+
+        let brand =
+            iced_widget::text(brand.map_or_else(String::new, |brand| brand.to_string())).style(|theme: &Theme| {
+                iced_widget::text::Style {
+                    color: Some(Catalog::style(theme, &<Theme as Catalog>::default()).brand),
+                }
+            });
+
+        let expiry = {
+            let change = Rc::clone(&input.on_change);
+            let current = card.clone();
+
+            iced_widget::text_input("MM/YY", &card.expiry)
+                .on_input(move |typed| {
+                    change(Card {
+                        expiry: mask_expiry(&typed),
+                        ..current.clone()
+                    })
+                })
+                .style(outline(errors.contains(&Error::Expiry)))
+                .width(70)
+        };
+
+        let cvc = {
+            let change = Rc::clone(&input.on_change);
+            let current = card.clone();
+            let length = card.brand().map_or(4, Brand::cvc_length);
+
+            iced_widget::text_input("CVC", &card.cvc)
+                .on_input(move |typed| {
+                    change(Card {
+                        cvc: mask_cvc(&typed, length),
+                        ..current.clone()
+                    })
+                })
+                .style(outline(errors.contains(&Error::Cvc)))
+                .width(60)
+        };
+
+        let fields = Row::new()
+            .push(number)
+            .push(brand)
+            .push(expiry)
+            .push(cvc)
+            .spacing(input.spacing)
+            .align_y(alignment::Vertical::Center);
+
+        let mut column = Column::new().push(fields).spacing(input.spacing);
+
+        if let Some(message) = card.message() {
+            column = column.push(
+                iced_widget::text(message).style(|theme: &Theme| iced_widget::text::Style {
+                    color: Some(Catalog::style(theme, &<Theme as Catalog>::default()).invalid),
+                }),
+            );
+        }
+
+        column.into()
+    }
+}
+
+/// The appearance of a [`CardInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the border of the wrong fields, and of the message naming them.
+    pub invalid: Color,
+    /// The [`Color`] of the name of the [`Brand`] of the number.
+    pub brand: Color,
+}
+
+/// The theme catalog of a [`CardInput`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`CardInput`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`CardInput`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        invalid: palette.danger.base.color,
+        brand: palette.background.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Brand, Card, Error, luhn, mask_expiry, mask_number};
+
+    fn card(number: &str, expiry: &str, cvc: &str) -> Card {
+        Card {
+            number: String::from(number),
+            expiry: String::from(expiry),
+            cvc: String::from(cvc),
+        }
+    }
+
+    #[test]
+    fn numbers_are_grouped_by_brand() {
+        assert_eq!(mask_number("4242424242424242"), "4242 4242 4242 4242");
+        assert_eq!(mask_number("378282246310005"), "3782 822463 10005");
+        assert_eq!(mask_number("4242 4242 4242 4242 99"), "4242 4242 4242 4242");
+        assert_eq!(mask_number("42-42"), "4242");
+    }
+
+    #[test]
+    fn brands_are_detected_by_their_first_digits() {
+        assert_eq!(Brand::detect("4"), Some(Brand::Visa));
+        assert_eq!(Brand::detect("2221"), Some(Brand::Mastercard));
+        assert_eq!(Brand::detect("55"), Some(Brand::Mastercard));
+        assert_eq!(Brand::detect("37"), Some(Brand::AmericanExpress));
+        assert_eq!(Brand::detect("6011"), Some(Brand::Discover));
+        assert_eq!(Brand::detect("1234"), None);
+    }
+
+    #[test]
+    fn luhn_checks_the_last_digit() {
+        assert!(luhn("4242424242424242"));
+        assert!(luhn("378282246310005"));
+        assert!(!luhn("4242424242424241"));
+    }
+
+    #[test]
+    fn expiries_are_masked_to_months_and_years() {
+        assert_eq!(mask_expiry("4"), "04");
+        assert_eq!(mask_expiry("1"), "1");
+        assert_eq!(mask_expiry("1227"), "12/27");
+        assert_eq!(mask_expiry("12/2799"), "12/27");
+    }
+
+    #[test]
+    fn only_fields_that_cannot_become_right_are_wrong() {
+        assert_eq!(card("4242 42", "1", "12").errors(), []);
+        assert_eq!(
+            card("4242 4242 4242 4241", "13/27", "123").errors(),
+            [Error::Number, Error::Expiry]
+        );
+        assert_eq!(card("1234", "", "").errors(), [Error::Number]);
+        assert_eq!(card("4242", "", "1234").errors(), [Error::Cvc]);
+    }
+
+    #[test]
+    fn a_complete_card_without_errors_is_valid() {
+        let card = card("4242 4242 4242 4242", "12/27", "123");
+
+        assert!(card.is_valid());
+        assert_eq!(card.expiry(), Some((12, 27)));
+        assert_eq!(card.message(), None);
+    }
+
+    #[test]
+    fn the_message_names_every_wrong_field() {
+        assert_eq!(
+            card("4242 4242 4242 4241", "13/27", "123").message().as_deref(),
+            Some("Check the card number and the expiry date.")
+        );
+    }
+}
@@ -0,0 +1,671 @@
+//! Pick the part of an image to keep.
+//!
+//! A [`Cropper`] draws an image with a crop rectangle over it, which can be moved by
+//! dragging its inside and resized by dragging its edges or corners. The rectangle is
+//! measured in the pixels of the image rather than of the window, so it can crop the full
+//! image as is, whatever size the cropper is drawn at.
+use iced_core::Border;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::ContentFit;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Theme;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::image;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::touch;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+
+/// An image with a draggable and resizable crop rectangle.
+pub struct Cropper<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    handle: image::Handle,
+    crop: Rectangle,
+    on_change: Box<dyn Fn(Rectangle) -> Message + 'a>,
+    aspect_ratio: Option<f32>,
+    guides: bool,
+    width: Length,
+    height: Length,
+    handle_size: f32,
+    min_size: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Cropper<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of the handles at the corners of the crop rectangle.
+    pub const DEFAULT_HANDLE_SIZE: f32 = 10.0;
+
+    /// The default minimum width and height of the crop rectangle, in image pixels.
+    pub const DEFAULT_MIN_SIZE: f32 = 16.0;
+
+    /// Creates a new [`Cropper`] over the given image, with the crop rectangle in the
+    /// pixels of the image, and the message to produce with the changed rectangle.
+    pub fn new(
+        handle: impl Into<image::Handle>,
+        crop: Rectangle,
+        on_change: impl Fn(Rectangle) -> Message + 'a,
+    ) -> Self {
+        Self {
+            handle: handle.into(),
+            crop,
+            on_change: Box::new(on_change),
+            aspect_ratio: None,
+            guides: true,
+            width: Length::Fill,
+            height: Length::Shrink,
+            handle_size: Self::DEFAULT_HANDLE_SIZE,
+            min_size: Self::DEFAULT_MIN_SIZE,
+            class: Theme::default(),
+        }
+    }
+
+    /// Locks the ratio of the width to the height of the crop rectangle while it is resized.
+    pub fn aspect_ratio(mut self, aspect_ratio: impl Into<Option<f32>>) -> Self {
+        self.aspect_ratio = aspect_ratio.into().filter(|ratio| *ratio > 0.0);
+        self
+    }
+
+    /// Sets whether rule-of-thirds guides are drawn while the crop rectangle is dragged.
+    pub fn guides(mut self, guides: bool) -> Self {
+        self.guides = guides;
+        self
+    }
+
+    /// Sets the width of the [`Cropper`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Cropper`].
+    ///
+    /// By default, it follows the aspect ratio of the image.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the size of the handles at the corners of the crop rectangle.
+    ///
+    /// Edges and corners can be grabbed within this distance.
+    pub fn handle_size(mut self, handle_size: f32) -> Self {
+        self.handle_size = handle_size;
+        self
+    }
+
+    /// Sets the minimum width and height of the crop rectangle, in image pixels.
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Sets the style of the [`Cropper`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Cropper`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the bounds the image is drawn in, and the amount of screen pixels per image pixel.
+    fn fit(bounds: Rectangle, image_size: Size) -> (Rectangle, f32) {
+        let size = ContentFit::Contain.fit(image_size, bounds.size());
+        let position = bounds.center() - Vector::new(size.width, size.height) * 0.5;
+        let scale = if image_size.width > 0.0 {
+            size.width / image_size.width
+        } else {
+            1.0
+        };
+
+        (Rectangle::new(position, size), scale)
+    }
+
+    /// Returns the part of the crop rectangle grabbed at the given position, if any.
+    fn grab_at(&self, crop: Rectangle, position: Point) -> Option<Grab> {
+        let reach = self.handle_size;
+
+        let near = |a: f32, b: f32| (a - b).abs() <= reach;
+        let within = |value: f32, start: f32, length: f32| value >= start - reach && value <= start + length + reach;
+
+        if !within(position.x, crop.x, crop.width) || !within(position.y, crop.y, crop.height) {
+            return None;
+        }
+
+        let left = near(position.x, crop.x);
+        let right = !left && near(position.x, crop.x + crop.width);
+        let top = near(position.y, crop.y);
+        let bottom = !top && near(position.y, crop.y + crop.height);
+
+        if left || right || top || bottom {
+            Some(Grab::Resize {
+                left,
+                top,
+                right,
+                bottom,
+            })
+        } else {
+            Some(Grab::Move)
+        }
+    }
+
+    /// Returns the crop rectangle after dragging the grabbed part by the given distance, in
+    /// image pixels, clamped to the image.
+    fn drag(&self, start: Rectangle, grab: Grab, delta: Vector, image_size: Size) -> Rectangle {
+        let Grab::Resize {
+            left: grab_left,
+            top: grab_top,
+            right: grab_right,
+            bottom: grab_bottom,
+        } = grab
+        else {
+            let x = (start.x + delta.x).clamp(0.0, (image_size.width - start.width).max(0.0));
+            let y = (start.y + delta.y).clamp(0.0, (image_size.height - start.height).max(0.0));
+
+            return Rectangle { x, y, ..start };
+        };
+
+        let min = self.min_size.min(image_size.width).min(image_size.height);
+
+        let mut left = start.x;
+        let mut top = start.y;
+        let mut right = start.x + start.width;
+        let mut bottom = start.y + start.height;
+
+        // Unlike `clamp`, these do not panic when the crop rectangle starts out smaller than the minimum.
+        if grab_left {
+            left = (left + delta.x).min(right - min).max(0.0);
+        }
+        if grab_right {
+            right = (right + delta.x).max(left + min).min(image_size.width);
+        }
+        if grab_top {
+            top = (top + delta.y).min(bottom - min).max(0.0);
+        }
+        if grab_bottom {
+            bottom = (bottom + delta.y).max(top + min).min(image_size.height);
+        }
+
+        if let Some(ratio) = self.aspect_ratio {
+            let horizontal = grab_left || grab_right;
+            let vertical = grab_top || grab_bottom;
+
+            let (width, height) = (right - left, bottom - top);
+
+            // The dimension that was dragged the most drives the other one.
+            let (width, height) = if horizontal && (!vertical || width / ratio >= height) {
+                (width, width / ratio)
+            } else {
+                (height * ratio, height)
+            };
+
+            // Edges that were not grabbed grow from their center, the others from the opposite edge.
+            let center = Point::new((left + right) / 2.0, (top + bottom) / 2.0);
+
+            let room_x = if grab_left {
+                right
+            } else if grab_right {
+                image_size.width - left
+            } else {
+                2.0 * center.x.min(image_size.width - center.x)
+            };
+
+            let room_y = if grab_top {
+                bottom
+            } else if grab_bottom {
+                image_size.height - top
+            } else {
+                2.0 * center.y.min(image_size.height - center.y)
+            };
+
+            let shrink = (room_x / width).min(room_y / height).min(1.0);
+            let (width, height) = (width * shrink, height * shrink);
+
+            if grab_left {
+                left = right - width;
+            } else if grab_right {
+                right = left + width;
+            } else {
+                left = center.x - width / 2.0;
+                right = center.x + width / 2.0;
+            }
+
+            if grab_top {
+                top = bottom - height;
+            } else if grab_bottom {
+                bottom = top + height;
+            } else {
+                top = center.y - height / 2.0;
+                bottom = center.y + height / 2.0;
+            }
+        }
+
+        Rectangle {
+            x: left,
+            y: top,
+            width: right - left,
+            height: bottom - top,
+        }
+    }
+}
+
+/// The part of the crop rectangle being dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Grab {
+    Move,
+    Resize {
+        left: bool,
+        top: bool,
+        right: bool,
+        bottom: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    grab: Grab,
+    origin: Point,
+    start: Rectangle,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    drag: Option<Drag>,
+    hovered: Option<Grab>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Cropper<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let image_size = renderer.measure_image(&self.handle).unwrap_or_default();
+        let image_size = Size::new(image_size.width as f32, image_size.height as f32);
+
+        let size = limits.resolve(self.width, self.height, image_size);
+
+        let size = if self.height == Length::Shrink && image_size.width > 0.0 {
+            let height = size.width * image_size.height / image_size.width;
+
+            Size::new(size.width, height.min(limits.max().height))
+        } else {
+            size
+        };
+
+        layout::Node::new(size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let image_size = renderer.measure_image(&self.handle).unwrap_or_default();
+        let image_size = Size::new(image_size.width as f32, image_size.height as f32);
+
+        let (image_bounds, scale) = Self::fit(layout.bounds(), image_size);
+        let crop = to_screen(self.crop, image_bounds, scale);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(layout.bounds())
+                    && let Some(grab) = self.grab_at(crop, position)
+                {
+                    state.drag = Some(Drag {
+                        grab,
+                        origin: position,
+                        start: self.crop,
+                    });
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = state.drag {
+                    if let Some(position) = cursor.position() {
+                        let delta = (position - drag.origin) * (1.0 / scale);
+                        let changed = self.drag(drag.start, drag.grab, delta, image_size);
+
+                        if changed != self.crop {
+                            shell.publish((self.on_change)(changed));
+                        }
+                    }
+
+                    shell.capture_event();
+                } else {
+                    let hovered = cursor
+                        .position_over(layout.bounds())
+                        .and_then(|position| self.grab_at(crop, position));
+
+                    if hovered != state.hovered {
+                        state.hovered = hovered;
+                        shell.request_redraw();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let image_size = renderer.measure_image(&self.handle).unwrap_or_default();
+        let image_size = Size::new(image_size.width as f32, image_size.height as f32);
+
+        let (image_bounds, scale) = Self::fit(bounds, image_size);
+        let crop = to_screen(self.crop, image_bounds, scale);
+
+        let status = if state.drag.is_some() {
+            Status::Dragged
+        } else if state.hovered.is_some() {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        let Some(clip_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.draw_image(image::Image::new(self.handle.clone()), image_bounds, clip_bounds);
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            let fill = |renderer: &mut Renderer, bounds: Rectangle, color: Color| {
+                if bounds.width > 0.0 && bounds.height > 0.0 {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            ..renderer::Quad::default()
+                        },
+                        color,
+                    );
+                }
+            };
+
+            // The image outside of the crop rectangle is dimmed.
+            let (crop_right, crop_bottom) = (crop.x + crop.width, crop.y + crop.height);
+            let (image_right, image_bottom) = (
+                image_bounds.x + image_bounds.width,
+                image_bounds.y + image_bounds.height,
+            );
+
+            fill(
+                renderer,
+                Rectangle::new(
+                    image_bounds.position(),
+                    Size::new(image_bounds.width, crop.y - image_bounds.y),
+                ),
+                style.overlay,
+            );
+            fill(
+                renderer,
+                Rectangle::new(
+                    Point::new(image_bounds.x, crop_bottom),
+                    Size::new(image_bounds.width, image_bottom - crop_bottom),
+                ),
+                style.overlay,
+            );
+            fill(
+                renderer,
+                Rectangle::new(
+                    Point::new(image_bounds.x, crop.y),
+                    Size::new(crop.x - image_bounds.x, crop.height),
+                ),
+                style.overlay,
+            );
+            fill(
+                renderer,
+                Rectangle::new(
+                    Point::new(crop_right, crop.y),
+                    Size::new(image_right - crop_right, crop.height),
+                ),
+                style.overlay,
+            );
+
+            if self.guides && state.drag.is_some() {
+                for third in [1.0, 2.0] {
+                    let x = crop.x + crop.width * third / 3.0;
+                    let y = crop.y + crop.height * third / 3.0;
+
+                    fill(
+                        renderer,
+                        Rectangle::new(Point::new(x, crop.y), Size::new(1.0, crop.height)),
+                        style.guide_color,
+                    );
+                    fill(
+                        renderer,
+                        Rectangle::new(Point::new(crop.x, y), Size::new(crop.width, 1.0)),
+                        style.guide_color,
+                    );
+                }
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: crop,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+
+            let size = self.handle_size;
+
+            for corner in [
+                Point::new(crop.x, crop.y),
+                Point::new(crop_right, crop.y),
+                Point::new(crop.x, crop_bottom),
+                Point::new(crop_right, crop_bottom),
+            ] {
+                fill(
+                    renderer,
+                    Rectangle::new(corner - Vector::new(size / 2.0, size / 2.0), Size::new(size, size)),
+                    style.handle_color,
+                );
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        let grab = state
+            .drag
+            .map(|drag| drag.grab)
+            .or_else(|| cursor.position_over(layout.bounds()).and(state.hovered));
+
+        match grab {
+            None => mouse::Interaction::default(),
+            Some(Grab::Move) if state.drag.is_some() => mouse::Interaction::Grabbing,
+            Some(Grab::Move) => mouse::Interaction::Grab,
+            Some(Grab::Resize {
+                left,
+                top,
+                right,
+                bottom,
+            }) => match (left || right, top || bottom) {
+                (true, false) => mouse::Interaction::ResizingHorizontally,
+                (false, true) => mouse::Interaction::ResizingVertically,
+                _ if (left && top) || (right && bottom) => mouse::Interaction::ResizingDiagonallyDown,
+                _ => mouse::Interaction::ResizingDiagonallyUp,
+            },
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Cropper<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: image::Renderer<Handle = image::Handle> + 'a,
+{
+    fn from(cropper: Cropper<'a, Message, Theme>) -> Self {
+        Self::new(cropper)
+    }
+}
+
+/// Returns the screen bounds of a rectangle in the pixels of an image drawn in the given bounds.
+fn to_screen(rectangle: Rectangle, image_bounds: Rectangle, scale: f32) -> Rectangle {
+    Rectangle {
+        x: image_bounds.x + rectangle.x * scale,
+        y: image_bounds.y + rectangle.y * scale,
+        width: rectangle.width * scale,
+        height: rectangle.height * scale,
+    }
+}
+
+/// The status of a [`Cropper`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The crop rectangle is idle.
+    Active,
+    /// The crop rectangle can be grabbed under the cursor.
+    Hovered,
+    /// The crop rectangle is being moved or resized.
+    Dragged,
+}
+
+/// The appearance of a [`Cropper`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] dimming the image outside of the crop rectangle.
+    pub overlay: Color,
+    /// The [`Border`] of the crop rectangle.
+    pub border: Border,
+    /// The [`Color`] of the rule-of-thirds guides.
+    pub guide_color: Color,
+    /// The [`Color`] of the handles at the corners of the crop rectangle.
+    pub handle_color: Color,
+}
+
+/// The theme catalog of a [`Cropper`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Cropper`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Cropper`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        overlay: Color { a: 0.5, ..Color::BLACK },
+        border: Border {
+            color: Color::WHITE,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        guide_color: Color { a: 0.6, ..Color::WHITE },
+        handle_color: Color::WHITE,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered | Status::Dragged => Style {
+            border: Border {
+                color: palette.primary.base.color,
+                ..active.border
+            },
+            handle_color: palette.primary.base.color,
+            ..active
+        },
+    }
+}
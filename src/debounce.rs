@@ -0,0 +1,242 @@
+//! Coalesce rapid events into fewer published messages.
+//!
+//! A [`Debounced`] is meant to live in the state of a widget. The widget pushes
+//! a message every time an event happens and publishes whatever comes out, then
+//! keeps calling [`Debounced::publish`] on every update so trailing messages are
+//! delivered once their deadline passes.
+use iced_core::Shell;
+use iced_core::time::Duration;
+use iced_core::time::Instant;
+use iced_core::window;
+
+/// A message slot that debounces or throttles the messages pushed into it.
+#[derive(Debug, Clone)]
+pub struct Debounced<Message> {
+    delay: Duration,
+    mode: Mode,
+    leading: bool,
+    trailing: bool,
+    pending: Option<Message>,
+    deadline: Option<Instant>,
+}
+
+/// How a [`Debounced`] measures its delay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The delay restarts with every pushed message, so a burst produces messages
+    /// only at its edges.
+    Debounce,
+    /// The delay starts with the first message of a window, so a burst produces at
+    /// most one message per window.
+    Throttle,
+}
+
+impl<Message> Debounced<Message> {
+    /// Creates a new [`Debounced`] that publishes the last message of a burst once
+    /// no message has been pushed for the given delay.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            mode: Mode::Debounce,
+            leading: false,
+            trailing: true,
+            pending: None,
+            deadline: None,
+        }
+    }
+
+    /// Creates a new [`Debounced`] that publishes at most one message per interval.
+    ///
+    /// Both the leading and trailing messages of a window are published by default.
+    pub fn throttled(interval: Duration) -> Self {
+        Self {
+            mode: Mode::Throttle,
+            leading: true,
+            ..Self::new(interval)
+        }
+    }
+
+    /// Sets whether the first message of a burst is published right away.
+    pub fn leading(mut self, leading: bool) -> Self {
+        self.leading = leading;
+        self
+    }
+
+    /// Sets whether the last message of a burst is published once its deadline passes.
+    pub fn trailing(mut self, trailing: bool) -> Self {
+        self.trailing = trailing;
+        self
+    }
+
+    /// Returns the [`Mode`] of the [`Debounced`].
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Pushes a new message, returning the message that should be published right away, if any.
+    pub fn push(&mut self, message: Message, now: Instant) -> Option<Message> {
+        let is_idle = self.deadline.is_none_or(|deadline| now >= deadline);
+
+        if is_idle {
+            let overdue = self.pending.take();
+            self.deadline = Some(now + self.delay);
+
+            if overdue.is_none() && self.leading {
+                return Some(message);
+            }
+
+            if self.trailing {
+                self.pending = Some(message);
+            }
+
+            return overdue;
+        }
+
+        if self.trailing {
+            self.pending = Some(message);
+        }
+
+        if self.mode == Mode::Debounce {
+            self.deadline = Some(now + self.delay);
+        }
+
+        None
+    }
+
+    /// Returns the pending message if its deadline has passed.
+    pub fn poll(&mut self, now: Instant) -> Option<Message> {
+        let deadline = self.deadline?;
+
+        if now < deadline {
+            return None;
+        }
+
+        self.deadline = None;
+        let message = self.pending.take();
+
+        // A trailing message of a throttle opens the next window.
+        if message.is_some() && self.mode == Mode::Throttle {
+            self.deadline = Some(now + self.delay);
+        }
+
+        message
+    }
+
+    /// Returns the pending message right away, regardless of its deadline.
+    pub fn flush(&mut self) -> Option<Message> {
+        self.deadline = None;
+        self.pending.take()
+    }
+
+    /// Discards the pending message, if any.
+    pub fn cancel(&mut self) {
+        self.deadline = None;
+        self.pending = None;
+    }
+
+    /// Returns whether a message is waiting for its deadline.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Returns the instant the pending message is due, if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline.filter(|_| self.pending.is_some())
+    }
+
+    /// Publishes the pending message if it is due and requests a redraw for the
+    /// deadline of the message still pending otherwise.
+    pub fn publish(&mut self, now: Instant, shell: &mut Shell<'_, Message>) {
+        if let Some(message) = self.poll(now) {
+            shell.publish(message);
+        }
+
+        if let Some(deadline) = self.deadline() {
+            shell.request_redraw_at(window::RedrawRequest::At(deadline));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::time::{Duration, Instant};
+
+    use super::Debounced;
+
+    const DELAY: Duration = Duration::from_millis(100);
+
+    /// Pushes a burst of three messages 40ms apart into the [`Debounced`] and polls it until it
+    /// settles, returning the published messages with the millisecond they came out at.
+    fn burst(mut debounced: Debounced<&'static str>) -> Vec<(u64, &'static str)> {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let mut published = Vec::new();
+
+        for (ms, message) in [(0, "a"), (40, "b"), (80, "c")] {
+            published.extend(debounced.push(message, at(ms)).map(|message| (ms, message)));
+        }
+
+        for ms in [130, 180, 300] {
+            published.extend(debounced.poll(at(ms)).map(|message| (ms, message)));
+        }
+
+        published
+    }
+
+    #[test]
+    fn debounce_publishes_the_edges_of_a_burst_once_it_settles() {
+        let debounced = || Debounced::new(DELAY);
+
+        assert_eq!(burst(debounced()), [(180, "c")]);
+        assert_eq!(burst(debounced().leading(true)), [(0, "a"), (180, "c")]);
+        assert_eq!(burst(debounced().leading(true).trailing(false)), [(0, "a")]);
+        assert!(burst(debounced().trailing(false)).is_empty());
+    }
+
+    #[test]
+    fn throttle_publishes_the_edges_of_its_first_window() {
+        let throttled = || Debounced::throttled(DELAY);
+
+        assert_eq!(burst(throttled()), [(0, "a"), (130, "c")]);
+        assert_eq!(burst(throttled().leading(false)), [(130, "c")]);
+        assert_eq!(burst(throttled().trailing(false)), [(0, "a")]);
+        assert!(burst(throttled().leading(false).trailing(false)).is_empty());
+    }
+
+    #[test]
+    fn a_push_past_the_deadline_returns_the_overdue_message() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+
+        let mut debounced = Debounced::new(DELAY);
+
+        assert_eq!(debounced.push("a", at(0)), None);
+        assert_eq!(debounced.push("b", at(150)), Some("a"));
+        assert_eq!(debounced.poll(at(250)), Some("b"));
+
+        // The overdue message is published in place of the leading one, which waits instead.
+        let mut debounced = Debounced::new(DELAY).leading(true);
+
+        assert_eq!(debounced.push("a", at(0)), Some("a"));
+        assert_eq!(debounced.push("b", at(50)), None);
+        assert_eq!(debounced.push("c", at(200)), Some("b"));
+        assert_eq!(debounced.poll(at(300)), Some("c"));
+    }
+
+    #[test]
+    fn a_trailing_throttled_message_opens_the_next_window() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+
+        let mut throttled = Debounced::throttled(DELAY);
+
+        assert_eq!(throttled.push("a", at(0)), Some("a"));
+        assert_eq!(throttled.push("b", at(50)), None);
+        assert_eq!(throttled.poll(at(100)), Some("b"));
+
+        // The next message falls in the window opened by the trailing one, so it waits for its end.
+        assert_eq!(throttled.push("c", at(150)), None);
+        assert_eq!(throttled.poll(at(199)), None);
+        assert_eq!(throttled.poll(at(200)), Some("c"));
+    }
+}
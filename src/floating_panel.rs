@@ -0,0 +1,1011 @@
+//! Float a panel of content above the window, like a picture-in-picture player.
+//!
+//! A [`FloatingPanel`] wraps the content below it and shows the panel in an overlay. The
+//! title bar of the panel drags it around, its edges resize it, and its button minimizes it
+//! to a pill. Releasing the panel anchors it to the closest corner of the window, snapping
+//! it into the corner when close enough.
+//!
+//! The panel only keeps the drag in progress. Its corner, offset, size and whether it is
+//! minimized make up a [`Placement`] that can be stored and restored on the next launch.
+use iced_core::Background;
+use iced_core::Border;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shadow;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Theme;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::alignment;
+use iced_core::border;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::touch;
+use iced_core::widget::Operation;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+
+use crate::anchored_overlay::Priority;
+
+/// A corner of the window a [`FloatingPanel`] is anchored to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+impl Corner {
+    fn is_left(self) -> bool {
+        matches!(self, Corner::TopLeft | Corner::BottomLeft)
+    }
+
+    fn is_top(self) -> bool {
+        matches!(self, Corner::TopLeft | Corner::TopRight)
+    }
+}
+
+/// Where a [`FloatingPanel`] sits in the window, and whether it is minimized.
+///
+/// The panel is placed relative to a [`Corner`], so it stays in place when the window is
+/// resized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Placement {
+    /// The [`Corner`] the panel is anchored to.
+    pub corner: Corner,
+    /// The horizontal distance between the panel and the side of its [`Corner`].
+    pub x: f32,
+    /// The vertical distance between the panel and the side of its [`Corner`].
+    pub y: f32,
+    /// The width of the panel.
+    pub width: f32,
+    /// The height of the panel, including its title bar.
+    pub height: f32,
+    /// Whether the panel is minimized to a pill.
+    pub minimized: bool,
+}
+
+impl Placement {
+    /// Creates a new [`Placement`] of the given size, in the bottom right corner.
+    pub fn new(size: impl Into<Size>) -> Self {
+        let size = size.into();
+
+        Self {
+            corner: Corner::BottomRight,
+            x: MARGIN,
+            y: MARGIN,
+            width: size.width,
+            height: size.height,
+            minimized: false,
+        }
+    }
+
+    /// Returns the bounds of the panel in a window of the given size, kept inside of it.
+    pub fn bounds(&self, window: Size) -> Rectangle {
+        self.place(Size::new(self.width, self.height), window)
+    }
+
+    /// Returns the bounds of the pill of the minimized panel in a window of the given size.
+    pub fn pill_bounds(&self, window: Size) -> Rectangle {
+        self.place(PILL_SIZE, window)
+    }
+
+    fn place(&self, size: Size, window: Size) -> Rectangle {
+        let width = size.width.min(window.width);
+        let height = size.height.min(window.height);
+
+        let x = if self.corner.is_left() {
+            self.x
+        } else {
+            window.width - self.x - width
+        };
+
+        let y = if self.corner.is_top() {
+            self.y
+        } else {
+            window.height - self.y - height
+        };
+
+        Rectangle {
+            x: x.clamp(0.0, window.width - width),
+            y: y.clamp(0.0, window.height - height),
+            width,
+            height,
+        }
+    }
+
+    /// Anchors the given bounds to the closest corner of the window, pulling them into the
+    /// corner when within the snap distance.
+    fn anchored(&self, bounds: Rectangle, window: Size, snap_distance: f32) -> Self {
+        let center = bounds.center();
+
+        let corner = match (center.x < window.width / 2.0, center.y < window.height / 2.0) {
+            (true, true) => Corner::TopLeft,
+            (false, true) => Corner::TopRight,
+            (true, false) => Corner::BottomLeft,
+            (false, false) => Corner::BottomRight,
+        };
+
+        let x = if corner.is_left() {
+            bounds.x
+        } else {
+            window.width - bounds.x - bounds.width
+        };
+
+        let y = if corner.is_top() {
+            bounds.y
+        } else {
+            window.height - bounds.y - bounds.height
+        };
+
+        let snap = |distance: f32| if distance < snap_distance { MARGIN } else { distance };
+
+        Self {
+            corner,
+            x: snap(x),
+            y: snap(y),
+            ..*self
+        }
+    }
+}
+
+/// The space between a snapped panel and the edges of the window.
+const MARGIN: f32 = 16.0;
+
+/// The size of the pill of a minimized panel.
+const PILL_SIZE: Size = Size::new(160.0, 32.0);
+
+/// The height of the title bar of a panel.
+const TITLE_HEIGHT: f32 = 28.0;
+
+/// The horizontal space between the title and the edges of the title bar.
+const TITLE_PADDING: f32 = 10.0;
+
+/// The distance from the edges of a panel that grabs them for resizing.
+const RESIZE_REACH: f32 = 6.0;
+
+/// The distance the pill has to be dragged before the drag stops being a click.
+const CLICK_SLOP: f32 = 4.0;
+
+/// A panel of content floating above the content below it.
+pub struct FloatingPanel<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    base: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    title: String,
+    placement: Placement,
+    on_change: Box<dyn Fn(Placement) -> Message + 'a>,
+    min_size: Size,
+    snap_distance: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> FloatingPanel<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default smallest size a [`FloatingPanel`] can be resized to.
+    pub const DEFAULT_MIN_SIZE: Size = Size::new(160.0, 90.0);
+
+    /// The default distance from a corner within which a released [`FloatingPanel`] snaps into it.
+    pub const DEFAULT_SNAP_DISTANCE: f32 = 48.0;
+
+    /// Creates a new [`FloatingPanel`] with the given title and content floating above the base
+    /// content at the given [`Placement`], and the message to produce with the changed one.
+    pub fn new(
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        title: impl Into<String>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        placement: Placement,
+        on_change: impl Fn(Placement) -> Message + 'a,
+    ) -> Self {
+        Self {
+            base: base.into(),
+            content: content.into(),
+            title: title.into(),
+            placement,
+            on_change: Box::new(on_change),
+            min_size: Self::DEFAULT_MIN_SIZE,
+            snap_distance: Self::DEFAULT_SNAP_DISTANCE,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the smallest size the panel can be resized to.
+    pub fn min_size(mut self, min_size: impl Into<Size>) -> Self {
+        self.min_size = min_size.into();
+        self
+    }
+
+    /// Sets the distance from a corner within which a released panel snaps into it.
+    ///
+    /// A zero distance never snaps.
+    pub fn snap_distance(mut self, snap_distance: impl Into<Pixels>) -> Self {
+        self.snap_distance = snap_distance.into().0;
+        self
+    }
+
+    /// Sets the text size of the title.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the title.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`FloatingPanel`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`FloatingPanel`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The part of the panel being dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Grab {
+    Move,
+    Resize {
+        left: bool,
+        top: bool,
+        right: bool,
+        bottom: bool,
+    },
+    Minimize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    grab: Grab,
+    origin: Point,
+    start: Rectangle,
+    current: Rectangle,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    drag: Option<Drag>,
+    window: Size,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for FloatingPanel<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.base), Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.base, &self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.base.as_widget().size()
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.base
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.base.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.base
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.base
+            .as_widget()
+            .draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        self.base
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree { state, children, .. } = tree;
+        let (base, content) = children.split_at_mut(1);
+
+        let panel = overlay::Element::new(Box::new(Overlay {
+            state: state.downcast_mut::<State>(),
+            content: &mut self.content,
+            tree: &mut content[0],
+            title: &self.title,
+            placement: self.placement,
+            on_change: self.on_change.as_ref(),
+            min_size: self.min_size,
+            snap_distance: self.snap_distance,
+            text_size: self.text_size,
+            font: self.font,
+            class: &self.class,
+        }));
+
+        // The overlays of the content below stay open, under the panel.
+        let base = self
+            .base
+            .as_widget_mut()
+            .overlay(&mut base[0], layout, renderer, viewport, translation);
+
+        Some(match base {
+            Some(base) => overlay::Group::with_children(vec![base, panel]).overlay(),
+            None => panel,
+        })
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    state: &'a mut State,
+    content: &'a mut Element<'b, Message, Theme, Renderer>,
+    tree: &'a mut Tree,
+    title: &'a str,
+    placement: Placement,
+    on_change: &'a dyn Fn(Placement) -> Message,
+    min_size: Size,
+    snap_distance: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: &'a Theme::Class<'b>,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the bounds of the panel, or of its pill when minimized, as they are dragged.
+    fn bounds(&self) -> Rectangle {
+        match self.state.drag {
+            Some(drag) => drag.current,
+            None if self.placement.minimized => self.placement.pill_bounds(self.state.window),
+            None => self.placement.bounds(self.state.window),
+        }
+    }
+
+    /// Returns the bounds of the minimize button at the end of the title bar.
+    fn minimize_bounds(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + bounds.width - TITLE_HEIGHT,
+            y: bounds.y,
+            width: TITLE_HEIGHT,
+            height: TITLE_HEIGHT.min(bounds.height),
+        }
+    }
+
+    /// Returns the part of the panel grabbed at the given position, if any.
+    fn grab_at(&self, position: Point) -> Option<Grab> {
+        let bounds = self.bounds();
+
+        if self.placement.minimized {
+            return bounds.contains(position).then_some(Grab::Move);
+        }
+
+        if !bounds.expand(RESIZE_REACH).contains(position) {
+            return None;
+        }
+
+        let near = |a: f32, b: f32| (a - b).abs() <= RESIZE_REACH;
+
+        let left = near(position.x, bounds.x);
+        let right = !left && near(position.x, bounds.x + bounds.width);
+        let top = near(position.y, bounds.y);
+        let bottom = !top && near(position.y, bounds.y + bounds.height);
+
+        if left || right || top || bottom {
+            Some(Grab::Resize {
+                left,
+                top,
+                right,
+                bottom,
+            })
+        } else if self.minimize_bounds(bounds).contains(position) {
+            Some(Grab::Minimize)
+        } else if position.y < bounds.y + TITLE_HEIGHT {
+            Some(Grab::Move)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the bounds of the panel after dragging the grabbed part by the given distance,
+    /// kept inside the window.
+    fn drag(&self, drag: Drag, delta: Vector) -> Rectangle {
+        let window = self.state.window;
+        let start = drag.start;
+
+        let Grab::Resize {
+            left: grab_left,
+            top: grab_top,
+            right: grab_right,
+            bottom: grab_bottom,
+        } = drag.grab
+        else {
+            let x = (start.x + delta.x).clamp(0.0, (window.width - start.width).max(0.0));
+            let y = (start.y + delta.y).clamp(0.0, (window.height - start.height).max(0.0));
+
+            return Rectangle { x, y, ..start };
+        };
+
+        let min_width = self.min_size.width.min(window.width);
+        let min_height = self.min_size.height.max(TITLE_HEIGHT).min(window.height);
+
+        let mut left = start.x;
+        let mut top = start.y;
+        let mut right = start.x + start.width;
+        let mut bottom = start.y + start.height;
+
+        // Unlike `clamp`, these do not panic when the panel starts out smaller than the minimum.
+        if grab_left {
+            left = (left + delta.x).min(right - min_width).max(0.0);
+        }
+        if grab_right {
+            right = (right + delta.x).max(left + min_width).min(window.width);
+        }
+        if grab_top {
+            top = (top + delta.y).min(bottom - min_height).max(0.0);
+        }
+        if grab_bottom {
+            bottom = (bottom + delta.y).max(top + min_height).min(window.height);
+        }
+
+        Rectangle {
+            x: left,
+            y: top,
+            width: right - left,
+            height: bottom - top,
+        }
+    }
+
+    fn press(&mut self, position: Point, shell: &mut Shell<'_, Message>) -> bool {
+        let Some(grab) = self.grab_at(position) else {
+            return false;
+        };
+
+        if grab == Grab::Minimize {
+            shell.publish((self.on_change)(Placement {
+                minimized: true,
+                ..self.placement
+            }));
+        } else {
+            let bounds = self.bounds();
+
+            self.state.drag = Some(Drag {
+                grab,
+                origin: position,
+                start: bounds,
+                current: bounds,
+            });
+        }
+
+        shell.capture_event();
+
+        true
+    }
+
+    fn release(&mut self, shell: &mut Shell<'_, Message>) {
+        let Some(drag) = self.state.drag.take() else {
+            return;
+        };
+
+        let moved = Vector::new(drag.current.x - drag.start.x, drag.current.y - drag.start.y);
+
+        // Clicking the pill restores the panel where it was.
+        let placement = if self.placement.minimized && moved.x.hypot(moved.y) < CLICK_SLOP {
+            Placement {
+                minimized: false,
+                ..self.placement
+            }
+        } else {
+            let placement = self
+                .placement
+                .anchored(drag.current, self.state.window, self.snap_distance);
+
+            if self.placement.minimized {
+                placement
+            } else {
+                Placement {
+                    width: drag.current.width,
+                    height: drag.current.height,
+                    ..placement
+                }
+            }
+        };
+
+        if placement != self.placement {
+            shell.publish((self.on_change)(placement));
+        }
+
+        shell.request_redraw();
+    }
+
+    fn title_text(&self, bounds: Rectangle, renderer: &Renderer) -> text::Text<String, Renderer::Font> {
+        text::Text {
+            content: self.title.to_owned(),
+            bounds: Size::new((bounds.width - TITLE_PADDING * 2.0).max(0.0), bounds.height),
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> iced_core::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.state.window = bounds;
+
+        if self.placement.minimized {
+            return layout::Node::new(bounds);
+        }
+
+        let panel = self.bounds();
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(panel.width, (panel.height - TITLE_HEIGHT).max(0.0)),
+        );
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(panel.x, panel.y + TITLE_HEIGHT));
+
+        layout::Node::with_children(bounds, vec![content])
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position()
+                    && self.press(position, shell)
+                {
+                    return;
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+                if self.press(*position, shell) {
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = self.state.drag {
+                    let current = self.drag(drag, *position - drag.origin);
+
+                    self.state.drag = Some(Drag { current, ..drag });
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if self.state.drag.is_some() =>
+            {
+                self.release(shell);
+                shell.capture_event();
+                return;
+            }
+            _ => {}
+        }
+
+        let bounds = self.bounds();
+
+        if let Some(content_layout) = layout.children().next() {
+            self.content.as_widget_mut().update(
+                self.tree,
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                &bounds,
+            );
+        }
+
+        // Presses and scrolls over the panel must not reach the content below it.
+        let is_pointer_event = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_) | mouse::Event::WheelScrolled { .. })
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+
+        if is_pointer_event && cursor.is_over(bounds) {
+            shell.capture_event();
+        }
+    }
+
+    fn mouse_interaction(&self, layout: Layout<'_>, cursor: mouse::Cursor, renderer: &Renderer) -> mouse::Interaction {
+        let grab = self
+            .state
+            .drag
+            .map(|drag| drag.grab)
+            .or_else(|| cursor.position().and_then(|position| self.grab_at(position)));
+
+        match grab {
+            Some(Grab::Move) if self.state.drag.is_some() => mouse::Interaction::Grabbing,
+            Some(Grab::Move) if self.placement.minimized => mouse::Interaction::Pointer,
+            Some(Grab::Move) => mouse::Interaction::Grab,
+            Some(Grab::Minimize) => mouse::Interaction::Pointer,
+            Some(Grab::Resize {
+                left,
+                top,
+                right,
+                bottom,
+            }) => match (left || right, top || bottom) {
+                (true, false) => mouse::Interaction::ResizingHorizontally,
+                (false, true) => mouse::Interaction::ResizingVertically,
+                _ if (left && top) || (right && bottom) => mouse::Interaction::ResizingDiagonallyDown,
+                _ => mouse::Interaction::ResizingDiagonallyUp,
+            },
+            None => layout
+                .children()
+                .next()
+                .map_or_else(mouse::Interaction::default, |content_layout| {
+                    self.content.as_widget().mouse_interaction(
+                        self.tree,
+                        content_layout,
+                        cursor,
+                        &self.bounds(),
+                        renderer,
+                    )
+                }),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = self.bounds();
+
+        let status = if self.state.drag.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = theme.style(self.class, status);
+
+        if self.placement.minimized {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: border::rounded(bounds.height / 2.0),
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                style.title_background,
+            );
+
+            renderer.fill_text(
+                self.title_text(bounds, renderer),
+                Point::new(bounds.x + TITLE_PADDING, bounds.center_y()),
+                style.title_color,
+                bounds,
+            );
+
+            return;
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let title_bar = Rectangle {
+            height: TITLE_HEIGHT.min(bounds.height),
+            ..bounds
+        };
+        let radius = style.border.radius;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: title_bar,
+                border: Border {
+                    radius: border::Radius {
+                        bottom_right: 0.0,
+                        bottom_left: 0.0,
+                        ..radius
+                    },
+                    ..Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            style.title_background,
+        );
+
+        let minimize = self.minimize_bounds(bounds);
+
+        renderer.fill_text(
+            self.title_text(
+                Rectangle {
+                    width: title_bar.width - minimize.width,
+                    ..title_bar
+                },
+                renderer,
+            ),
+            Point::new(title_bar.x + TITLE_PADDING, title_bar.center_y()),
+            style.title_color,
+            title_bar,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: minimize.center_x() - 5.0,
+                    y: minimize.center_y().floor(),
+                    width: 10.0,
+                    height: 2.0,
+                },
+                ..renderer::Quad::default()
+            },
+            style.title_color,
+        );
+
+        if let Some(content_layout) = layout.children().next() {
+            let content_bounds = Rectangle {
+                y: bounds.y + title_bar.height,
+                height: bounds.height - title_bar.height,
+                ..bounds
+            };
+
+            renderer.with_layer(content_bounds, |renderer| {
+                self.content.as_widget().draw(
+                    self.tree,
+                    renderer,
+                    theme,
+                    defaults,
+                    content_layout,
+                    cursor,
+                    &content_bounds,
+                );
+            });
+        }
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let bounds = self.bounds();
+
+        // The overlays of the content, like the menu of a pick list, open on top of the panel.
+        layout.children().next().and_then(|content_layout| {
+            self.content
+                .as_widget_mut()
+                .overlay(self.tree, content_layout, renderer, &bounds, Vector::ZERO)
+        })
+    }
+
+    fn index(&self) -> f32 {
+        Priority::Panel.index()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FloatingPanel<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(floating_panel: FloatingPanel<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(floating_panel)
+    }
+}
+
+/// The possible status of a [`FloatingPanel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The panel is resting.
+    Active,
+    /// The cursor is over the panel.
+    Hovered,
+    /// The panel is being moved or resized.
+    Dragged,
+}
+
+/// The appearance of a [`FloatingPanel`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the panel.
+    pub background: Background,
+    /// The [`Border`] of the panel.
+    pub border: Border,
+    /// The [`Shadow`] cast by the panel and its pill.
+    pub shadow: Shadow,
+    /// The [`Background`] of the title bar and of the pill.
+    pub title_background: Background,
+    /// The [`Color`] of the title and of the minimize button.
+    pub title_color: Color,
+}
+
+/// The theme catalog of a [`FloatingPanel`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`FloatingPanel`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`FloatingPanel`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let shadow = Shadow {
+        color: Color {
+            a: 0.25,
+            ..Color::BLACK
+        },
+        offset: Vector::new(0.0, 4.0),
+        blur_radius: 12.0,
+    };
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 8.0.into(),
+        },
+        shadow: match status {
+            Status::Active => shadow,
+            Status::Hovered | Status::Dragged => Shadow {
+                blur_radius: 20.0,
+                ..shadow
+            },
+        },
+        title_background: palette.background.weak.color.into(),
+        title_color: palette.background.weak.text,
+    }
+}
@@ -0,0 +1,546 @@
+//! Pick a font family from a searchable list.
+//!
+//! A [`FontPicker`] reuses the [`Menu`] of a [`MultiPickList`], drawing every
+//! family in its own typeface. Typing while the menu is open filters the families.
+//!
+//! [`Menu`]: crate::multi_pick_list::menu::Menu
+//! [`MultiPickList`]: crate::MultiPickList
+use iced_core::Clipboard;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Font;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Padding;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::alignment;
+use iced_core::keyboard;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::touch;
+use iced_core::widget::tree;
+use iced_core::widget::tree::Tree;
+use iced_core::window;
+
+use crate::multi_pick_list::Catalog;
+use crate::multi_pick_list::Status;
+use crate::multi_pick_list::Style;
+use crate::multi_pick_list::StyleFn;
+use crate::multi_pick_list::menu;
+
+/// A field listing font families, each drawn in its own typeface.
+///
+/// The families are provided by the application, since only fonts loaded into the
+/// renderer can be drawn.
+pub struct FontPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    families: &'a [&'static str],
+    selected: Option<&'static str>,
+    on_select: Box<dyn Fn(&'static str) -> Message + 'a>,
+    placeholder: Option<String>,
+    search_placeholder: String,
+    empty_text: String,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    menu_height: Length,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+    last_status: Option<Status>,
+}
+
+impl<'a, Message, Theme, Renderer> FontPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    /// The default width of a [`FontPicker`].
+    pub const DEFAULT_WIDTH: f32 = 200.0;
+
+    /// Creates a new [`FontPicker`] with the given font families, the selected one, and
+    /// the message to produce when a family is picked.
+    pub fn new(
+        families: &'a [&'static str],
+        selected: Option<&'static str>,
+        on_select: impl Fn(&'static str) -> Message + 'a,
+    ) -> Self {
+        Self {
+            families,
+            selected,
+            on_select: Box::new(on_select),
+            placeholder: None,
+            search_placeholder: String::from("Search..."),
+            empty_text: String::from("No fonts"),
+            width: Length::Fixed(Self::DEFAULT_WIDTH),
+            padding: iced_widget::button::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            menu_height: Length::Shrink,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+            last_status: None,
+        }
+    }
+
+    /// Sets the placeholder of the [`FontPicker`], shown while no family is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the placeholder shown in the [`FontPicker`] while its menu is open and nothing
+    /// has been typed yet.
+    pub fn search_placeholder(mut self, search_placeholder: impl Into<String>) -> Self {
+        self.search_placeholder = search_placeholder.into();
+        self
+    }
+
+    /// Sets the text shown in the menu while no family matches the search.
+    pub fn empty_text(mut self, empty_text: impl Into<String>) -> Self {
+        self.empty_text = empty_text.into();
+        self
+    }
+
+    /// Sets the width of the [`FontPicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`FontPicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`FontPicker`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`FontPicker`].
+    pub fn text_line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the height of the menu of the [`FontPicker`].
+    pub fn menu_height(mut self, menu_height: impl Into<Length>) -> Self {
+        self.menu_height = menu_height.into();
+        self
+    }
+
+    /// Sets the style of the [`FontPicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the menu of the [`FontPicker`].
+    #[must_use]
+    pub fn menu_style(mut self, style: impl Fn(&Theme, menu::Status) -> menu::Style + 'a) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Lists the families matching the given search, ignoring case.
+    fn entries(&self, query: &str) -> Vec<menu::Entry> {
+        let query = query.to_lowercase();
+
+        self.families
+            .iter()
+            .enumerate()
+            .filter(|(_, family)| family.to_lowercase().contains(&query))
+            .map(|(index, _)| menu::Entry::Option(index))
+            .collect()
+    }
+
+    fn open_menu(&self, state: &mut State) {
+        state.is_open = true;
+        state.query.clear();
+        state.hovered_option = None;
+        state.entries = self.entries(&state.query);
+    }
+
+    fn close_menu(&self, state: &mut State) {
+        let _ = state.menu.take_placement();
+        state.is_open = false;
+        state.query.clear();
+        state.hovered_option = None;
+        state.placement = None;
+    }
+
+    /// Filters the families again after the search changed.
+    fn search(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.entries = self.entries(&state.query);
+        state.hovered_option = state.entries.first().and_then(|entry| entry.option());
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+        shell.capture_event();
+    }
+}
+
+struct State {
+    menu: menu::State,
+    is_open: bool,
+    is_focused: bool,
+    query: String,
+    hovered_option: Option<usize>,
+    entries: Vec<menu::Entry>,
+    /// Where the menu opened, once it has been laid out.
+    placement: Option<menu::Placement>,
+}
+
+/// Returns the label of a family in the menu of a [`FontPicker`].
+fn label(family: &&'static str) -> String {
+    family.to_string()
+}
+
+/// Returns the [`Font`] a family is drawn with in the menu of a [`FontPicker`].
+fn typeface(family: &&'static str) -> Option<Font> {
+    Some(Font::with_name(*family))
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for FontPicker<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            menu: menu::State::new(),
+            is_open: false,
+            is_focused: false,
+            query: String::new(),
+            hovered_option: None,
+            entries: Vec::new(),
+            placement: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let intrinsic = Size::new(0.0, f32::from(self.text_line_height.to_absolute(text_size)));
+
+        layout::Node::new(
+            limits
+                .width(self.width)
+                .shrink(self.padding)
+                .resolve(self.width, Length::Shrink, intrinsic)
+                .expand(self.padding),
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The menu is dismissed once a family is picked, so the picker closes on its next update.
+        if state.menu.take_dismissed() && state.is_open {
+            self.close_menu(state);
+            shell.request_redraw();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if state.is_open {
+                    // The press was not captured by the menu, so it happened outside of it.
+                    self.close_menu(state);
+                    state.is_focused = cursor.is_over(layout.bounds());
+                    shell.request_redraw();
+                    shell.capture_event();
+                } else if cursor.is_over(layout.bounds()) {
+                    self.open_menu(state);
+                    state.is_focused = true;
+                    shell.request_redraw();
+                    shell.capture_event();
+                } else {
+                    state.is_focused = false;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, text, modifiers, .. }) if state.is_open => {
+                match key {
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                        self.close_menu(state);
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                        let family = state
+                            .hovered_option
+                            .or_else(|| state.entries.first().and_then(|entry| entry.option()))
+                            .and_then(|index| self.families.get(index).copied());
+
+                        if let Some(family) = family {
+                            shell.publish((self.on_select)(family));
+                        }
+
+                        self.close_menu(state);
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                        if state.query.pop().is_some() {
+                            self.search(state, shell);
+                        }
+                    }
+                    _ => {
+                        if let Some(text) = text
+                            && !modifiers.command()
+                            && !text.chars().any(char::is_control)
+                        {
+                            state.query.push_str(text);
+                            self.search(state, shell);
+                        }
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Enter | keyboard::key::Named::Space),
+                ..
+            }) if state.is_focused => {
+                self.open_menu(state);
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Window(window::Event::Unfocused) if state.is_open => {
+                self.close_menu(state);
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+
+        if state.is_open
+            && let Some(placement) = state.menu.take_placement()
+        {
+            state.placement = Some(placement);
+        }
+
+        let status = {
+            let is_hovered = cursor.is_over(layout.bounds());
+            let selected_count = usize::from(self.selected.is_some());
+
+            if state.is_open {
+                Status::Opened {
+                    is_hovered,
+                    is_above: state.placement == Some(menu::Placement::Above),
+                    selected_count,
+                }
+            } else if is_hovered {
+                Status::Hovered { selected_count }
+            } else if state.is_focused {
+                Status::Focused { selected_count }
+            } else {
+                Status::Active { selected_count }
+            }
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_status = Some(status);
+        } else if self.last_status.is_some_and(|last_status| last_status != status) {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = Catalog::style(
+            theme,
+            &self.class,
+            self.last_status.unwrap_or(Status::Active {
+                selected_count: usize::from(self.selected.is_some()),
+            }),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_text(
+            text::Text {
+                content: Renderer::ARROW_DOWN_ICON.to_string(),
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                font: Renderer::ICON_FONT,
+                bounds: Size::new(bounds.width, f32::from(text::LineHeight::default().to_absolute(text_size))),
+                align_x: text::Alignment::Right,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::default(),
+            },
+            Point::new(bounds.x + bounds.width - self.padding.right, bounds.center_y()),
+            style.handle_color,
+            *viewport,
+        );
+
+        // The field shows the search while open, and the selected family in its own typeface otherwise.
+        let (content, font, color) = if state.is_open {
+            if state.query.is_empty() {
+                (self.search_placeholder.clone(), renderer.default_font(), style.placeholder_color)
+            } else {
+                (state.query.clone(), renderer.default_font(), style.text_color)
+            }
+        } else if let Some(family) = self.selected {
+            (family.to_owned(), Font::with_name(family), style.text_color)
+        } else if let Some(placeholder) = &self.placeholder {
+            (placeholder.clone(), renderer.default_font(), style.placeholder_color)
+        } else {
+            return;
+        };
+
+        renderer.fill_text(
+            text::Text {
+                content,
+                bounds: Size::new(
+                    (bounds.width - self.padding.x() - text_size.0).max(0.0),
+                    bounds.height,
+                ),
+                size: text_size,
+                line_height: self.text_line_height,
+                font,
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            },
+            Point::new(bounds.x + self.padding.left, bounds.center_y()),
+            color,
+            bounds,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut menu = menu::Menu::new(
+            &mut state.menu,
+            self.families,
+            self.selected.as_slice(),
+            &mut state.hovered_option,
+            self.on_select.as_ref(),
+            &label,
+            &self.menu_class,
+        )
+        .mode(menu::Mode::Single)
+        .width(bounds.width)
+        .padding(self.padding)
+        .text_line_height(self.text_line_height)
+        .text_shaping(text::Shaping::Advanced)
+        .option_font(&typeface)
+        .type_ahead(false)
+        .entries(&state.entries)
+        .empty_text(&self.empty_text);
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(
+            layout.position() + translation,
+            *viewport,
+            bounds.height,
+            self.menu_height,
+        ))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FontPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(font_picker: FontPicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(font_picker)
+    }
+}
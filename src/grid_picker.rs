@@ -0,0 +1,677 @@
+//! Select cells of a grid, like seats, pixels or schedule slots.
+//!
+//! A [`GridPicker`] is the two dimensional counterpart of a [`MultiPickList`]: the selection
+//! is owned by the application, and dragging across the grid toggles a whole rectangle of
+//! cells with a single message.
+//!
+//! [`MultiPickList`]: crate::MultiPickList
+use iced_core::Background;
+use iced_core::Border;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Theme;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::alignment;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text::Paragraph;
+use iced_core::text::{self};
+use iced_core::touch;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+
+/// The position of a cell in a [`GridPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cell {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Cell {
+    /// Creates a new [`Cell`] at the given row and column.
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+}
+
+/// A grid of cells that can be selected by clicking or by dragging a rectangle over them.
+pub struct GridPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    rows: usize,
+    columns: usize,
+    selected: &'a [Cell],
+    on_select: Box<dyn Fn(Vec<Cell>, bool) -> Message + 'a>,
+    disabled: Option<Box<dyn Fn(Cell) -> bool + 'a>>,
+    row_labels: Vec<String>,
+    column_labels: Vec<String>,
+    legend: Option<[String; 3]>,
+    cell_size: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> GridPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default size of the cells of a [`GridPicker`].
+    pub const DEFAULT_CELL_SIZE: f32 = 24.0;
+
+    /// The default spacing between the cells of a [`GridPicker`].
+    pub const DEFAULT_SPACING: f32 = 4.0;
+
+    /// The space between the labels and the cells.
+    const LABEL_SPACING: f32 = 6.0;
+
+    /// The space between the cells and the legend.
+    const LEGEND_SPACING: f32 = 10.0;
+
+    /// Creates a new [`GridPicker`] with the given amount of rows and columns, the selected
+    /// cells, and the message to produce with the toggled cells and whether they are being
+    /// selected.
+    pub fn new(
+        rows: usize,
+        columns: usize,
+        selected: &'a [Cell],
+        on_select: impl Fn(Vec<Cell>, bool) -> Message + 'a,
+    ) -> Self {
+        Self {
+            rows,
+            columns,
+            selected,
+            on_select: Box::new(on_select),
+            disabled: None,
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+            legend: None,
+            cell_size: Self::DEFAULT_CELL_SIZE,
+            spacing: Self::DEFAULT_SPACING,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the closure deciding which cells cannot be selected, like taken seats.
+    pub fn disabled(mut self, disabled: impl Fn(Cell) -> bool + 'a) -> Self {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
+    /// Sets the labels drawn in front of every row.
+    pub fn row_labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.row_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the labels drawn above every column.
+    pub fn column_labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.column_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the legend drawn below the grid, naming available, selected and disabled cells.
+    pub fn legend(
+        mut self,
+        available: impl Into<String>,
+        selected: impl Into<String>,
+        disabled: impl Into<String>,
+    ) -> Self {
+        self.legend = Some([available.into(), selected.into(), disabled.into()]);
+        self
+    }
+
+    /// Sets the size of the cells of the [`GridPicker`].
+    pub fn cell_size(mut self, cell_size: impl Into<Pixels>) -> Self {
+        self.cell_size = cell_size.into().0;
+        self
+    }
+
+    /// Sets the spacing between the cells of the [`GridPicker`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the labels and the legend.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the labels and the legend.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`GridPicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`GridPicker`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_disabled(&self, cell: Cell) -> bool {
+        self.disabled.as_ref().is_some_and(|disabled| disabled(cell))
+    }
+
+    fn is_selected(&self, cell: Cell) -> bool {
+        self.selected.contains(&cell)
+    }
+
+    fn text<C>(&self, content: C, renderer: &Renderer) -> text::Text<C, Renderer::Font> {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        text::Text {
+            content,
+            bounds: Size::INFINITE,
+            size,
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::default(),
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(text::LineHeight::default().to_absolute(size))
+    }
+
+    fn width_of(&self, content: &str, renderer: &Renderer) -> f32 {
+        Renderer::Paragraph::with_text(self.text(content, renderer)).min_width()
+    }
+
+    /// Returns the offset of the first cell from the top left corner of the [`GridPicker`].
+    fn origin(&self, renderer: &Renderer) -> Vector {
+        let x = self
+            .row_labels
+            .iter()
+            .map(|label| self.width_of(label, renderer))
+            .fold(0.0, f32::max);
+
+        let x = if x > 0.0 { x + Self::LABEL_SPACING } else { 0.0 };
+        let y = if self.column_labels.is_empty() {
+            0.0
+        } else {
+            self.line_height(renderer) + Self::LABEL_SPACING
+        };
+
+        Vector::new(x, y)
+    }
+
+    fn cells_size(&self) -> Size {
+        let extent = |count: usize| {
+            if count == 0 {
+                0.0
+            } else {
+                count as f32 * (self.cell_size + self.spacing) - self.spacing
+            }
+        };
+
+        Size::new(extent(self.columns), extent(self.rows))
+    }
+
+    /// Returns the screen bounds of the given cell.
+    fn cell_bounds(&self, origin: Point, cell: Cell) -> Rectangle {
+        let step = self.cell_size + self.spacing;
+
+        Rectangle {
+            x: origin.x + cell.column as f32 * step,
+            y: origin.y + cell.row as f32 * step,
+            width: self.cell_size,
+            height: self.cell_size,
+        }
+    }
+
+    /// Returns the cell under the given position, if any.
+    fn cell_at(&self, origin: Point, position: Point) -> Option<Cell> {
+        let cell = self.nearest_cell(origin, position)?;
+
+        self.cell_bounds(origin, cell).contains(position).then_some(cell)
+    }
+
+    /// Returns the cell closest to the given position, clamped to the grid.
+    fn nearest_cell(&self, origin: Point, position: Point) -> Option<Cell> {
+        if self.rows == 0 || self.columns == 0 {
+            return None;
+        }
+
+        let step = self.cell_size + self.spacing;
+        let index = |offset: f32, count: usize| ((offset / step).floor().max(0.0) as usize).min(count - 1);
+
+        Some(Cell::new(
+            index(position.y - origin.y, self.rows),
+            index(position.x - origin.x, self.columns),
+        ))
+    }
+}
+
+/// Returns the cells in the rectangle spanned by the given corners.
+fn span(from: Cell, to: Cell) -> impl Iterator<Item = Cell> {
+    let rows = from.row.min(to.row)..=from.row.max(to.row);
+    let columns = from.column.min(to.column)..=from.column.max(to.column);
+
+    rows.flat_map(move |row| columns.clone().map(move |column| Cell::new(row, column)))
+}
+
+fn in_span(cell: Cell, from: Cell, to: Cell) -> bool {
+    (from.row.min(to.row)..=from.row.max(to.row)).contains(&cell.row)
+        && (from.column.min(to.column)..=from.column.max(to.column)).contains(&cell.column)
+}
+
+/// A rectangle being dragged over the grid.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    anchor: Cell,
+    current: Cell,
+    select: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    drag: Option<Drag>,
+    hovered: Option<Cell>,
+    origin: Vector,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for GridPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        // Measuring the labels is costly, so the origin of the cells is kept for events and drawing.
+        let origin = self.origin(renderer);
+        tree.state.downcast_mut::<State>().origin = origin;
+        let cells = self.cells_size();
+
+        let legend = if self.legend.is_some() {
+            Self::LEGEND_SPACING + self.line_height(renderer)
+        } else {
+            0.0
+        };
+
+        let size = Size::new(origin.x + cells.width, origin.y + cells.height + legend);
+
+        layout::Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let origin = bounds.position() + state.origin;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds)
+                    && let Some(cell) = self.cell_at(origin, position)
+                    && !self.is_disabled(cell)
+                {
+                    state.drag = Some(Drag {
+                        anchor: cell,
+                        current: cell,
+                        select: !self.is_selected(cell),
+                    });
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(drag) = &mut state.drag else {
+                    let hovered = cursor
+                        .position_over(bounds)
+                        .and_then(|position| self.cell_at(origin, position));
+
+                    if hovered != state.hovered {
+                        state.hovered = hovered;
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                // The rectangle keeps following the pointer outside of the grid, up to its edges.
+                if let Some(position) = cursor.position()
+                    && let Some(cell) = self.nearest_cell(origin, position)
+                    && cell != drag.current
+                {
+                    drag.current = cell;
+                    shell.request_redraw();
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(drag) = state.drag.take() {
+                    let cells: Vec<_> = span(drag.anchor, drag.current)
+                        .filter(|cell| !self.is_disabled(*cell) && self.is_selected(*cell) != drag.select)
+                        .collect();
+
+                    if !cells.is_empty() {
+                        shell.publish((self.on_select)(cells, drag.select));
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let origin = bounds.position() + state.origin;
+        let line_height = self.line_height(renderer);
+
+        let label_color = theme
+            .style(&self.class, Status::Active)
+            .text_color
+            .unwrap_or(style.text_color);
+
+        for (column, label) in self.column_labels.iter().enumerate().take(self.columns) {
+            let cell = self.cell_bounds(origin, Cell::new(0, column));
+
+            renderer.fill_text(
+                text::Text {
+                    bounds: Size::new(cell.width + self.spacing, line_height),
+                    align_x: text::Alignment::Center,
+                    ..self.text(label.clone(), renderer)
+                },
+                Point::new(cell.center_x(), bounds.y + line_height / 2.0),
+                label_color,
+                *viewport,
+            );
+        }
+
+        for (row, label) in self.row_labels.iter().enumerate().take(self.rows) {
+            let cell = self.cell_bounds(origin, Cell::new(row, 0));
+
+            renderer.fill_text(
+                text::Text {
+                    bounds: Size::new(origin.x - bounds.x, cell.height),
+                    ..self.text(label.clone(), renderer)
+                },
+                Point::new(bounds.x, cell.center_y()),
+                label_color,
+                *viewport,
+            );
+        }
+
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let cell = Cell::new(row, column);
+                let cell_bounds = self.cell_bounds(origin, cell);
+
+                if !cell_bounds.intersects(viewport) {
+                    continue;
+                }
+
+                let is_disabled = self.is_disabled(cell);
+
+                // Cells under the dragged rectangle preview the selection they are about to get.
+                let is_selected = match state.drag {
+                    Some(drag) if !is_disabled && in_span(cell, drag.anchor, drag.current) => drag.select,
+                    _ => self.is_selected(cell),
+                };
+
+                let status = if is_disabled {
+                    Status::Disabled
+                } else if is_selected {
+                    Status::Selected
+                } else if state.drag.is_none() && state.hovered == Some(cell) {
+                    Status::Hovered
+                } else {
+                    Status::Active
+                };
+
+                let style = theme.style(&self.class, status);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: cell_bounds,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+            }
+        }
+
+        if let Some(legend) = &self.legend {
+            let swatch = line_height * 0.7;
+            let y = origin.y + self.cells_size().height + Self::LEGEND_SPACING;
+            let mut x = bounds.x;
+
+            for (label, status) in legend.iter().zip([Status::Active, Status::Selected, Status::Disabled]) {
+                let style = theme.style(&self.class, status);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x,
+                            y: y + (line_height - swatch) / 2.0,
+                            width: swatch,
+                            height: swatch,
+                        },
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+
+                x += swatch + Self::LABEL_SPACING;
+
+                renderer.fill_text(
+                    self.text(label.clone(), renderer),
+                    Point::new(x, y + line_height / 2.0),
+                    label_color,
+                    *viewport,
+                );
+
+                x += self.width_of(label, renderer) + Self::LEGEND_SPACING * 2.0;
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Crosshair;
+        }
+
+        match state.hovered {
+            Some(cell) if cursor.is_over(layout.bounds()) && !self.is_disabled(cell) => mouse::Interaction::Pointer,
+            Some(_) if cursor.is_over(layout.bounds()) => mouse::Interaction::NotAllowed,
+            _ => mouse::Interaction::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<GridPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(grid_picker: GridPicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(grid_picker)
+    }
+}
+
+/// The status of a cell of a [`GridPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The cell is neither selected nor hovered.
+    Active,
+    /// The cell is hovered.
+    Hovered,
+    /// The cell is selected.
+    Selected,
+    /// The cell cannot be selected.
+    Disabled,
+}
+
+/// The appearance of a cell of a [`GridPicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the cell.
+    pub background: Background,
+    /// The [`Border`] of the cell.
+    pub border: Border,
+    /// The text [`Color`] of the labels and the legend, taken from [`Status::Active`].
+    pub text_color: Option<Color>,
+}
+
+/// The theme catalog of a [`GridPicker`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`GridPicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`GridPicker`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: palette.background.weak.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 3.0.into(),
+        },
+        text_color: None,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Selected => Style {
+            background: palette.primary.strong.color.into(),
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Disabled => Style {
+            background: palette.background.strong.color.into(),
+            border: Border {
+                color: palette.background.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+    }
+}
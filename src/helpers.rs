@@ -0,0 +1,350 @@
+//! Helper functions to create the widgets of the crate.
+use std::borrow::Borrow;
+use std::ops::RangeInclusive;
+
+use iced_core::Element;
+use iced_core::Font;
+use iced_core::Pixels;
+use iced_core::Rectangle;
+use iced_core::image;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::widget::Tree;
+
+use crate::AnchoredOverlay;
+use crate::AnimatedList;
+use crate::BottomSheet;
+use crate::CardInput;
+use crate::Cropper;
+use crate::FloatingPanel;
+use crate::FontPicker;
+use crate::GridPicker;
+use crate::HoldButton;
+use crate::Keypad;
+use crate::Marquee;
+use crate::MeasurementInput;
+use crate::MultiPickList;
+use crate::OnScreenKeyboard;
+use crate::PhoneInput;
+use crate::ProgressBar;
+use crate::QrCode;
+use crate::ScheduleBuilder;
+use crate::SignaturePad;
+use crate::SliderBank;
+use crate::SquareRadio;
+use crate::TreeTable;
+use crate::VirtualList;
+use crate::WeekPlanner;
+use crate::bottom_sheet;
+use crate::card_input::Card;
+use crate::cropper;
+use crate::floating_panel;
+use crate::grid_picker;
+use crate::hold_button;
+use crate::keypad;
+use crate::marquee;
+use crate::measurement_input;
+use crate::multi_pick_list;
+use crate::on_screen_keyboard;
+use crate::phone_input::PhoneNumber;
+use crate::progress_bar_ext;
+use crate::qr_code;
+use crate::schedule_builder::Schedule;
+use crate::signature_pad;
+use crate::signature_pad::Signature;
+use crate::signature_pad::Stroke;
+use crate::slider_bank;
+use crate::square_radio;
+use crate::tree_table;
+use crate::week_planner;
+use crate::week_planner::WeekPlan;
+
+/// Creates a new [`MultiPickList`] with the given options, the selected ones, and the
+/// message to produce when an option is toggled.
+pub fn multi_pick_list<'a, T, L, V, Message, Theme, Renderer>(
+    options: L,
+    selected: V,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> MultiPickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<[T]> + 'a,
+    Message: Clone,
+    Theme: multi_pick_list::Catalog,
+    Renderer: text::Renderer,
+{
+    MultiPickList::new(options, selected, on_select)
+}
+
+/// Creates a new [`SquareRadio`] for the given value, the selected one, and the message
+/// to produce when it is clicked.
+pub fn square_radio<'a, V, Message, Theme, Renderer>(
+    value: V,
+    selection: Option<V>,
+    on_click: impl FnOnce(V) -> Message,
+) -> SquareRadio<'a, Message, Theme, Renderer>
+where
+    V: Eq + Copy,
+    Message: Clone,
+    Theme: square_radio::Catalog,
+    Renderer: text::Renderer,
+{
+    SquareRadio::new(value, selection, on_click)
+}
+
+/// Creates a new [`ProgressBar`] with the given range of values and the current one.
+pub fn progress_bar<'a, Message, Theme, Renderer>(
+    range: RangeInclusive<f32>,
+    value: f32,
+) -> ProgressBar<'a, Message, Theme, Renderer>
+where
+    Theme: progress_bar_ext::Catalog,
+    Renderer: text::Renderer,
+{
+    ProgressBar::new(range, value)
+}
+
+/// Creates a new [`VirtualList`] with the given amount of rows, the height of every row,
+/// and the closure building the row at a given index.
+pub fn virtual_list<'a, Message, Theme, Renderer>(
+    count: usize,
+    row_height: impl Into<Pixels>,
+    view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    VirtualList::new(count, row_height, view)
+}
+
+/// Creates a new [`FontPicker`] with the given font families, the selected one, and the
+/// message to produce when a family is picked.
+pub fn font_picker<'a, Message, Theme, Renderer>(
+    families: &'a [&'static str],
+    selected: Option<&'static str>,
+    on_select: impl Fn(&'static str) -> Message + 'a,
+) -> FontPicker<'a, Message, Theme, Renderer>
+where
+    Theme: multi_pick_list::Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    FontPicker::new(families, selected, on_select)
+}
+
+/// Creates a new [`ScheduleBuilder`] editing the given [`Schedule`], and the message to
+/// produce with the changed one.
+pub fn schedule_builder<'a, Message>(
+    schedule: &'a Schedule,
+    on_change: impl Fn(Schedule) -> Message + 'a,
+) -> ScheduleBuilder<'a, Message> {
+    ScheduleBuilder::new(schedule, on_change)
+}
+
+/// Creates a new [`MeasurementInput`] with the given value and [`measurement_input::Unit`],
+/// and the message to produce with the changed ones.
+pub fn measurement_input<'a, Message>(
+    value: f32,
+    unit: measurement_input::Unit,
+    on_change: impl Fn(f32, measurement_input::Unit) -> Message + 'a,
+) -> MeasurementInput<'a, Message> {
+    MeasurementInput::new(value, unit, on_change)
+}
+
+/// Creates a new [`PhoneInput`] editing the given [`PhoneNumber`], and the message to produce
+/// with the changed one.
+pub fn phone_input<'a, Message>(
+    phone: &'a PhoneNumber,
+    on_change: impl Fn(PhoneNumber) -> Message + 'a,
+) -> PhoneInput<'a, Message> {
+    PhoneInput::new(phone, on_change)
+}
+
+/// Creates a new [`CardInput`] editing the given [`Card`], and the message to produce with the
+/// changed one.
+pub fn card_input<'a, Message>(card: &'a Card, on_change: impl Fn(Card) -> Message + 'a) -> CardInput<'a, Message> {
+    CardInput::new(card, on_change)
+}
+
+/// Creates a new [`Cropper`] over the given image, with the crop rectangle in the pixels of
+/// the image, and the message to produce with the changed rectangle.
+pub fn cropper<'a, Message, Theme>(
+    handle: impl Into<image::Handle>,
+    crop: Rectangle,
+    on_change: impl Fn(Rectangle) -> Message + 'a,
+) -> Cropper<'a, Message, Theme>
+where
+    Theme: cropper::Catalog,
+{
+    Cropper::new(handle, crop, on_change)
+}
+
+/// Creates a new [`SignaturePad`] showing the given [`Signature`], and the message to produce
+/// with every finished [`Stroke`].
+pub fn signature_pad<'a, Message, Theme>(
+    signature: &'a Signature,
+    on_stroke: impl Fn(Stroke) -> Message + 'a,
+) -> SignaturePad<'a, Message, Theme>
+where
+    Theme: signature_pad::Catalog,
+{
+    SignaturePad::new(signature, on_stroke)
+}
+
+/// Creates a new [`GridPicker`] with the given amount of rows and columns, the selected cells,
+/// and the message to produce with the toggled cells and whether they are being selected.
+pub fn grid_picker<'a, Message, Theme, Renderer>(
+    rows: usize,
+    columns: usize,
+    selected: &'a [grid_picker::Cell],
+    on_select: impl Fn(Vec<grid_picker::Cell>, bool) -> Message + 'a,
+) -> GridPicker<'a, Message, Theme, Renderer>
+where
+    Theme: grid_picker::Catalog,
+    Renderer: text::Renderer,
+{
+    GridPicker::new(rows, columns, selected, on_select)
+}
+
+/// Creates a new [`WeekPlanner`] painting the given [`WeekPlan`], and the message to produce
+/// with the changed one.
+pub fn week_planner<'a, Message, Theme, Renderer>(
+    plan: &'a WeekPlan,
+    on_change: impl Fn(WeekPlan) -> Message + 'a,
+) -> WeekPlanner<'a, Message, Theme, Renderer>
+where
+    Theme: week_planner::Catalog,
+    Renderer: text::Renderer,
+{
+    WeekPlanner::new(plan, on_change)
+}
+
+/// Creates a new [`BottomSheet`] showing the sheet above the base content at the given
+/// [`bottom_sheet::Snap`], and the message to produce with the one it settles on.
+pub fn bottom_sheet<'a, Message, Theme, Renderer>(
+    base: impl Into<Element<'a, Message, Theme, Renderer>>,
+    sheet: impl Into<Element<'a, Message, Theme, Renderer>>,
+    snap: bottom_sheet::Snap,
+    on_snap: impl Fn(bottom_sheet::Snap) -> Message + 'a,
+) -> BottomSheet<'a, Message, Theme, Renderer>
+where
+    Theme: bottom_sheet::Catalog,
+{
+    BottomSheet::new(base, sheet, snap, on_snap)
+}
+
+/// Creates a new [`FloatingPanel`] with the given title and content floating above the base
+/// content at the given [`floating_panel::Placement`], and the message to produce with the
+/// changed one.
+pub fn floating_panel<'a, Message, Theme, Renderer>(
+    base: impl Into<Element<'a, Message, Theme, Renderer>>,
+    title: impl Into<String>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    placement: floating_panel::Placement,
+    on_change: impl Fn(floating_panel::Placement) -> Message + 'a,
+) -> FloatingPanel<'a, Message, Theme, Renderer>
+where
+    Theme: floating_panel::Catalog,
+    Renderer: text::Renderer,
+{
+    FloatingPanel::new(base, title, content, placement, on_change)
+}
+
+/// Creates a new [`HoldButton`] with the given content, and the message to produce once it has
+/// been held down for its whole duration.
+pub fn hold_button<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_complete: Message,
+) -> HoldButton<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: hold_button::Catalog,
+{
+    HoldButton::new(content, on_complete)
+}
+
+/// Creates a new [`AnimatedList`] with the given rows and their keys.
+pub fn animated_list<'a, Key, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+) -> AnimatedList<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: renderer::Renderer,
+{
+    AnimatedList::with_children(children)
+}
+
+/// Creates a new [`Marquee`] with the given text, scrolling when it does not fit.
+pub fn marquee<'a, Theme, Renderer>(content: impl Into<String>) -> Marquee<'a, Theme, Renderer>
+where
+    Theme: marquee::Catalog,
+    Renderer: text::Renderer,
+{
+    Marquee::new(content)
+}
+
+/// Creates a new [`QrCode`] drawing the given [`qr_code::Matrix`].
+pub fn qr_code<'a, Theme>(matrix: &'a qr_code::Matrix) -> QrCode<'a, Theme>
+where
+    Theme: qr_code::Catalog,
+{
+    QrCode::new(matrix)
+}
+
+/// Creates a new [`Keypad`] with the message to produce with every tapped [`keypad::Key`].
+pub fn keypad<'a, Message, Theme, Renderer>(
+    on_key: impl Fn(keypad::Key) -> Message + 'a,
+) -> Keypad<'a, Message, Theme, Renderer>
+where
+    Theme: keypad::Catalog,
+    Renderer: text::Renderer,
+{
+    Keypad::new(on_key)
+}
+
+/// Creates a new [`OnScreenKeyboard`] with the message to produce with every typed
+/// [`on_screen_keyboard::Input`].
+pub fn on_screen_keyboard<'a, Message, Theme, Renderer>(
+    on_input: impl Fn(on_screen_keyboard::Input) -> Message + 'a,
+) -> OnScreenKeyboard<'a, Message, Theme, Renderer>
+where
+    Theme: on_screen_keyboard::Catalog,
+    Renderer: text::Renderer,
+{
+    OnScreenKeyboard::new(on_input)
+}
+
+/// Creates a new [`TreeTable`] with the given top level [`tree_table::Node`]s.
+pub fn tree_table<'a, T, Message, Theme, Renderer>(
+    roots: &'a [tree_table::Node<T>],
+) -> TreeTable<'a, T, Message, Theme, Renderer>
+where
+    Theme: tree_table::Catalog,
+    Renderer: text::Renderer,
+{
+    TreeTable::new(roots)
+}
+
+/// Creates a new [`SliderBank`] with a slider for every value in the given range, and the
+/// message to produce with the index and the new value of a slider that moved.
+pub fn slider_bank<'a, Message, Theme, Renderer>(
+    range: RangeInclusive<f32>,
+    values: &'a [f32],
+    on_change: impl Fn(usize, f32) -> Message + 'a,
+) -> SliderBank<'a, Message, Theme, Renderer>
+where
+    Theme: slider_bank::Catalog,
+    Renderer: text::Renderer,
+{
+    SliderBank::new(range, values, on_change)
+}
+
+/// Creates a new [`AnchoredOverlay`] showing the given content, with its [`Tree`], next to the
+/// given target.
+pub fn anchored_overlay<'a, 'b, Message, Theme, Renderer>(
+    content: &'a mut Element<'b, Message, Theme, Renderer>,
+    tree: &'a mut Tree,
+    target: Rectangle,
+) -> AnchoredOverlay<'a, 'b, Message, Theme, Renderer> {
+    AnchoredOverlay::new(content, tree, target)
+}
@@ -0,0 +1,550 @@
+//! Confirm an action by holding a button down, like deleting something that cannot be undone.
+//!
+//! A [`HoldButton`] has to be pressed for its whole duration before it produces its message.
+//! While held, its [`Indicator`] fills up around the button or along its bottom edge, and
+//! releasing it early, or moving the pointer away, cancels the action and empties it again.
+use iced_core::Background;
+use iced_core::Border;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Padding;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Theme;
+use iced_core::Widget;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::time::Duration;
+use iced_core::time::Instant;
+use iced_core::touch;
+use iced_core::widget::Operation;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+use iced_core::window;
+
+/// How a [`HoldButton`] shows the progress of a hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Indicator {
+    /// A ring tracing the edges of the button clockwise, starting at the top.
+    #[default]
+    Ring,
+    /// A bar filling the bottom edge of the button, from left to right.
+    Bar,
+}
+
+/// A button producing its message only once it has been held down long enough.
+pub struct HoldButton<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_complete: Message,
+    on_cancel: Option<Message>,
+    duration: Duration,
+    indicator: Indicator,
+    thickness: f32,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> HoldButton<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+{
+    /// The default time a [`HoldButton`] has to be held down.
+    pub const DEFAULT_DURATION: Duration = Duration::from_secs(1);
+
+    /// The default thickness of the [`Indicator`] of a [`HoldButton`].
+    pub const DEFAULT_THICKNESS: f32 = 3.0;
+
+    /// The default [`Padding`] of a [`HoldButton`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`HoldButton`] with the given content, and the message to produce once it
+    /// has been held down for its whole duration.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, on_complete: Message) -> Self {
+        let content = content.into();
+        let size = content.as_widget().size_hint();
+
+        Self {
+            content,
+            on_complete,
+            on_cancel: None,
+            duration: Self::DEFAULT_DURATION,
+            indicator: Indicator::default(),
+            thickness: Self::DEFAULT_THICKNESS,
+            width: size.width.fluid(),
+            height: size.height.fluid(),
+            padding: Self::DEFAULT_PADDING,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message to produce when the [`HoldButton`] is released before completing.
+    pub fn on_cancel(mut self, on_cancel: Message) -> Self {
+        self.on_cancel = Some(on_cancel);
+        self
+    }
+
+    /// Sets the time the [`HoldButton`] has to be held down.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets how the [`HoldButton`] shows the progress of a hold.
+    pub fn indicator(mut self, indicator: Indicator) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Sets the thickness of the [`Indicator`] of the [`HoldButton`].
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = thickness.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the width of the [`HoldButton`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`HoldButton`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] around the content of the [`HoldButton`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`HoldButton`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`HoldButton`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Cancels the current hold, if any, producing the cancel message.
+    fn cancel(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        if state.hold.take().is_some() {
+            state.progress = 0.0;
+
+            if let Some(on_cancel) = &self.on_cancel {
+                shell.publish(on_cancel.clone());
+            }
+
+            shell.request_redraw();
+        }
+    }
+
+    /// Draws the filled part of the [`Indicator`] inside the given bounds.
+    fn draw_indicator<Renderer>(&self, renderer: &mut Renderer, bounds: Rectangle, progress: f32, fill: Background)
+    where
+        Renderer: renderer::Renderer,
+    {
+        let thickness = self.thickness.min(bounds.width / 2.0).min(bounds.height / 2.0);
+        let mut strip = |bounds: Rectangle| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                fill,
+            );
+        };
+
+        match self.indicator {
+            Indicator::Bar => strip(Rectangle {
+                y: bounds.y + bounds.height - thickness,
+                width: bounds.width * progress,
+                height: thickness,
+                ..bounds
+            }),
+            Indicator::Ring => {
+                for bounds in ring(bounds, thickness, progress) {
+                    strip(bounds);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the strips tracing the given progress along the edges of the bounds, clockwise from
+/// the middle of the top edge.
+fn ring(bounds: Rectangle, thickness: f32, progress: f32) -> Vec<Rectangle> {
+    let (right, bottom) = (bounds.x + bounds.width, bounds.y + bounds.height);
+    let mut remaining = 2.0 * (bounds.width + bounds.height) * progress;
+    let mut strips = Vec::new();
+
+    for edge in 0..5 {
+        let length = match edge {
+            0 | 4 => bounds.width / 2.0,
+            2 => bounds.width,
+            _ => bounds.height,
+        };
+        let traced = remaining.min(length);
+
+        if traced <= 0.0 {
+            break;
+        }
+
+        // Every edge is traced from where the previous one ended.
+        strips.push(match edge {
+            0 => Rectangle::new(Point::new(bounds.center_x(), bounds.y), Size::new(traced, thickness)),
+            1 => Rectangle::new(Point::new(right - thickness, bounds.y), Size::new(thickness, traced)),
+            2 => Rectangle::new(
+                Point::new(right - traced, bottom - thickness),
+                Size::new(traced, thickness),
+            ),
+            3 => Rectangle::new(Point::new(bounds.x, bottom - traced), Size::new(thickness, traced)),
+            _ => Rectangle::new(Point::new(bounds.x, bounds.y), Size::new(traced, thickness)),
+        });
+
+        remaining -= length;
+    }
+
+    strips
+}
+
+/// A press of a [`HoldButton`] being held, started on the first frame after it.
+#[derive(Debug, Clone, Copy, Default)]
+struct Hold {
+    started: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    hold: Option<Hold>,
+    progress: f32,
+    is_hovered: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for HoldButton<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        layout::padded(limits, self.width, self.height, self.padding, |limits| {
+            self.content
+                .as_widget_mut()
+                .layout(&mut tree.children[0], renderer, limits)
+        })
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(bounds) {
+                    state.hold = Some(Hold::default());
+                    state.progress = 0.0;
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let is_hovered = cursor.is_over(bounds);
+
+                if is_hovered != state.is_hovered {
+                    state.is_hovered = is_hovered;
+                    shell.request_redraw();
+                }
+
+                // Sliding off the button is a way out, like on a regular button.
+                if !is_hovered {
+                    self.cancel(state, shell);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.hold.is_some() {
+                    self.cancel(state, shell);
+                    shell.capture_event();
+                }
+            }
+            Event::Window(window::Event::Unfocused) => {
+                self.cancel(state, shell);
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(hold) = &mut state.hold {
+                    let started = *hold.started.get_or_insert(*now);
+                    let elapsed = now.saturating_duration_since(started);
+
+                    state.progress = if self.duration.is_zero() {
+                        1.0
+                    } else {
+                        (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+                    };
+
+                    if state.progress >= 1.0 {
+                        state.hold = None;
+                        state.progress = 0.0;
+
+                        shell.publish(self.on_complete.clone());
+                    }
+
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if state.hold.is_some() {
+            Status::Held
+        } else if state.is_hovered && cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let appearance = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                ..renderer::Quad::default()
+            },
+            appearance.background,
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+                ..*style
+            },
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        if state.progress > 0.0 {
+            self.draw_indicator(renderer, bounds, state.progress, appearance.progress);
+        }
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<HoldButton<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(hold_button: HoldButton<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(hold_button)
+    }
+}
+
+/// The possible status of a [`HoldButton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`HoldButton`] is idle.
+    Active,
+    /// The [`HoldButton`] is hovered.
+    Hovered,
+    /// The [`HoldButton`] is being held down.
+    Held,
+}
+
+/// The appearance of a [`HoldButton`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the button.
+    pub background: Background,
+    /// The [`Border`] of the button.
+    pub border: Border,
+    /// The [`Color`] of the text of the content.
+    pub text_color: Color,
+    /// The [`Background`] of the filled part of the [`Indicator`].
+    pub progress: Background,
+}
+
+/// The theme catalog of a [`HoldButton`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`HoldButton`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`HoldButton`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: palette.background.weak.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 2.0.into(),
+        },
+        text_color: palette.background.weak.text,
+        progress: palette.danger.base.color.into(),
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered | Status::Held => Style {
+            background: palette.background.strong.color.into(),
+            text_color: palette.background.strong.text,
+            ..active
+        },
+    }
+}
@@ -0,0 +1,483 @@
+//! Enter numbers on touch screens without a keyboard, like on a kiosk.
+//!
+//! A [`Keypad`] shows the digits, a decimal separator, a backspace and a confirm key, and
+//! produces a message with the [`Key`] tapped. It does not own the number being typed: the
+//! application applies every [`Key`] to its field, which [`Key::apply`] does for plain text.
+//! It can be placed below the field, or in a [`BottomSheet`] to slide up like a system keyboard.
+//!
+//! [`BottomSheet`]: crate::BottomSheet
+use iced_core::Background;
+use iced_core::Border;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Text;
+use iced_core::Theme;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::alignment;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::touch;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+
+/// A key of a [`Keypad`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A digit, from `0` to `9`.
+    Digit(u8),
+    /// The decimal separator.
+    Decimal,
+    /// Removes the last character.
+    Backspace,
+    /// Confirms the number.
+    Confirm,
+}
+
+impl Key {
+    /// Applies the [`Key`] to the given text, returning whether it confirmed it.
+    ///
+    /// A decimal separator is only added once, and a leading one is preceded by a zero.
+    pub fn apply(self, text: &mut String) -> bool {
+        match self {
+            Key::Digit(digit) => text.push(char::from(b'0' + digit.min(9))),
+            Key::Decimal if !text.contains('.') => {
+                if text.is_empty() {
+                    text.push('0');
+                }
+
+                text.push('.');
+            }
+            Key::Decimal => {}
+            Key::Backspace => _ = text.pop(),
+            Key::Confirm => return true,
+        }
+
+        false
+    }
+}
+
+/// A grid of keys to enter numbers by tapping them.
+pub struct Keypad<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    on_key: Box<dyn Fn(Key) -> Message + 'a>,
+    has_decimal: bool,
+    confirm_label: String,
+    key_size: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Keypad<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default size of a key of a [`Keypad`].
+    pub const DEFAULT_KEY_SIZE: f32 = 56.0;
+
+    /// The default space between the keys of a [`Keypad`].
+    pub const DEFAULT_SPACING: f32 = 8.0;
+
+    /// Creates a new [`Keypad`] with the message to produce with every tapped [`Key`].
+    pub fn new(on_key: impl Fn(Key) -> Message + 'a) -> Self {
+        Self {
+            on_key: Box::new(on_key),
+            has_decimal: true,
+            confirm_label: String::from("OK"),
+            key_size: Self::DEFAULT_KEY_SIZE,
+            spacing: Self::DEFAULT_SPACING,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets whether the [`Keypad`] has a decimal separator key, for numbers that are not whole.
+    ///
+    /// By default, it does.
+    pub fn decimal(mut self, has_decimal: bool) -> Self {
+        self.has_decimal = has_decimal;
+        self
+    }
+
+    /// Sets the label of the confirm key of the [`Keypad`].
+    pub fn confirm_label(mut self, confirm_label: impl Into<String>) -> Self {
+        self.confirm_label = confirm_label.into();
+        self
+    }
+
+    /// Sets the size of a key of the [`Keypad`].
+    ///
+    /// Keys under 44 pixels are hard to hit with a finger.
+    pub fn key_size(mut self, key_size: impl Into<Pixels>) -> Self {
+        self.key_size = key_size.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the space between the keys of the [`Keypad`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0.max(0.0);
+        self
+    }
+
+    /// Sets the text size of the labels of the keys.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the labels of the keys.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Keypad`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Keypad`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the keys of the [`Keypad`] and their bounds, relative to its top left corner.
+    ///
+    /// The digits are laid out like on a phone keypad, with the confirm key spanning the last row.
+    fn keys(&self) -> Vec<(Key, Rectangle)> {
+        let step = self.key_size + self.spacing;
+        let cell = |column: usize, row: usize| {
+            Rectangle::new(
+                Point::new(column as f32 * step, row as f32 * step),
+                Size::new(self.key_size, self.key_size),
+            )
+        };
+
+        let mut keys: Vec<_> = (1..=9)
+            .map(|digit| {
+                let index = usize::from(digit - 1);
+
+                (Key::Digit(digit), cell(index % 3, index / 3))
+            })
+            .collect();
+
+        if self.has_decimal {
+            keys.push((Key::Decimal, cell(0, 3)));
+        }
+
+        keys.push((Key::Digit(0), cell(1, 3)));
+        keys.push((Key::Backspace, cell(2, 3)));
+        keys.push((
+            Key::Confirm,
+            Rectangle {
+                width: 2.0 * step + self.key_size,
+                ..cell(0, 4)
+            },
+        ));
+
+        keys
+    }
+
+    /// Returns the [`Key`] under the given position, relative to the top left corner.
+    fn key_at(&self, position: Point) -> Option<Key> {
+        self.keys()
+            .into_iter()
+            .find(|(_, bounds)| bounds.contains(position))
+            .map(|(key, _)| key)
+    }
+
+    /// Returns the label of the given [`Key`].
+    fn label(&self, key: Key) -> String {
+        match key {
+            Key::Digit(digit) => digit.to_string(),
+            Key::Decimal => String::from("."),
+            Key::Backspace => String::from("\u{232B}"),
+            Key::Confirm => self.confirm_label.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    pressed: Option<Key>,
+    hovered: Option<Key>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Keypad<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let width = 3.0 * self.key_size + 2.0 * self.spacing;
+        let height = 5.0 * self.key_size + 4.0 * self.spacing;
+
+        layout::atomic(limits, width, height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let key = cursor.position_in(bounds).and_then(|position| self.key_at(position));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if key.is_some() {
+                    state.pressed = key;
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if state.hovered != key {
+                    state.hovered = key;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                // A key is only tapped when released over it, so sliding away from it cancels it.
+                if let Some(pressed) = state.pressed.take() {
+                    if key == Some(pressed) {
+                        shell.publish((self.on_key)(pressed));
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.pressed.take().is_some() {
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_key = cursor
+            .position_in(layout.bounds())
+            .and_then(|position| self.key_at(position))
+            .is_some();
+
+        if is_over_key {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let hovered = state.hovered.filter(|_| cursor.is_over(bounds));
+
+        for (key, key_bounds) in self.keys() {
+            let status = if state.pressed == Some(key) {
+                Status::Pressed
+            } else if hovered == Some(key) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let style = theme.style(&self.class, status);
+            let key_bounds = key_bounds + Vector::new(bounds.x, bounds.y);
+            let (background, text_color) = match key {
+                Key::Confirm => (style.confirm_background, style.confirm_text_color),
+                _ => (style.key_background, style.text_color),
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: key_bounds,
+                    border: style.key_border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+
+            renderer.fill_text(
+                Text {
+                    content: self.label(key),
+                    bounds: key_bounds.size(),
+                    size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                    line_height: text::LineHeight::default(),
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                key_bounds.center(),
+                text_color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Keypad<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(keypad: Keypad<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(keypad)
+    }
+}
+
+/// The possible status of a key of a [`Keypad`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The key is idle.
+    Active,
+    /// The key is hovered.
+    Hovered,
+    /// The key is being pressed.
+    Pressed,
+}
+
+/// The appearance of a key of a [`Keypad`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the keys.
+    pub key_background: Background,
+    /// The [`Border`] of the keys.
+    pub key_border: Border,
+    /// The [`Color`] of the labels of the keys.
+    pub text_color: Color,
+    /// The [`Background`] of the confirm key.
+    pub confirm_background: Background,
+    /// The [`Color`] of the label of the confirm key.
+    pub confirm_text_color: Color,
+}
+
+/// The theme catalog of a [`Keypad`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Keypad`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Keypad`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        key_background: palette.background.weak.color.into(),
+        key_border: Border {
+            radius: 8.0.into(),
+            ..Border::default()
+        },
+        text_color: palette.background.weak.text,
+        confirm_background: palette.primary.base.color.into(),
+        confirm_text_color: palette.primary.base.text,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            key_background: palette.background.strong.color.into(),
+            confirm_background: palette.primary.strong.color.into(),
+            ..active
+        },
+        Status::Pressed => Style {
+            key_background: palette.background.strongest.color.into(),
+            text_color: palette.background.strongest.text,
+            confirm_background: palette.primary.weak.color.into(),
+            confirm_text_color: palette.primary.weak.text,
+            ..active
+        },
+    }
+}
@@ -1,7 +1,58 @@
+pub mod anchored_overlay;
+pub mod animated_list;
+pub mod bottom_sheet;
+pub mod card_input;
+pub mod cropper;
+pub mod debounce;
+pub mod floating_panel;
+pub mod font_picker;
+pub mod grid_picker;
+pub mod helpers;
+pub mod hold_button;
+pub mod keypad;
+pub mod marquee;
+pub mod measurement_input;
 pub mod multi_pick_list;
+pub mod on_screen_keyboard;
+mod parsed_input;
+pub mod phone_input;
+pub mod prelude;
 pub mod progress_bar_ext;
+pub mod qr_code;
+pub mod schedule_builder;
+pub mod signature_pad;
+pub mod slider_bank;
 pub mod square_radio;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod tree_table;
+pub mod virtual_list;
+pub mod week_planner;
 
+pub use self::anchored_overlay::AnchoredOverlay;
+pub use self::animated_list::AnimatedList;
+pub use self::bottom_sheet::BottomSheet;
+pub use self::card_input::CardInput;
+pub use self::cropper::Cropper;
+pub use self::debounce::Debounced;
+pub use self::floating_panel::FloatingPanel;
+pub use self::font_picker::FontPicker;
+pub use self::grid_picker::GridPicker;
+pub use self::helpers::*;
+pub use self::hold_button::HoldButton;
+pub use self::keypad::Keypad;
+pub use self::marquee::Marquee;
+pub use self::measurement_input::MeasurementInput;
+pub use self::multi_pick_list::menu;
 pub use self::multi_pick_list::MultiPickList;
+pub use self::on_screen_keyboard::OnScreenKeyboard;
+pub use self::phone_input::PhoneInput;
 pub use self::progress_bar_ext::ProgressBar;
+pub use self::qr_code::QrCode;
+pub use self::schedule_builder::ScheduleBuilder;
+pub use self::signature_pad::SignaturePad;
+pub use self::slider_bank::SliderBank;
 pub use self::square_radio::SquareRadio;
+pub use self::tree_table::TreeTable;
+pub use self::virtual_list::VirtualList;
+pub use self::week_planner::WeekPlanner;
@@ -0,0 +1,416 @@
+//! Scroll a line of text that does not fit sideways, like a ticker.
+//!
+//! A [`Marquee`] shows its text as is when it fits its bounds. Otherwise, the text scrolls from
+//! right to left in a loop, fading out at the edges, and pauses while the [`Marquee`] is hovered
+//! so it can be read.
+use std::f32::consts::FRAC_PI_2;
+
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Radians;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Text;
+use iced_core::Theme;
+use iced_core::Widget;
+use iced_core::alignment;
+use iced_core::gradient;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::text::paragraph;
+use iced_core::time::Instant;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+use iced_core::window;
+
+/// A line of text scrolling sideways when it is wider than its bounds.
+pub struct Marquee<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: String,
+    width: Length,
+    speed: f32,
+    gap: f32,
+    fade: f32,
+    pause_on_hover: bool,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme, Renderer> Marquee<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default speed of a [`Marquee`], in pixels per second.
+    pub const DEFAULT_SPEED: f32 = 40.0;
+
+    /// The default space between the end of the text of a [`Marquee`] and its next loop.
+    pub const DEFAULT_GAP: f32 = 32.0;
+
+    /// The default width of the fading edges of a [`Marquee`].
+    pub const DEFAULT_FADE: f32 = 16.0;
+
+    /// Creates a new [`Marquee`] with the given text.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            width: Length::Fill,
+            speed: Self::DEFAULT_SPEED,
+            gap: Self::DEFAULT_GAP,
+            fade: Self::DEFAULT_FADE,
+            pause_on_hover: true,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::default(),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Marquee`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the speed of the [`Marquee`], in pixels per second.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed.max(0.0);
+        self
+    }
+
+    /// Sets the space between the end of the text and its next loop.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into().0.max(0.0);
+        self
+    }
+
+    /// Sets the width of the edges the text fades out at, while scrolling.
+    ///
+    /// Zero disables the fading.
+    pub fn fade(mut self, fade: impl Into<Pixels>) -> Self {
+        self.fade = fade.into().0.max(0.0);
+        self
+    }
+
+    /// Sets whether the [`Marquee`] stops scrolling while hovered.
+    ///
+    /// By default, it does.
+    pub fn pause_on_hover(mut self, pause_on_hover: bool) -> Self {
+        self.pause_on_hover = pause_on_hover;
+        self
+    }
+
+    /// Sets the text size of the [`Marquee`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`Marquee`].
+    pub fn text_line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`Marquee`].
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the [`Marquee`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Marquee`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Marquee`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+struct State<P: text::Paragraph> {
+    paragraph: paragraph::Plain<P>,
+    /// How far the text has scrolled into its current loop.
+    offset: f32,
+    last_tick: Option<Instant>,
+    is_hovered: bool,
+}
+
+impl<P: text::Paragraph> State<P> {
+    /// Returns the width of the text.
+    fn text_width(&self) -> f32 {
+        self.paragraph.min_bounds().width
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Marquee<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            paragraph: paragraph::Plain::default(),
+            offset: 0.0,
+            last_tick: None,
+            is_hovered: false,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = f32::from(self.text_line_height.to_absolute(text_size));
+
+        // The text is shaped on a single line of unbounded width, so it can be measured.
+        let _ = state.paragraph.update(Text {
+            content: &self.content,
+            bounds: Size::new(f32::INFINITY, line_height),
+            size: text_size,
+            line_height: self.text_line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::None,
+        });
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(state.text_width(), line_height));
+
+        layout::Node::new(size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let is_hovered = cursor.is_over(bounds);
+
+                if is_hovered != state.is_hovered {
+                    state.is_hovered = is_hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let text_width = state.text_width();
+
+                if text_width <= bounds.width || self.speed == 0.0 {
+                    state.offset = 0.0;
+                    state.last_tick = None;
+                    return;
+                }
+
+                // The pause starts over from where the text stopped, instead of catching up.
+                if self.pause_on_hover && state.is_hovered {
+                    state.last_tick = None;
+                    return;
+                }
+
+                if let Some(last_tick) = state.last_tick {
+                    let elapsed = now.saturating_duration_since(last_tick).as_secs_f32();
+
+                    state.offset = (state.offset + self.speed * elapsed) % (text_width + self.gap);
+                }
+
+                state.last_tick = Some(*now);
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        let status = if state.is_hovered && cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        let Some(clip_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let text_width = state.text_width();
+        let is_scrolling = text_width > bounds.width && self.speed > 0.0;
+
+        if !is_scrolling {
+            renderer.fill_paragraph(
+                state.paragraph.raw(),
+                Point::new(bounds.x, bounds.center_y()),
+                style.text_color,
+                clip_bounds,
+            );
+
+            return;
+        }
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            let x = bounds.x - state.offset;
+
+            // A second copy follows the first one, so the loop has no visible seam.
+            for x in [x, x + text_width + self.gap] {
+                renderer.fill_paragraph(
+                    state.paragraph.raw(),
+                    Point::new(x, bounds.center_y()),
+                    style.text_color,
+                    clip_bounds,
+                );
+            }
+
+            if let Some(color) = style.fade
+                && self.fade > 0.0
+            {
+                let width = self.fade.min(bounds.width / 2.0);
+
+                // An angle of a quarter turn runs from the left to the right of the bounds.
+                for (x, angle) in [
+                    (bounds.x, Radians(FRAC_PI_2)),
+                    (bounds.x + bounds.width - width, Radians(3.0 * FRAC_PI_2)),
+                ] {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle { x, width, ..bounds },
+                            ..renderer::Quad::default()
+                        },
+                        gradient::Linear::new(angle)
+                            .add_stop(0.0, color)
+                            .add_stop(1.0, Color { a: 0.0, ..color }),
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Marquee<'a, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(marquee: Marquee<'a, Theme, Renderer>) -> Self {
+        Element::new(marquee)
+    }
+}
+
+/// The possible status of a [`Marquee`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Marquee`] is idle.
+    Active,
+    /// The [`Marquee`] is hovered, and paused if it pauses on hover.
+    Hovered,
+}
+
+/// The appearance of a [`Marquee`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the text.
+    pub text_color: Color,
+    /// The [`Color`] the text fades into at the edges while scrolling, usually the background
+    /// behind the [`Marquee`], if any.
+    pub fade: Option<Color>,
+}
+
+/// The theme catalog of a [`Marquee`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Marquee`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Marquee`].
+pub fn default(theme: &Theme, _status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        text_color: palette.background.base.text,
+        fade: Some(palette.background.base.color),
+    }
+}
@@ -0,0 +1,178 @@
+//! Type a measurement and pick its unit.
+//!
+//! A [`MeasurementInput`] pairs a number field with a pick list of the units measuring the
+//! same quantity. Picking another unit converts the value to it, so the measurement stays the
+//! same. Typing publishes the value in the current [`Unit`], while picking one publishes the
+//! converted value, rounded to the precision of the input.
+use std::fmt;
+use std::rc::Rc;
+
+use iced_core::Element;
+use iced_core::Length;
+use iced_core::text;
+use iced_widget::Row;
+use iced_widget::pick_list;
+use iced_widget::text_input;
+
+use crate::parsed_input::ParsedInput;
+
+/// The unit of a measurement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    /// A length in logical pixels.
+    Pixels,
+    /// A length as a percentage of the reference length of the [`MeasurementInput`].
+    Percent,
+    /// A mass in kilograms.
+    Kilograms,
+    /// A mass in avoirdupois pounds.
+    Pounds,
+    /// A temperature in degrees Celsius.
+    Celsius,
+    /// A temperature in degrees Fahrenheit.
+    Fahrenheit,
+}
+
+impl Unit {
+    /// The units of a length.
+    pub const LENGTH: [Unit; 2] = [Unit::Pixels, Unit::Percent];
+
+    /// The units of a mass.
+    pub const MASS: [Unit; 2] = [Unit::Kilograms, Unit::Pounds];
+
+    /// The units of a temperature.
+    pub const TEMPERATURE: [Unit; 2] = [Unit::Celsius, Unit::Fahrenheit];
+
+    /// Returns the units measuring the same quantity as this one, including itself.
+    pub fn siblings(self) -> &'static [Unit] {
+        match self {
+            Unit::Pixels | Unit::Percent => &Self::LENGTH,
+            Unit::Kilograms | Unit::Pounds => &Self::MASS,
+            Unit::Celsius | Unit::Fahrenheit => &Self::TEMPERATURE,
+        }
+    }
+
+    /// Converts the given value from this [`Unit`] to another one of the same quantity.
+    ///
+    /// A percentage is relative to the given reference length in pixels. The value is left
+    /// unchanged when the units measure different quantities.
+    pub fn convert(self, value: f32, to: Unit, reference: f32) -> f32 {
+        const POUNDS_PER_KILOGRAM: f32 = 2.204_622_6;
+
+        match (self, to) {
+            (Unit::Pixels, Unit::Percent) if reference != 0.0 => value / reference * 100.0,
+            (Unit::Percent, Unit::Pixels) => value * reference / 100.0,
+            (Unit::Kilograms, Unit::Pounds) => value * POUNDS_PER_KILOGRAM,
+            (Unit::Pounds, Unit::Kilograms) => value / POUNDS_PER_KILOGRAM,
+            (Unit::Celsius, Unit::Fahrenheit) => value * 9.0 / 5.0 + 32.0,
+            (Unit::Fahrenheit, Unit::Celsius) => (value - 32.0) * 5.0 / 9.0,
+            _ => value,
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = match self {
+            Unit::Pixels => "px",
+            Unit::Percent => "%",
+            Unit::Kilograms => "kg",
+            Unit::Pounds => "lb",
+            Unit::Celsius => "°C",
+            Unit::Fahrenheit => "°F",
+        };
+
+        f.write_str(unit)
+    }
+}
+
+/// A number field followed by a pick list of its [`Unit`].
+pub struct MeasurementInput<'a, Message> {
+    value: f32,
+    unit: Unit,
+    on_change: Rc<dyn Fn(f32, Unit) -> Message + 'a>,
+    reference: f32,
+    precision: u32,
+    width: Length,
+    spacing: f32,
+}
+
+impl<'a, Message> MeasurementInput<'a, Message> {
+    /// The default spacing between the number field and the unit of a [`MeasurementInput`].
+    pub const DEFAULT_SPACING: f32 = 4.0;
+
+    /// Creates a new [`MeasurementInput`] with the given value and [`Unit`], and the message to
+    /// produce with the changed ones.
+    pub fn new(value: f32, unit: Unit, on_change: impl Fn(f32, Unit) -> Message + 'a) -> Self {
+        Self {
+            value,
+            unit,
+            on_change: Rc::new(on_change),
+            reference: 100.0,
+            precision: 2,
+            width: Length::Fixed(80.0),
+            spacing: Self::DEFAULT_SPACING,
+        }
+    }
+
+    /// Sets the length in pixels a percentage is relative to, when converting between
+    /// [`Unit::Pixels`] and [`Unit::Percent`]. Defaults to 100.
+    pub fn reference(mut self, reference: f32) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    /// Sets the amount of decimals a converted value is rounded to. Defaults to 2.
+    pub fn precision(mut self, precision: u32) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the width of the number field of the [`MeasurementInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the number field and the unit of the [`MeasurementInput`].
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MeasurementInput<'a, Message>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: text_input::Catalog + pick_list::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(input: MeasurementInput<'a, Message>) -> Self {
+        let MeasurementInput { value, unit, .. } = input;
+
+        let number = {
+            let change = Rc::clone(&input.on_change);
+
+            ParsedInput::new("0", value, move |value: f32| change(value, unit)).width(input.width)
+        };
+
+        let unit = {
+            let change = Rc::clone(&input.on_change);
+            let (reference, precision) = (input.reference, input.precision);
+
+            pick_list(unit.siblings(), Some(unit), move |to| {
+                let scale = 10_f32.powi(precision as i32);
+                let converted = unit.convert(value, to, reference);
+
+                change((converted * scale).round() / scale, to)
+            })
+        };
+
+        Row::new()
+            .push(number)
+            .push(unit)
+            .spacing(input.spacing)
+            .align_y(iced_core::alignment::Vertical::Center)
+            .into()
+    }
+}
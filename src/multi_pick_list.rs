@@ -1,4 +1,6 @@
 use iced_core::alignment;
+use iced_core::border;
+use iced_core::gradient;
 use iced_core::keyboard;
 use iced_core::layout;
 use iced_core::mouse;
@@ -6,81 +8,275 @@ use iced_core::overlay;
 use iced_core::renderer;
 use iced_core::text::paragraph;
 use iced_core::text::{self, Text};
+use iced_core::time::{Duration, Instant};
 use iced_core::touch;
 use iced_core::widget::tree::{self, Tree};
+use iced_core::widget::{self, Operation, operation};
 use iced_core::window;
 use iced_core::{
-    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point, Rectangle, Shell,
-    Size, Theme, Vector, Widget,
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point, Radians, Rectangle,
+    Shell, Size, Theme, Vector, Widget,
 };
+use iced_widget::scrollable;
 
+use std::any::Any;
 use std::borrow::Borrow;
+use std::cmp::{Ordering, Reverse};
 use std::f32;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::rc::Rc;
 
 pub struct MultiPickList<'a, T, L, V, Message, Theme, Renderer>
 where
-    T: ToString + PartialEq + Clone,
+    T: PartialEq + Clone,
     L: Borrow<[T]> + 'a,
     V: Borrow<[T]> + 'a,
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Option<widget::Id>,
     on_select: Box<dyn Fn(T) -> Message + 'a>,
+    on_toggle: Option<Box<dyn Fn(T, bool) -> Message + 'a>>,
+    on_changed: Option<Box<dyn Fn(Vec<T>) -> Message + 'a>>,
+    display: Box<dyn Fn(&T) -> String + 'a>,
+    option_key: Option<Box<dyn Fn(&T) -> u64 + 'a>>,
+    is_same: Option<Box<dyn Fn(&T, &T) -> bool + 'a>>,
     on_open: Option<Message>,
     on_close: Option<Message>,
+    on_open_with: Option<Box<dyn Fn(Context) -> Message + 'a>>,
+    on_close_with: Option<Box<dyn Fn(Context) -> Message + 'a>>,
+    on_option_hovered: Option<Box<dyn Fn(T) -> Message + 'a>>,
     options: L,
     label: Option<String>,
+    placeholder: Option<String>,
     selected: V,
     width: Length,
     padding: Padding,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
+    text_wrapping: text::Wrapping,
+    text_ellipsis: bool,
+    text_align_x: text::Alignment,
+    text_align_y: alignment::Vertical,
     font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
+    overflow: Overflow,
     class: <Theme as Catalog>::Class<'a>,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
     last_status: Option<Status>,
     menu_height: Length,
+    pin_selected: bool,
+    sort: SortOrder<'a, T>,
+    separators: Vec<usize>,
+    option_badge: Option<Box<dyn Fn(&T) -> Option<String> + 'a>>,
+    option_style: Option<Box<dyn Fn(&T, OptionStatus) -> menu::OptionStyle + 'a>>,
+    option_disabled: Option<Box<dyn Fn(&T) -> bool + 'a>>,
+    compact: Option<Icon<Renderer::Font>>,
+    menu_animation: Duration,
+    option_tooltip: Option<Box<dyn Fn(&T) -> Option<String> + 'a>>,
+    tooltip_delay: Option<Duration>,
+    hover_delay: Option<Duration>,
+    menu_backdrop: bool,
+    menu_overscroll: bool,
+    menu_z_index: f32,
+    menu_margin: f32,
+    menu_columns: usize,
+    selection_indicator: menu::SelectionIndicator,
+    option_depth: Option<Box<dyn Fn(&T) -> usize + 'a>>,
+    option_indentation: Option<Box<dyn Fn(&T) -> u16 + 'a>>,
+    indent_width: f32,
+    select_children: bool,
+    read_only: bool,
+    menu_footer: Option<Element<'a, Message, Theme, Renderer>>,
+    menu_header: Option<String>,
+    empty_text: Option<String>,
+    is_loading: bool,
+    option_height: Option<Pixels>,
+    checkbox_size: Option<Pixels>,
+    checkbox_spacing: Pixels,
+    wheel_cycles: bool,
+    menu_scrollbar: Option<scrollable::Scrollbar>,
+    menu_scrollable_style: Option<ScrollableStyle<'a, Theme>>,
 }
 
+/// A styling function for the [`Scrollable`] of a [`Menu`], along with its conversion into the
+/// class of the [`Theme`], which can only be named where the conversion is known to exist.
+///
+/// [`Scrollable`]: iced_widget::Scrollable
+type ScrollableStyle<'a, Theme> = (
+    Box<dyn Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a>,
+    for<'b> fn(scrollable::StyleFn<'b, Theme>) -> <Theme as scrollable::Catalog>::Class<'b>,
+);
+
 impl<'a, T, L, V, Message, Theme, Renderer> MultiPickList<'a, T, L, V, Message, Theme, Renderer>
 where
-    T: ToString + PartialEq + Clone,
+    T: PartialEq + Clone,
     L: Borrow<[T]> + 'a,
     V: Borrow<[T]> + 'a,
     Message: Clone,
     Theme: Catalog,
     Renderer: text::Renderer,
 {
-    pub fn new(options: L, selected: V, on_select: impl Fn(T) -> Message + 'a) -> Self {
+    /// The default duration of the [`Menu`] unfolding when opened.
+    pub const DEFAULT_MENU_ANIMATION: Duration = Duration::from_millis(120);
+
+    /// Creates a new [`MultiPickList`] with the given options, the selected ones, and the
+    /// message to produce when an option is toggled.
+    ///
+    /// Options are labeled with their [`ToString`] output.
+    pub fn new(options: L, selected: V, on_select: impl Fn(T) -> Message + 'a) -> Self
+    where
+        T: ToString + 'a,
+    {
+        Self::with_display(options, selected, on_select, T::to_string)
+    }
+
+    /// Creates a new [`MultiPickList`] like [`MultiPickList::new`], labeling every option with
+    /// the given closure instead.
+    pub fn with_display(
+        options: L,
+        selected: V,
+        on_select: impl Fn(T) -> Message + 'a,
+        display: impl Fn(&T) -> String + 'a,
+    ) -> Self {
         Self {
+            id: None,
             on_select: Box::new(on_select),
+            on_toggle: None,
+            on_changed: None,
+            display: Box::new(display),
+            option_key: None,
+            is_same: None,
             on_open: None,
             on_close: None,
+            on_open_with: None,
+            on_close_with: None,
+            on_option_hovered: None,
             options,
             label: None,
+            placeholder: None,
             selected,
             width: Length::Shrink,
             padding: iced_widget::button::DEFAULT_PADDING,
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::default(),
+            text_wrapping: text::Wrapping::None,
+            text_ellipsis: false,
+            text_align_x: text::Alignment::Default,
+            text_align_y: alignment::Vertical::Center,
             font: None,
             handle: Handle::default(),
+            overflow: Overflow::default(),
             class: <Theme as Catalog>::default(),
             menu_class: <Theme as Catalog>::default_menu(),
             last_status: None,
             menu_height: Length::Shrink,
+            pin_selected: false,
+            sort: SortOrder::None,
+            separators: Vec::new(),
+            option_badge: None,
+            option_style: None,
+            option_disabled: None,
+            compact: None,
+            menu_animation: Self::DEFAULT_MENU_ANIMATION,
+            option_tooltip: None,
+            tooltip_delay: None,
+            hover_delay: None,
+            menu_backdrop: false,
+            menu_overscroll: false,
+            menu_z_index: menu::DEFAULT_Z_INDEX,
+            menu_margin: menu::DEFAULT_MARGIN,
+            menu_columns: 1,
+            selection_indicator: menu::SelectionIndicator::default(),
+            option_depth: None,
+            option_indentation: None,
+            indent_width: menu::DEFAULT_INDENT_WIDTH,
+            select_children: false,
+            read_only: false,
+            menu_footer: None,
+            menu_header: None,
+            empty_text: None,
+            is_loading: false,
+            option_height: None,
+            checkbox_size: None,
+            checkbox_spacing: Pixels(menu::DEFAULT_CHECKBOX_SPACING),
+            wheel_cycles: false,
+            menu_scrollbar: None,
+            menu_scrollable_style: None,
         }
     }
 
-    /// Sets the placeholder of the [`MultiPickList`].
+    /// Sets the [`widget::Id`] of the [`MultiPickList`].
+    ///
+    /// The id is needed to [`open`] or [`close`] the [`MultiPickList`] programmatically.
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the label of the [`MultiPickList`].
     pub fn label(mut self, label: impl Into<String>) -> Self {
         self.label = Some(label.into());
         self
     }
 
+    /// Sets the placeholder of the [`MultiPickList`].
+    ///
+    /// The placeholder is shown instead of the label while nothing is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the text shown in the [`Menu`] while there are no options, like "No results".
+    ///
+    /// Without it, the [`Menu`] of a [`MultiPickList`] without options is empty.
+    pub fn empty_text(mut self, empty_text: impl Into<String>) -> Self {
+        self.empty_text = Some(empty_text.into());
+        self
+    }
+
+    /// Sets whether the options of the [`MultiPickList`] are still being loaded, like while a query
+    /// started with [`MultiPickList::on_open`] is in flight.
+    ///
+    /// A spinner is shown below the options of the [`Menu`] while loading, in place of its empty text.
+    pub fn loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
+    /// Sets the height of every option in the [`Menu`], regardless of its text.
+    ///
+    /// By default, an option is as tall as its text plus the vertical padding.
+    pub fn option_height(mut self, option_height: impl Into<Pixels>) -> Self {
+        self.option_height = Some(option_height.into());
+        self
+    }
+
+    /// Sets the size of the checkbox of every option in the [`Menu`].
+    ///
+    /// By default, it is 60% of the height of a line of text plus the vertical padding.
+    pub fn checkbox_size(mut self, checkbox_size: impl Into<Pixels>) -> Self {
+        self.checkbox_size = Some(checkbox_size.into());
+        self
+    }
+
+    /// Sets the space around the checkbox of every option in the [`Menu`], from the edges of
+    /// the option and from its label.
+    pub fn checkbox_spacing(mut self, checkbox_spacing: impl Into<Pixels>) -> Self {
+        self.checkbox_spacing = checkbox_spacing.into();
+        self
+    }
+
+    /// Sets whether scrolling the wheel over the closed [`MultiPickList`] cycles through its
+    /// options, replacing the selection with the next or previous option.
+    pub fn wheel_cycles(mut self, wheel_cycles: bool) -> Self {
+        self.wheel_cycles = wheel_cycles;
+        self
+    }
+
     /// Sets the width of the [`MultiPickList`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -93,6 +289,197 @@ where
         self
     }
 
+    /// Sets how long the [`Menu`] takes to unfold when opened.
+    ///
+    /// A zero duration shows the [`Menu`] right away.
+    pub fn menu_animation(mut self, duration: Duration) -> Self {
+        self.menu_animation = duration;
+        self
+    }
+
+    /// Sets the closure returning the depth of every option, turning the options into a tree.
+    ///
+    /// Options are expected in depth-first order, so the children of an option are the options
+    /// following it with a greater depth. Parents start collapsed and expand from their arrow.
+    pub fn option_depth(mut self, option_depth: impl Fn(&T) -> usize + 'a) -> Self {
+        self.option_depth = Some(Box::new(option_depth));
+        self
+    }
+
+    /// Sets the closure returning the indentation level of every option, which shifts its
+    /// content right by the level times the [`indent_width`](Self::indent_width).
+    ///
+    /// Unlike [`option_depth`](Self::option_depth), the options stay a flat list, so headers
+    /// and their options can be told apart without anything to expand.
+    pub fn option_indentation(mut self, option_indentation: impl Fn(&T) -> u16 + 'a) -> Self {
+        self.option_indentation = Some(Box::new(option_indentation));
+        self
+    }
+
+    /// Sets the width of a level of [`option_indentation`](Self::option_indentation).
+    ///
+    /// By default, it is [`menu::DEFAULT_INDENT_WIDTH`].
+    pub fn indent_width(mut self, indent_width: impl Into<Pixels>) -> Self {
+        self.indent_width = indent_width.into().0;
+        self
+    }
+
+    /// Sets whether toggling a parent option toggles its children to match it.
+    pub fn select_children(mut self, select_children: bool) -> Self {
+        self.select_children = select_children;
+        self
+    }
+
+    /// Sets whether the [`MultiPickList`] is read-only.
+    ///
+    /// A read-only [`MultiPickList`] still opens its [`Menu`], so the selection can be reviewed,
+    /// but its options cannot be toggled and are not highlighted when hovered.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets the amount of columns the options of the [`Menu`] flow into, from left to right.
+    pub fn menu_columns(mut self, menu_columns: usize) -> Self {
+        self.menu_columns = menu_columns.max(1);
+        self
+    }
+
+    /// Sets how the selected options of the [`Menu`] are told apart.
+    ///
+    /// By default, every option has a leading checkbox.
+    pub fn selection_indicator(mut self, selection_indicator: menu::SelectionIndicator) -> Self {
+        self.selection_indicator = selection_indicator;
+        self
+    }
+
+    /// Sets the element pinned below the options of the [`Menu`], like an "Add new item" button.
+    ///
+    /// It does not scroll with the options and receives events while the [`Menu`] is open.
+    pub fn menu_footer(mut self, menu_footer: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.menu_footer = Some(menu_footer.into());
+        self
+    }
+
+    /// Sets the title of a header bar pinned above the options of the [`Menu`], with a button
+    /// closing it.
+    ///
+    /// Closing the [`Menu`] from its header produces the message of [`MultiPickList::on_close`],
+    /// like any other dismissal. It helps on touch screens, where tapping outside of the [`Menu`]
+    /// to close it is not obvious.
+    pub fn menu_header(mut self, title: impl Into<String>) -> Self {
+        self.menu_header = Some(title.into());
+        self
+    }
+
+    /// Sets whether a backdrop is drawn behind the [`Menu`], dimming the rest of the window.
+    ///
+    /// Clicking the backdrop closes the [`Menu`] without reaching the widgets below it.
+    pub fn menu_backdrop(mut self, menu_backdrop: bool) -> Self {
+        self.menu_backdrop = menu_backdrop;
+        self
+    }
+
+    /// Sets whether dragging the options of the [`Menu`] past their edges with a finger stretches
+    /// them, springing back once lifted.
+    pub fn menu_overscroll(mut self, menu_overscroll: bool) -> Self {
+        self.menu_overscroll = menu_overscroll;
+        self
+    }
+
+    /// Sets the z-index of the [`Menu`] among the other overlays of the window, so it stays on
+    /// top of the overlay the [`MultiPickList`] lives in.
+    ///
+    /// By default, it is [`menu::DEFAULT_Z_INDEX`].
+    pub fn menu_z_index(mut self, menu_z_index: f32) -> Self {
+        self.menu_z_index = menu_z_index;
+        self
+    }
+
+    /// Sets the space kept between the [`Menu`] and the edges of the window.
+    ///
+    /// The [`Menu`] opens on the side of the [`MultiPickList`] with the most space, and scrolls
+    /// its options when they do not fit there.
+    pub fn menu_margin(mut self, menu_margin: impl Into<Pixels>) -> Self {
+        self.menu_margin = menu_margin.into().0;
+        self
+    }
+
+    /// Sets whether the selected options are pinned to the top of the [`Menu`],
+    /// above a divider and the remaining options.
+    ///
+    /// The order is decided when the [`Menu`] opens, so rows do not move while toggling them.
+    pub fn pin_selected(mut self, pin_selected: bool) -> Self {
+        self.pin_selected = pin_selected;
+        self
+    }
+
+    /// Sets the [`SortOrder`] of the options in the [`Menu`].
+    ///
+    /// Like pinned options, the order is decided when the [`Menu`] opens. Options nested with
+    /// [`option_depth`](Self::option_depth) keep their order, so children stay below their parent.
+    pub fn sort(mut self, sort: SortOrder<'a, T>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Sets the indices of the options that are preceded by a divider in the [`Menu`].
+    ///
+    /// Dividers are drawn as a thin rule and cannot be hovered or selected.
+    pub fn separators(mut self, separators: impl IntoIterator<Item = usize>) -> Self {
+        self.separators = separators.into_iter().collect();
+        self
+    }
+
+    /// Sets the closure producing the badge drawn at the end of every option, like a count of results.
+    ///
+    /// Options without a badge return `None`.
+    pub fn option_badge(mut self, option_badge: impl Fn(&T) -> Option<String> + 'a) -> Self {
+        self.option_badge = Some(Box::new(option_badge));
+        self
+    }
+
+    /// Sets the closure producing the colors of every option given its status, like the color of a tag
+    /// or of a severity level.
+    ///
+    /// The colors left unset are taken from the style of the [`Menu`].
+    pub fn option_style(mut self, option_style: impl Fn(&T, OptionStatus) -> menu::OptionStyle + 'a) -> Self {
+        self.option_style = Some(Box::new(option_style));
+        self
+    }
+
+    /// Sets the closure telling whether an option is disabled, like a plan not available yet.
+    ///
+    /// Disabled options are drawn with the [`Disabled`](OptionStatus::Disabled) status, and
+    /// cannot be hovered, toggled, or reached with the keyboard or the mouse wheel.
+    pub fn option_disabled(mut self, option_disabled: impl Fn(&T) -> bool + 'a) -> Self {
+        self.option_disabled = Some(Box::new(option_disabled));
+        self
+    }
+
+    /// Sets the closure producing the tooltip shown once an option has been hovered for a while.
+    ///
+    /// Options without a tooltip return `None`.
+    pub fn option_tooltip(mut self, option_tooltip: impl Fn(&T) -> Option<String> + 'a) -> Self {
+        self.option_tooltip = Some(Box::new(option_tooltip));
+        self
+    }
+
+    /// Sets how long an option has to be hovered before its tooltip is shown.
+    pub fn tooltip_delay(mut self, delay: Duration) -> Self {
+        self.tooltip_delay = Some(delay);
+        self
+    }
+
+    /// Renders the [`MultiPickList`] as a compact button showing the given [`Icon`] and a
+    /// badge with the amount of selected options, like the filter buttons of a toolbar.
+    ///
+    /// The [`Menu`] behaves the same, but grows to fit its options.
+    pub fn compact(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.compact = Some(icon);
+        self
+    }
+
     /// Sets the [`Padding`] of the [`MultiPickList`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -117,6 +504,37 @@ where
         self
     }
 
+    /// Sets the [`text::Wrapping`] strategy of the options of the [`MultiPickList`].
+    ///
+    /// Every row of the [`Menu`] grows to fit its wrapped option.
+    ///
+    /// By default, options are not wrapped.
+    pub fn text_wrapping(mut self, wrapping: text::Wrapping) -> Self {
+        self.text_wrapping = wrapping;
+        self
+    }
+
+    /// Sets whether options too long for the [`Menu`] are truncated with an ellipsis.
+    ///
+    /// Only options that are not wrapped are truncated.
+    pub fn text_ellipsis(mut self, text_ellipsis: bool) -> Self {
+        self.text_ellipsis = text_ellipsis;
+        self
+    }
+
+    /// Sets the horizontal alignment of the options in the [`Menu`] of the [`MultiPickList`].
+    pub fn text_align_x(mut self, alignment: impl Into<text::Alignment>) -> Self {
+        self.text_align_x = alignment.into();
+        self
+    }
+
+    /// Sets the vertical alignment of the options in the [`Menu`] of the [`MultiPickList`], which
+    /// shows in rows taller than their option.
+    pub fn text_align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.text_align_y = alignment.into();
+        self
+    }
+
     /// Sets the font of the [`MultiPickList`].
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
         self.font = Some(font.into());
@@ -129,6 +547,39 @@ where
         self
     }
 
+    /// Sets how the label of the [`MultiPickList`] is shown when it is wider than the space
+    /// left to it before the [`Handle`].
+    ///
+    /// By default, the label is clipped.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the closure producing the label of every option, instead of its [`ToString`] output.
+    pub fn display_with(mut self, display: impl Fn(&T) -> String + 'a) -> Self {
+        self.display = Box::new(display);
+        self
+    }
+
+    /// Sets the closure returning the key of every option, like its ID.
+    ///
+    /// The key tells whether an option is selected, so a selected value holding stale data, like an
+    /// outdated label, still matches its option. Options sharing the key of a selected value are all
+    /// selected. When the options change, the state of every option also follows its key, so only
+    /// new options are shaped again.
+    ///
+    /// By default, an option is selected when it is equal to a selected value, and options are
+    /// told apart by their label when they change.
+    pub fn key<K: Hash + Eq>(mut self, key: impl Fn(&T) -> K + 'a) -> Self {
+        let key = Rc::new(key);
+        let hashed = Rc::clone(&key);
+
+        self.option_key = Some(Box::new(move |option| hash(&hashed(option))));
+        self.is_same = Some(Box::new(move |a, b| key(a) == key(b)));
+        self
+    }
+
     /// Sets the message that will be produced when the [`MultiPickList`] is opened.
     pub fn on_open(mut self, on_open: Message) -> Self {
         self.on_open = Some(on_open);
@@ -141,6 +592,52 @@ where
         self
     }
 
+    /// Sets the closure producing a message with the [`Context`] of the [`Menu`] once it is opened.
+    ///
+    /// The message is produced once the [`Menu`] has been placed, right after the `on_open` message.
+    pub fn on_open_with(mut self, on_open_with: impl Fn(Context) -> Message + 'a) -> Self {
+        self.on_open_with = Some(Box::new(on_open_with));
+        self
+    }
+
+    /// Sets the closure producing a message with the [`Context`] of the [`Menu`] when it is closed.
+    pub fn on_close_with(mut self, on_close_with: impl Fn(Context) -> Message + 'a) -> Self {
+        self.on_close_with = Some(Box::new(on_close_with));
+        self
+    }
+
+    /// Sets the message that will be produced when an option is toggled, along with whether
+    /// it is being selected, instead of the message given to [`MultiPickList::new`].
+    pub fn on_toggle(mut self, on_toggle: impl Fn(T, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the message that will be produced with the whole selection once the [`Menu`] closes,
+    /// if it changed while the [`Menu`] was open.
+    ///
+    /// Options toggled inside the [`Menu`] no longer produce a message each, so rapid toggles
+    /// result in a single update. Toggles outside of it, like wheel cycling, are not batched.
+    pub fn on_changed(mut self, on_changed: impl Fn(Vec<T>) -> Message + 'a) -> Self {
+        self.on_changed = Some(Box::new(on_changed));
+        self
+    }
+
+    /// Sets the message that will be produced when an option of the [`MultiPickList`] is hovered.
+    pub fn on_option_hovered(mut self, on_option_hovered: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_option_hovered = Some(Box::new(on_option_hovered));
+        self
+    }
+
+    /// Sets how long an option has to stay hovered before [`on_option_hovered`] is produced, so
+    /// sweeping the cursor across the [`Menu`] only reports the option it rests on.
+    ///
+    /// [`on_option_hovered`]: Self::on_option_hovered
+    pub fn hover_delay(mut self, delay: Duration) -> Self {
+        self.hover_delay = Some(delay);
+        self
+    }
+
     /// Sets the style of the [`MultiPickList`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -153,7 +650,7 @@ where
 
     /// Sets the style of the [`Menu`].
     #[must_use]
-    pub fn menu_style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    pub fn menu_style(mut self, style: impl Fn(&Theme, menu::Status) -> menu::Style + 'a) -> Self
     where
         <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
     {
@@ -161,6 +658,27 @@ where
         self
     }
 
+    /// Sets the [`scrollable::Scrollbar`] of the [`Menu`], with its width, margin and anchor.
+    pub fn menu_scrollbar(mut self, scrollbar: scrollable::Scrollbar) -> Self {
+        self.menu_scrollbar = Some(scrollbar);
+        self
+    }
+
+    /// Sets the style of the scrollbar of the [`Menu`].
+    ///
+    /// By default, it is the [`menu::Catalog::default_scrollable`] of the theme.
+    #[must_use]
+    pub fn menu_scrollable_style(mut self, style: impl Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a) -> Self
+    where
+        for<'b> <Theme as scrollable::Catalog>::Class<'b>: From<scrollable::StyleFn<'b, Theme>>,
+    {
+        let into_class: for<'b> fn(scrollable::StyleFn<'b, Theme>) -> <Theme as scrollable::Catalog>::Class<'b> =
+            |style| style.into();
+
+        self.menu_scrollable_style = Some((Box::new(style), into_class));
+        self
+    }
+
     /// Sets the style class of the [`MultiPickList`].
     #[must_use]
     pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
@@ -174,77 +692,447 @@ where
         self.menu_class = class.into();
         self
     }
-}
 
-impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
-    for MultiPickList<'a, T, L, V, Message, Theme, Renderer>
-where
-    T: Clone + ToString + PartialEq + 'a,
-    L: Borrow<[T]>,
-    V: Borrow<[T]>,
-    Message: Clone + 'a,
-    Theme: Catalog + 'a,
-    Renderer: text::Renderer + 'a,
-{
-    fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State<Renderer::Paragraph>>()
+    /// Opens the [`Menu`] and publishes the `on_open` message, if any.
+    fn open_menu<P: text::Paragraph>(&self, state: &mut State<P>, shell: &mut Shell<'_, Message>) {
+        let options = self.options.borrow();
+        let selected = self.selected.borrow();
+
+        // The context is published once the overlay has been laid out again.
+        let _ = state.menu.take_placement();
+        state.placement = None;
+
+        state.is_open = true;
+        state.hovered_option = None;
+        state.opened_at = None;
+        state.reveal = if self.menu_animation.is_zero() { 1.0 } else { 0.0 };
+        state.pinned = options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| self.is_selected(selected, option))
+            .map(|(index, _)| index)
+            .collect();
+        state.batch = self.on_changed.is_some().then(|| state.pinned.clone());
+        state.entries = self.entries(state);
+
+        if let Some(on_open) = &self.on_open {
+            shell.publish(on_open.clone());
+        }
     }
 
-    fn state(&self) -> tree::State {
-        tree::State::new(State::<Renderer::Paragraph>::new())
+    /// Closes the [`Menu`] anchored to the given bounds and publishes the `on_close` messages, if any.
+    fn close_menu<P: text::Paragraph>(&self, state: &mut State<P>, bounds: Rectangle, shell: &mut Shell<'_, Message>) {
+        state.is_open = false;
+        state.hovered_option = None;
+
+        if let Some(mut batch) = state.batch.take()
+            && let Some(on_changed) = &self.on_changed
+        {
+            let options = self.options.borrow();
+            let selected = self.selected.borrow();
+
+            // The selection is published in the order of the options, like it was toggled in.
+            batch.sort_unstable();
+
+            let is_changed = batch.len() != selected.len()
+                || batch.iter().any(|index| {
+                    options
+                        .get(*index)
+                        .is_none_or(|option| !self.is_selected(selected, option))
+                });
+
+            if is_changed {
+                shell.publish(on_changed(
+                    batch.iter().filter_map(|index| options.get(*index).cloned()).collect(),
+                ));
+            }
+        }
+
+        if let Some(on_close) = &self.on_close {
+            shell.publish(on_close.clone());
+        }
+
+        if let Some(on_close_with) = &self.on_close_with {
+            shell.publish(on_close_with(self.context(bounds, state.placement)));
+        }
     }
 
-    fn size(&self) -> Size<Length> {
-        Size {
-            width: self.width,
-            height: Length::Shrink,
+    /// Returns the hash of the key of the given option.
+    fn option_key(&self, option: &T) -> u64 {
+        match &self.option_key {
+            Some(option_key) => option_key(option),
+            None => hash(&(self.display)(option)),
         }
     }
 
-    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
-        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
-        let font = self.font.unwrap_or_else(|| renderer.default_font());
-        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+    /// Returns whether the given option is disabled.
+    fn is_disabled(&self, option: &T) -> bool {
+        self.option_disabled
+            .as_ref()
+            .is_some_and(|option_disabled| option_disabled(option))
+    }
+
+    /// Returns whether the given option is among the selected values.
+    fn is_selected(&self, selected: &[T], option: &T) -> bool {
+        contains(selected, option, self.is_same.as_deref())
+    }
+
+    /// Returns the message toggling the given option, which is being selected or not.
+    fn toggle(&self, option: T, is_selected: bool) -> Message {
+        match &self.on_toggle {
+            Some(on_toggle) => on_toggle(option, is_selected),
+            None => (self.on_select)(option),
+        }
+    }
+
+    /// Replaces the selection with the option after the first selected one, or before it
+    /// when going backwards.
+    fn cycle(&self, forward: bool, shell: &mut Shell<'_, Message>) {
         let options = self.options.borrow();
+        let selected = self.selected.borrow();
 
-        state.options.resize_with(options.len(), Default::default);
+        let current = options.iter().position(|option| self.is_selected(selected, option));
+        let is_enabled = |index: &usize| !self.is_disabled(&options[*index]);
 
-        let option_text = Text {
-            content: "",
-            bounds: Size::new(f32::INFINITY, self.text_line_height.to_absolute(text_size).into()),
-            size: text_size,
-            line_height: self.text_line_height,
-            font,
-            align_x: text::Alignment::Default,
-            align_y: alignment::Vertical::Center,
-            shaping: self.text_shaping,
-            wrapping: text::Wrapping::default(),
+        // Disabled options are skipped, and the selection stops at either end of the options.
+        let next = match current {
+            Some(index) if forward => (index + 1..options.len()).find(is_enabled),
+            Some(index) => (0..index).rev().find(is_enabled),
+            None => (0..options.len()).find(is_enabled),
         };
 
-        for (option, paragraph) in options.iter().zip(state.options.iter_mut()) {
-            let label = option.to_string();
+        let Some(option) = next.and_then(|next| options.get(next)) else {
+            return;
+        };
 
-            let _ = paragraph.update(Text {
-                content: &label,
-                ..option_text
-            });
+        for option in selected {
+            shell.publish(self.toggle(option.clone(), false));
         }
 
-        if let Some(label) = &self.label {
-            let _ = state.label.update(Text {
-                content: label,
-                ..option_text
-            });
+        shell.publish(self.toggle(option.clone(), true));
+    }
+
+    /// Returns the [`Context`] of the [`Menu`] anchored to the given bounds.
+    fn context(&self, bounds: Rectangle, placement: Option<menu::Placement>) -> Context {
+        Context {
+            bounds,
+            placement,
+            options: self.options.borrow().len(),
         }
+    }
 
-        let max_width = match self.width {
-            Length::Shrink => {
-                let labels_width = state
-                    .options
-                    .iter()
-                    .fold(0.0, |width, paragraph| f32::max(width, paragraph.min_width()));
+    /// Lists the entries of the [`Menu`], with the pinned options above the rest and
+    /// without the children of collapsed options.
+    ///
+    /// Returns no entries when every option is shown in order without any divider.
+    fn entries<P: text::Paragraph>(&self, state: &State<P>) -> Vec<menu::Entry> {
+        if !self.pin_selected
+            && self.separators.is_empty()
+            && self.option_depth.is_none()
+            && matches!(self.sort, SortOrder::None)
+        {
+            return Vec::new();
+        }
+
+        let count = self.options.borrow().len();
+        let pinned = if self.pin_selected { &state.pinned[..] } else { &[] };
+
+        let mut is_hidden = vec![false; count];
+        let mut collapsed_depth = None;
+
+        for (index, &depth) in state.depths.iter().enumerate().take(count) {
+            if collapsed_depth.is_some_and(|collapsed_depth| depth > collapsed_depth) {
+                is_hidden[index] = true;
+                continue;
+            }
+
+            let has_children = state.depths.get(index + 1).is_some_and(|&next| next > depth);
+            let is_expanded = state.expanded.get(index).copied().unwrap_or(false);
+
+            collapsed_depth = (has_children && !is_expanded).then_some(depth);
+        }
+
+        let mut entries: Vec<_> = pinned
+            .iter()
+            .copied()
+            .filter(|&index| index < count)
+            .map(menu::Entry::Option)
+            .collect();
+
+        let mut is_first = true;
+
+        for index in self
+            .order(state)
+            .into_iter()
+            .filter(|index| !pinned.contains(index) && !is_hidden[*index])
+        {
+            let follows_option = entries.last().is_some_and(|entry| *entry != menu::Entry::Separator);
+
+            // The first unpinned option is divided from the pinned ones.
+            if follows_option && (is_first || self.separators.contains(&index)) {
+                entries.push(menu::Entry::Separator);
+            }
+
+            entries.push(menu::Entry::Option(index));
+            is_first = false;
+        }
+
+        entries
+    }
+
+    /// Returns the indices of the options in the [`SortOrder`] of the [`MultiPickList`].
+    fn order<P: text::Paragraph>(&self, state: &State<P>) -> Vec<usize> {
+        let options = self.options.borrow();
+        let mut order: Vec<usize> = (0..options.len()).collect();
+
+        if self.option_depth.is_some() {
+            return order;
+        }
+
+        match &self.sort {
+            SortOrder::None => {}
+            SortOrder::Ascending => order.sort_by_cached_key(|index| (self.display)(&options[*index])),
+            SortOrder::Descending => order.sort_by_cached_key(|index| Reverse((self.display)(&options[*index]))),
+            SortOrder::SelectedFirst => order.sort_by_key(|index| !state.pinned.contains(index)),
+            SortOrder::Custom(compare) => order.sort_by(|a, b| compare(&options[*a], &options[*b])),
+        }
+
+        order
+    }
+
+    /// Returns the label shown in the field of the [`MultiPickList`], along with its paragraph and
+    /// whether it is the placeholder.
+    fn shown_label<'b>(
+        &'b self,
+        state: &'b State<Renderer::Paragraph>,
+    ) -> Option<(&'b str, &'b paragraph::Plain<Renderer::Paragraph>, bool)> {
+        match &self.placeholder {
+            Some(placeholder) if self.selected.borrow().is_empty() => {
+                Some((placeholder.as_str(), &state.placeholder, true))
+            }
+            _ => self.label.as_ref().map(|label| (label.as_str(), &state.label, false)),
+        }
+    }
+
+    /// Returns the bounds left to the label of the [`MultiPickList`], inside its padding and
+    /// before its [`Handle`].
+    fn label_bounds(&self, bounds: Rectangle, renderer: &Renderer) -> Rectangle {
+        let size = |size: Option<Pixels>| size.unwrap_or_else(|| renderer.default_size()).0;
+
+        // The handle is kept apart from the label by the left padding, like in the layout.
+        let handle_width = match &self.handle {
+            Handle::Arrow { size: handle_size } => size(*handle_size) + self.padding.left,
+            Handle::Static(icon) => size(icon.size) + self.padding.left,
+            Handle::Dynamic { open, closed } => size(open.size).max(size(closed.size)) + self.padding.left,
+            Handle::None => 0.0,
+        };
+
+        Rectangle {
+            x: bounds.x + self.padding.left,
+            y: bounds.y + self.padding.top,
+            width: (bounds.width - self.padding.x() - handle_width).max(0.0),
+            height: (bounds.height - self.padding.y()).max(0.0),
+        }
+    }
+
+    /// Draws the compact button of the [`MultiPickList`] with the given [`Icon`].
+    fn draw_compact(
+        &self,
+        renderer: &mut Renderer,
+        icon: &Icon<Renderer::Font>,
+        badge: &paragraph::Plain<Renderer::Paragraph>,
+        style: &Style,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        let size = icon.size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_text(
+            Text {
+                content: icon.code_point.to_string(),
+                size,
+                line_height: icon.line_height,
+                font: icon.font,
+                bounds: bounds.size(),
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                shaping: icon.shaping,
+                wrapping: text::Wrapping::None,
+            },
+            bounds.center(),
+            style.handle_color,
+            *viewport,
+        );
+
+        if self.selected.borrow().is_empty() {
+            return;
+        }
+
+        let badge_line_height = f32::from(text::LineHeight::default().to_absolute(Pixels(size.0 * 0.7)));
+
+        // The badge sits on the top right corner of the button, growing to the left with the count.
+        let badge_width = badge_line_height.max(badge.min_width() + badge_line_height / 2.0);
+        let badge_bounds = Rectangle {
+            x: bounds.x + bounds.width - badge_width / 2.0 - badge_line_height / 2.0,
+            y: bounds.y - badge_line_height / 2.0,
+            width: badge_width,
+            height: badge_line_height,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: badge_bounds,
+                border: border::rounded(badge_line_height / 2.0),
+                ..renderer::Quad::default()
+            },
+            style.badge_background,
+        );
+
+        renderer.fill_paragraph(badge.raw(), badge_bounds.center(), style.badge_text_color, *viewport);
+    }
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MultiPickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq + 'a,
+    L: Borrow<[T]>,
+    V: Borrow<[T]>,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let options = self.options.borrow();
+
+        let keys: Vec<u64> = options.iter().map(|option| self.option_key(option)).collect();
+        let is_changed = keys != state.keys;
+
+        if is_changed {
+            // The state of every option follows its key, so moved options keep their paragraph.
+            let diff = menu::Diff::new(&state.keys, &keys);
+
+            diff.reorder(&mut state.options);
+            diff.reorder(&mut state.expanded);
+
+            state.pinned = state.pinned.iter().filter_map(|index| diff.index(*index)).collect();
+            state.pinned.sort_unstable();
+
+            if let Some(batch) = &mut state.batch {
+                *batch = batch.iter().filter_map(|index| diff.index(*index)).collect();
+            }
+
+            state.hovered_option = state.hovered_option.and_then(|index| diff.index(index));
+            state.keys = keys;
+        }
+
+        match &self.option_depth {
+            Some(option_depth) => {
+                state.depths = options.iter().map(option_depth).collect();
+                state.expanded.resize(options.len(), false);
+            }
+            None => state.depths.clear(),
+        }
+
+        if is_changed && state.is_open {
+            state.entries = self.entries(state);
+        }
+
+        let option_text = Text {
+            content: "",
+            bounds: Size::new(f32::INFINITY, self.text_line_height.to_absolute(text_size).into()),
+            size: text_size,
+            line_height: self.text_line_height,
+            font,
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::default(),
+        };
+
+        for (option, paragraph) in options.iter().zip(state.options.iter_mut()) {
+            let label = (self.display)(option);
+
+            let _ = paragraph.update(Text {
+                content: &label,
+                ..option_text
+            });
+        }
+
+        if let Some(label) = &self.label {
+            let _ = state.label.update(Text {
+                content: label,
+                ..option_text
+            });
+        }
+
+        if let Some(placeholder) = &self.placeholder {
+            let _ = state.placeholder.update(Text {
+                content: placeholder,
+                ..option_text
+            });
+        }
+
+        if let Some(icon) = &self.compact {
+            let size = icon.size.unwrap_or(text_size);
+            let line_height = f32::from(icon.line_height.to_absolute(size));
+
+            if state.is_open {
+                state.entries = self.entries(state);
+            }
+
+            let badge_size = Pixels(icon.size.unwrap_or_else(|| renderer.default_size()).0 * 0.7);
+
+            let _ = state.badge.update(Text {
+                content: &self.selected.borrow().len().to_string(),
+                bounds: Size::INFINITY,
+                size: badge_size,
+                line_height: text::LineHeight::default(),
+                font,
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::None,
+            });
+
+            let intrinsic = Size::new(line_height, line_height).expand(self.padding);
+
+            return layout::Node::new(limits.width(self.width).resolve(self.width, Length::Shrink, intrinsic));
+        }
+
+        let max_width = match self.width {
+            Length::Shrink => {
+                let labels_width = state
+                    .options
+                    .iter()
+                    .fold(0.0, |width, paragraph| f32::max(width, paragraph.min_width()));
 
-                labels_width.max(self.label.as_ref().map(|_| state.label.min_width()).unwrap_or(0.0))
+                labels_width
+                    .max(self.label.as_ref().map(|_| state.label.min_width()).unwrap_or(0.0))
+                    .max(
+                        self.placeholder
+                            .as_ref()
+                            .map(|_| state.placeholder.min_width())
+                            .unwrap_or(0.0),
+                    )
             }
             _ => 0.0,
         };
@@ -262,6 +1150,28 @@ where
                 .expand(self.padding)
         };
 
+        if state.is_open {
+            state.entries = self.entries(state);
+        }
+
+        let label_bounds = self.label_bounds(Rectangle::with_size(size), renderer);
+
+        state.ellipsized = match self.shown_label(state) {
+            Some((content, paragraph, _))
+                if self.overflow == Overflow::Ellipsis && paragraph.min_width() > label_bounds.width =>
+            {
+                Some(menu::ellipsize::<Renderer::Paragraph>(
+                    content,
+                    Text {
+                        content,
+                        bounds: label_bounds.size(),
+                        ..option_text
+                    },
+                ))
+            }
+            _ => None,
+        };
+
         layout::Node::new(size)
     }
 
@@ -271,55 +1181,171 @@ where
         event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+        if state.is_open
+            && state.placement.is_none()
+            && let Some(placement) = state.menu.take_placement()
+        {
+            state.placement = Some(placement);
+
+            if let Some(on_open_with) = &self.on_open_with {
+                shell.publish(on_open_with(self.context(layout.bounds(), state.placement)));
+            }
+        }
+
+        if state.menu.take_dismissed() && state.is_open {
+            self.close_menu(state, layout.bounds(), shell);
+            shell.request_redraw();
+        }
+
+        if let Some(request) = state.request.take() {
+            match request {
+                Request::Open if !state.is_open => self.open_menu(state, shell),
+                Request::Close if state.is_open => self.close_menu(state, layout.bounds(), shell),
+                _ => {}
+            }
+
+            shell.request_redraw();
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if state.is_open {
                     // Event wasn't processed by overlay, so cursor was clicked either outside its
                     // bounds or on the drop-down, either way we close the overlay.
-                    state.is_open = false;
-                    state.hovered_option = None;
-
-                    if let Some(on_close) = &self.on_close {
-                        shell.publish(on_close.clone());
-                    }
-
+                    self.close_menu(state, layout.bounds(), shell);
+                    state.is_focused = cursor.is_over(layout.bounds());
                     shell.capture_event();
                 } else if cursor.is_over(layout.bounds()) {
-                    state.is_open = true;
+                    state.is_focused = true;
+                    self.open_menu(state, shell);
+                    shell.capture_event();
+                } else {
+                    state.is_focused = false;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if self.wheel_cycles && !self.read_only && !state.is_open && cursor.is_over(layout.bounds()) =>
+            {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => {
+                        // Trackpads scroll by many small amounts, which only move once they add up
+                        // to a line of text.
+                        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+                        let line_height = f32::from(self.text_line_height.to_absolute(text_size)).max(1.0);
+
+                        state.wheel_offset += y;
+
+                        let lines = (state.wheel_offset / line_height).trunc();
+                        state.wheel_offset -= lines * line_height;
 
-                    if let Some(on_open) = &self.on_open {
-                        shell.publish(on_open.clone());
+                        lines
                     }
+                };
 
-                    shell.capture_event();
+                // Scrolling down moves forward, like the options are listed in the menu.
+                if lines != 0.0 {
+                    self.cycle(lines < 0.0, shell);
                 }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) if state.is_open => {
+                // The menu lets Escape through, since only the field can close it.
+                self.close_menu(state, layout.bounds(), shell);
+                state.is_focused = true;
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Enter | keyboard::key::Named::Space),
+                ..
+            }) if state.is_focused && !state.is_open => {
+                self.open_menu(state, shell);
+                shell.request_redraw();
+                shell.capture_event();
             }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 state.keyboard_modifiers = *modifiers;
             }
+            Event::Window(window::Event::Unfocused) if state.is_open => {
+                // Otherwise the menu lingers over the window after switching away from it.
+                self.close_menu(state, layout.bounds(), shell);
+                shell.request_redraw();
+            }
             _ => {}
         };
 
         let status = {
             let is_hovered = cursor.is_over(layout.bounds());
+            let selected_count = self.selected.borrow().len();
 
             if state.is_open {
-                Status::Opened { is_hovered }
+                Status::Opened {
+                    is_hovered,
+                    is_above: state.placement == Some(menu::Placement::Above),
+                    selected_count,
+                }
             } else if is_hovered {
-                Status::Hovered
+                Status::Hovered { selected_count }
+            } else if state.is_focused {
+                Status::Focused { selected_count }
             } else {
-                Status::Active
+                Status::Active { selected_count }
             }
         };
 
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && state.is_open
+            && state.reveal < 1.0
+        {
+            // The animation starts with the first frame of the open menu.
+            let opened_at = *state.opened_at.get_or_insert(*now);
+            let progress = now.saturating_duration_since(opened_at).as_secs_f32() / self.menu_animation.as_secs_f32();
+
+            // Eases out cubically, so the menu slows down as it settles.
+            state.reveal = 1.0 - (1.0 - progress.min(1.0)).powi(3);
+
+            if progress < 1.0 {
+                shell.request_redraw();
+            }
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && self.overflow == Overflow::Marquee
+            && self.compact.is_none()
+        {
+            let label_width = self
+                .shown_label(state)
+                .map_or(0.0, |(_, paragraph, _)| paragraph.min_width());
+
+            if label_width > self.label_bounds(layout.bounds(), renderer).width {
+                if let Some(marquee_tick) = state.marquee_tick {
+                    let elapsed = now.saturating_duration_since(marquee_tick).as_secs_f32();
+
+                    state.marquee_offset =
+                        (state.marquee_offset + MARQUEE_SPEED * elapsed) % (label_width + MARQUEE_GAP);
+                }
+
+                state.marquee_tick = Some(*now);
+                shell.request_redraw();
+            } else {
+                state.marquee_offset = 0.0;
+                state.marquee_tick = None;
+            }
+        }
+
         if let Event::Window(window::Event::RedrawRequested(_now)) = event {
             self.last_status = Some(status);
         } else if self.last_status.is_some_and(|last_status| last_status != status) {
@@ -327,6 +1353,13 @@ where
         }
     }
 
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, _renderer: &Renderer, operation: &mut dyn Operation) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        operation.custom(self.id.as_ref(), layout.bounds(), &mut state.request);
+    }
+
     fn mouse_interaction(
         &self,
         _tree: &Tree,
@@ -357,7 +1390,13 @@ where
     ) {
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let bounds = layout.bounds();
-        let style = Catalog::style(theme, &self.class, self.last_status.unwrap_or(Status::Active));
+        let style = Catalog::style(
+            theme,
+            &self.class,
+            self.last_status.unwrap_or(Status::Active {
+                selected_count: self.selected.borrow().len(),
+            }),
+        );
 
         renderer.fill_quad(
             renderer::Quad {
@@ -368,6 +1407,11 @@ where
             style.background,
         );
 
+        if let Some(icon) = &self.compact {
+            self.draw_compact(renderer, icon, &state.badge, &style, bounds, viewport);
+            return;
+        }
+
         let handle = match &self.handle {
             Handle::Arrow { size } => Some((
                 Renderer::ICON_FONT,
@@ -420,22 +1464,84 @@ where
             );
         }
 
-        if let Some(label) = &self.label {
+        let Some((_, paragraph, is_placeholder)) = self.shown_label(state) else {
+            return;
+        };
+
+        let label_bounds = self.label_bounds(bounds, renderer);
+
+        let Some(clip_bounds) = label_bounds.intersection(viewport) else {
+            return;
+        };
+
+        let color = if is_placeholder {
+            style.placeholder_color
+        } else {
+            style.text_color
+        };
+        let label_width = paragraph.min_width();
+        let is_overflowing = label_width > label_bounds.width;
+
+        if let Some(ellipsized) = &state.ellipsized {
             renderer.fill_text(
                 Text {
-                    content: label.clone(),
+                    content: ellipsized.clone(),
                     bounds: Size::new(f32::INFINITY, bounds.height),
                     size: self.text_size.unwrap_or_else(|| renderer.default_size()),
                     line_height: self.text_line_height,
                     font: self.font.unwrap_or_else(|| renderer.default_font()),
                     align_x: text::Alignment::Left,
                     align_y: alignment::Vertical::Center,
-                    shaping: text::Shaping::Basic,
+                    shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
                 },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
-                style.text_color,
-                *viewport,
+                Point::new(label_bounds.x, bounds.center_y()),
+                color,
+                clip_bounds,
+            );
+
+            return;
+        }
+
+        if self.overflow == Overflow::Marquee && is_overflowing {
+            let x = label_bounds.x - state.marquee_offset;
+
+            // A second copy follows the first one, so the loop has no visible seam.
+            renderer.with_layer(clip_bounds, |renderer| {
+                for x in [x, x + label_width + MARQUEE_GAP] {
+                    renderer.fill_paragraph(paragraph.raw(), Point::new(x, bounds.center_y()), color, clip_bounds);
+                }
+            });
+
+            return;
+        }
+
+        renderer.fill_paragraph(
+            paragraph.raw(),
+            Point::new(label_bounds.x, bounds.center_y()),
+            color,
+            clip_bounds,
+        );
+
+        if self.overflow == Overflow::Fade
+            && is_overflowing
+            && let Background::Color(background) = style.background
+        {
+            let width = FADE_WIDTH.min(label_bounds.width / 2.0);
+
+            // An angle of three quarter turns runs from the right to the left of the bounds.
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: label_bounds.x + label_bounds.width - width,
+                        width,
+                        ..label_bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                gradient::Linear::new(Radians(3.0 * f32::consts::FRAC_PI_2))
+                    .add_stop(0.0, background)
+                    .add_stop(1.0, Color { a: 0.0, ..background }),
             );
         }
     }
@@ -454,10 +1560,42 @@ where
         if state.is_open {
             let bounds = layout.bounds();
 
-            let on_select = &self.on_select;
-
-            let mut menu = menu::Menu::new(
-                &mut state.menu,
+            // A compact menu fits its options, and so do the columns of a menu, which can outgrow
+            // the viewport and are panned sideways then.
+            let width = if self.compact.is_some() || self.menu_columns > 1 {
+                let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+                let text_line_height = f32::from(self.text_line_height.to_absolute(text_size));
+                let checkbox_size = self.checkbox_size.map(|size| size.0);
+                let box_size = menu::checkbox_size(checkbox_size, text_line_height, self.padding);
+                // Indented options need as much more room as they are shifted.
+                let shift = |option: &T| {
+                    self.option_indentation
+                        .as_ref()
+                        .map_or(0.0, |indentation| f32::from(indentation(option)) * self.indent_width)
+                };
+                let labels_width = state
+                    .options
+                    .iter()
+                    .zip(self.options.borrow())
+                    .fold(0.0, |width, (paragraph, option)| {
+                        f32::max(width, paragraph.min_width() + shift(option))
+                    });
+                let (leading, trailing) = self.selection_indicator.reserved(box_size, self.checkbox_spacing.0);
+
+                let column_width = labels_width + self.padding.x() + leading + trailing;
+
+                bounds.width.max(column_width * self.menu_columns as f32)
+            } else {
+                bounds.width
+            };
+
+            let on_select = &self.on_select;
+            let on_toggle = self.on_toggle.as_deref();
+            let is_same = self.is_same.as_deref();
+            let selected = self.selected.borrow();
+
+            let mut menu = menu::Menu::new(
+                &mut state.menu,
                 self.options.borrow(),
                 self.selected.borrow(),
                 &mut state.hovered_option,
@@ -466,15 +1604,118 @@ where
                     // things at once
                     // state.is_open = false;
 
-                    (on_select)(option)
+                    match on_toggle {
+                        Some(on_toggle) => {
+                            let is_selected = !contains(selected, &option, is_same);
+
+                            on_toggle(option, is_selected)
+                        }
+                        None => (on_select)(option),
+                    }
                 },
-                None,
+                self.display.as_ref(),
                 &self.menu_class,
             )
-            .width(bounds.width)
+            .width(width)
             .padding(self.padding)
             .font(font)
-            .text_shaping(self.text_shaping);
+            .text_line_height(self.text_line_height)
+            .text_shaping(self.text_shaping)
+            .text_wrapping(self.text_wrapping)
+            .text_ellipsis(self.text_ellipsis)
+            .text_align_x(self.text_align_x)
+            .text_align_y(self.text_align_y)
+            .reveal(state.reveal)
+            .backdrop(self.menu_backdrop)
+            .overscroll(self.menu_overscroll)
+            .z_index(self.menu_z_index)
+            .margin(self.menu_margin)
+            .read_only(self.read_only)
+            .loading(self.is_loading)
+            .columns(self.menu_columns)
+            .selection_indicator(self.selection_indicator)
+            .checkbox_spacing(self.checkbox_spacing)
+            .keys(&state.keys);
+
+            if let Some(batch) = &mut state.batch {
+                menu = menu.batch(batch);
+            }
+
+            if let Some(scrollbar) = self.menu_scrollbar {
+                menu = menu.scrollbar(scrollbar);
+            }
+
+            if let Some((style, into_class)) = &self.menu_scrollable_style {
+                menu = menu.scrollable_class(into_class(Box::new(move |theme, status| style(theme, status))));
+            }
+
+            if self.option_depth.is_some() {
+                menu = menu
+                    .hierarchy(&state.depths, &mut state.expanded)
+                    .select_children(self.select_children);
+            }
+
+            if let Some(option_indentation) = &self.option_indentation {
+                menu = menu
+                    .indentation(option_indentation.as_ref())
+                    .indent_width(self.indent_width);
+            }
+
+            if !state.entries.is_empty() {
+                menu = menu.entries(&state.entries);
+            }
+
+            if let Some(empty_text) = &self.empty_text {
+                menu = menu.empty_text(empty_text);
+            }
+
+            if let Some(option_height) = self.option_height {
+                menu = menu.option_height(option_height);
+            }
+
+            if let Some(checkbox_size) = self.checkbox_size {
+                menu = menu.checkbox_size(checkbox_size);
+            }
+
+            if let Some(menu_footer) = &mut self.menu_footer {
+                menu = menu.footer(menu_footer);
+            }
+
+            if let Some(menu_header) = &self.menu_header {
+                menu = menu.header(menu_header);
+            }
+
+            if let Some(is_same) = is_same {
+                menu = menu.is_same(is_same);
+            }
+
+            if let Some(on_option_hovered) = &self.on_option_hovered {
+                menu = menu.on_option_hovered(on_option_hovered.as_ref());
+            }
+
+            if let Some(option_badge) = &self.option_badge {
+                menu = menu.badge(option_badge.as_ref());
+            }
+
+            if let Some(option_style) = &self.option_style {
+                menu = menu.option_style(option_style.as_ref());
+            }
+
+            if let Some(option_disabled) = &self.option_disabled {
+                menu = menu.option_disabled(option_disabled.as_ref());
+            }
+
+            if let Some(option_tooltip) = &self.option_tooltip {
+                menu = menu.tooltip(option_tooltip.as_ref());
+            }
+
+            if let Some(tooltip_delay) = self.tooltip_delay {
+                menu = menu.tooltip_delay(tooltip_delay);
+            }
+
+            if let Some(hover_delay) = self.hover_delay {
+                menu = menu.hover_delay(hover_delay);
+            }
 
             if let Some(text_size) = self.text_size {
                 menu = menu.text_size(text_size);
@@ -495,7 +1736,7 @@ where
 impl<'a, T, L, V, Message, Theme, Renderer> From<MultiPickList<'a, T, L, V, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    T: Clone + ToString + PartialEq + 'a,
+    T: Clone + PartialEq + 'a,
     L: Borrow<[T]> + 'a,
     V: Borrow<[T]> + 'a,
     Message: Clone + 'a,
@@ -507,14 +1748,42 @@ where
     }
 }
 
+/// The speed of the label of a [`MultiPickList`] scrolling with [`Overflow::Marquee`], in logical
+/// pixels per second.
+const MARQUEE_SPEED: f32 = 40.0;
+
+/// The space between the end of the label of a [`MultiPickList`] scrolling with
+/// [`Overflow::Marquee`] and its next copy.
+const MARQUEE_GAP: f32 = 32.0;
+
+/// The width of the edge of the label of a [`MultiPickList`] fading out with [`Overflow::Fade`].
+const FADE_WIDTH: f32 = 24.0;
+
 #[derive(Debug)]
 struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    opened_at: Option<Instant>,
+    reveal: f32,
+    placement: Option<menu::Placement>,
+    is_focused: bool,
+    request: Option<Request>,
     hovered_option: Option<usize>,
+    pinned: Vec<usize>,
+    batch: Option<Vec<usize>>,
+    keys: Vec<u64>,
+    entries: Vec<menu::Entry>,
+    depths: Vec<usize>,
+    expanded: Vec<bool>,
     options: Vec<paragraph::Plain<P>>,
     label: paragraph::Plain<P>,
+    placeholder: paragraph::Plain<P>,
+    badge: paragraph::Plain<P>,
+    ellipsized: Option<String>,
+    marquee_offset: f32,
+    marquee_tick: Option<Instant>,
+    wheel_offset: f32,
 }
 
 impl<P: text::Paragraph> State<P> {
@@ -524,19 +1793,108 @@ impl<P: text::Paragraph> State<P> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            opened_at: None,
+            reveal: 1.0,
+            placement: None,
+            is_focused: false,
+            request: None,
             hovered_option: Option::default(),
+            pinned: Vec::new(),
+            batch: None,
+            keys: Vec::new(),
+            entries: Vec::new(),
+            depths: Vec::new(),
+            expanded: Vec::new(),
             options: Vec::new(),
             label: paragraph::Plain::default(),
+            placeholder: paragraph::Plain::default(),
+            badge: paragraph::Plain::default(),
+            ellipsized: None,
+            marquee_offset: 0.0,
+            marquee_tick: None,
+            wheel_offset: 0.0,
         }
     }
 }
 
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<P: text::Paragraph> Default for State<P> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// A pending request to open or close a [`MultiPickList`], produced by an [`Operation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Request {
+    Open,
+    Close,
+}
+
+/// Produces an [`Operation`] that opens the [`MultiPickList`] with the given [`widget::Id`].
+pub fn open<T>(id: impl Into<widget::Id>) -> impl Operation<T> {
+    Toggle {
+        target: id.into(),
+        request: Request::Open,
+    }
+}
+
+/// Produces an [`Operation`] that closes the [`MultiPickList`] with the given [`widget::Id`].
+pub fn close<T>(id: impl Into<widget::Id>) -> impl Operation<T> {
+    Toggle {
+        target: id.into(),
+        request: Request::Close,
+    }
+}
+
+struct Toggle {
+    target: widget::Id,
+    request: Request,
+}
+
+impl<T> Operation<T> for Toggle {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        operate(self);
+    }
+
+    fn custom(&mut self, id: Option<&widget::Id>, _bounds: Rectangle, state: &mut dyn Any) {
+        if id == Some(&self.target)
+            && let Some(request) = state.downcast_mut::<Option<Request>>()
+        {
+            *request = Some(self.request);
+        }
+    }
+}
+
+/// Returns whether the given option is among the selected values, comparing them with the given
+/// closure if any.
+fn contains<T: PartialEq>(selected: &[T], option: &T, is_same: Option<&dyn Fn(&T, &T) -> bool>) -> bool {
+    match is_same {
+        Some(is_same) => selected.iter().any(|value| is_same(value, option)),
+        None => selected.contains(option),
+    }
+}
+
+/// Returns the hash of the given key.
+fn hash(key: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// The handle to the right side of the [`MultiPickList`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Handle<Font> {
@@ -581,20 +1939,109 @@ pub struct Icon<Font> {
     pub shaping: text::Shaping,
 }
 
+/// The order of the options in the [`Menu`] of a [`MultiPickList`].
+pub enum SortOrder<'a, T> {
+    /// The options are shown in the order they are given.
+    ///
+    /// This is the default.
+    None,
+    /// The options are sorted by their label, from A to Z.
+    Ascending,
+    /// The options are sorted by their label, from Z to A.
+    Descending,
+    /// The options selected when the [`Menu`] opened come first, in the order they are given.
+    ///
+    /// Unlike [`MultiPickList::pin_selected`], no divider is drawn after them.
+    SelectedFirst,
+    /// The options are sorted with the given comparator.
+    Custom(Box<dyn Fn(&T, &T) -> Ordering + 'a>),
+}
+
+impl<T> Default for SortOrder<'_, T> {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// How the label of a [`MultiPickList`] is shown when it does not fit its field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// The label is cut off before the [`Handle`].
+    ///
+    /// This is the default.
+    #[default]
+    Clip,
+    /// The label is cut off before the [`Handle`], fading into the background of the field.
+    ///
+    /// The fade is only drawn over a [`Background::Color`].
+    Fade,
+    /// The label is truncated with an ellipsis.
+    Ellipsis,
+    /// The label scrolls sideways in a loop.
+    Marquee,
+}
+
+/// The status of an option in the [`Menu`] of a [`MultiPickList`], as given to
+/// [`MultiPickList::option_style`].
+pub type OptionStatus = menu::Status;
+
+/// The context of the [`Menu`] of a [`MultiPickList`] being opened or closed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Context {
+    /// The bounds of the [`MultiPickList`] the [`Menu`] is anchored to.
+    pub bounds: Rectangle,
+    /// Where the [`Menu`] was placed, if it was laid out before closing.
+    pub placement: Option<menu::Placement>,
+    /// The amount of options of the [`Menu`].
+    pub options: usize,
+}
+
 /// The possible status of a [`MultiPickList`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
     /// The [`MultiPickList`] can be interacted with.
-    Active,
+    Active {
+        /// The amount of selected options.
+        selected_count: usize,
+    },
     /// The [`MultiPickList`] is being hovered.
-    Hovered,
+    Hovered {
+        /// The amount of selected options.
+        selected_count: usize,
+    },
+    /// The [`MultiPickList`] has keyboard focus.
+    Focused {
+        /// The amount of selected options.
+        selected_count: usize,
+    },
     /// The [`MultiPickList`] is open.
     Opened {
         /// Whether the [`MultiPickList`] is hovered, while open.
         is_hovered: bool,
+        /// Whether the [`Menu`] opened above the [`MultiPickList`], to merge their borders.
+        is_above: bool,
+        /// The amount of selected options.
+        selected_count: usize,
     },
 }
 
+impl Status {
+    /// Returns the amount of selected options of the [`MultiPickList`].
+    pub fn selected_count(self) -> usize {
+        match self {
+            Status::Active { selected_count }
+            | Status::Hovered { selected_count }
+            | Status::Focused { selected_count }
+            | Status::Opened { selected_count, .. } => selected_count,
+        }
+    }
+
+    /// Returns whether no option of the [`MultiPickList`] is selected.
+    pub fn is_empty(self) -> bool {
+        self.selected_count() == 0
+    }
+}
+
 /// The appearance of a pick list.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
@@ -608,6 +2055,39 @@ pub struct Style {
     pub background: Background,
     /// The [`Border`] of the pick list.
     pub border: Border,
+    /// The [`Background`] of the badge counting the selected options of a compact pick list.
+    pub badge_background: Background,
+    /// The text [`Color`] of the badge counting the selected options of a compact pick list.
+    pub badge_text_color: Color,
+}
+
+#[cfg(feature = "stock-styles")]
+impl From<Style> for iced_widget::pick_list::Style {
+    fn from(style: Style) -> Self {
+        Self {
+            text_color: style.text_color,
+            placeholder_color: style.placeholder_color,
+            handle_color: style.handle_color,
+            background: style.background,
+            border: style.border,
+        }
+    }
+}
+
+/// Converts the style of a stock pick list, drawing the badge in its handle [`Color`].
+#[cfg(feature = "stock-styles")]
+impl From<iced_widget::pick_list::Style> for Style {
+    fn from(style: iced_widget::pick_list::Style) -> Self {
+        Self {
+            text_color: style.text_color,
+            placeholder_color: style.placeholder_color,
+            handle_color: style.handle_color,
+            background: style.background,
+            border: style.border,
+            badge_background: Background::Color(style.handle_color),
+            badge_text_color: style.text_color,
+        }
+    }
 }
 
 /// The theme catalog of a [`MultiPickList`].
@@ -658,13 +2138,23 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             width: 1.0,
             color: palette.background.strong.color,
         },
+        badge_background: palette.primary.strong.color.into(),
+        badge_text_color: palette.primary.strong.text,
     };
 
     match status {
-        Status::Active => active,
-        Status::Hovered | Status::Opened { .. } => Style {
+        Status::Active { .. } => active,
+        Status::Hovered { .. } | Status::Opened { .. } => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Focused { .. } => Style {
             border: Border {
                 color: palette.primary.strong.color,
+                width: 2.0,
                 ..active.border
             },
             ..active
@@ -674,22 +2164,44 @@ pub fn default(theme: &Theme, status: Status) -> Style {
 
 pub mod menu {
     //! Build and show dropdown menus.
+    //!
+    //! A [`Menu`] is the dropdown of a [`MultiPickList`], and it can be embedded in any other
+    //! widget as well. The owner keeps a [`State`] and the hovered option in its own tree,
+    //! builds a [`Menu`] in [`Widget::overlay`] while it is open, and turns it into an overlay
+    //! anchored to its bounds with [`Menu::overlay`].
+    //!
+    //! A [`Menu`] never closes itself. Clicks outside of it fall through to the owner, and
+    //! [`State::take_dismissed`] tells when the backdrop or the close button was clicked, or when
+    //! an option was picked in [`Mode::Single`].
+    //!
+    //! [`MultiPickList`]: super::MultiPickList
+    use std::cell::Cell;
+    use std::collections::{HashMap, VecDeque};
+    use std::ops::Range;
+
     use iced_core::border::Border;
+    use iced_core::keyboard;
     use iced_core::layout::{self, Layout};
     use iced_core::mouse;
     use iced_core::overlay;
     use iced_core::renderer;
-    use iced_core::text::{self, Text};
+    use iced_core::text::{self, Text, paragraph};
+    use iced_core::time::{Duration, Instant};
     use iced_core::touch;
+    use iced_core::widget::operation::scrollable::{AbsoluteOffset, scroll_by};
     use iced_core::widget::tree::{self, Tree};
+    use iced_core::widget::{self, Operation};
     use iced_core::window;
     use iced_core::{
-        Background, Clipboard, Color, Event, Length, Padding, Pixels, Point, Rectangle, Shadow, Size, Theme, Vector,
+        Background, Clipboard, Color, Event, Length, Padding, Pixels, Point, Radians, Rectangle, Shadow, Size, Theme,
+        Vector,
     };
     use iced_core::{Element, Shell, Widget};
-    use iced_core::{alignment, border};
+    use iced_core::{alignment, border, gradient};
     use iced_widget::scrollable::{self, Scrollable};
 
+    use crate::anchored_overlay;
+
     /// A list of selectable options.
     pub struct Menu<'a, 'b, T, Message, Theme, Renderer>
     where
@@ -699,61 +2211,221 @@ pub mod menu {
     {
         state: &'a mut State,
         options: &'a [T],
+        entries: Option<&'a [Entry]>,
         selected: &'a [T],
         hovered_option: &'a mut Option<usize>,
         on_selected: Box<dyn FnMut(T) -> Message + 'a>,
         on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+        display: &'a dyn Fn(&T) -> String,
+        mode: Mode,
+        is_same: Option<&'a dyn Fn(&T, &T) -> bool>,
+        badge: Option<&'a dyn Fn(&T) -> Option<String>>,
+        option_style: Option<&'a dyn Fn(&T, Status) -> OptionStyle>,
+        option_disabled: Option<&'a dyn Fn(&T) -> bool>,
+        tooltip: Option<&'a dyn Fn(&T) -> Option<String>>,
+        tooltip_delay: Duration,
+        hover_delay: Duration,
         width: f32,
         padding: Padding,
         text_size: Option<Pixels>,
         text_line_height: text::LineHeight,
         text_shaping: text::Shaping,
+        text_wrapping: text::Wrapping,
+        text_ellipsis: bool,
+        text_align_x: text::Alignment,
+        text_align_y: alignment::Vertical,
         font: Option<Renderer::Font>,
+        reveal: f32,
+        backdrop: bool,
+        overscroll: bool,
+        margin: f32,
+        z_index: f32,
+        columns: usize,
+        selection_indicator: SelectionIndicator,
+        hierarchy: Option<(&'a [usize], &'a mut [bool])>,
+        indentation: Option<&'a dyn Fn(&T) -> u16>,
+        indent_width: f32,
+        select_children: bool,
+        read_only: bool,
+        footer: Option<&'a mut Element<'b, Message, Theme, Renderer>>,
+        header: Option<&'a str>,
+        empty_text: Option<&'a str>,
+        is_loading: bool,
+        option_height: Option<f32>,
+        checkbox_size: Option<f32>,
+        checkbox_spacing: f32,
+        option_font: Option<&'a dyn Fn(&T) -> Option<Renderer::Font>>,
+        type_ahead: bool,
+        batch: Option<&'a mut Vec<usize>>,
+        keys: Option<&'a [u64]>,
+        scrollbar: Option<scrollable::Scrollbar>,
+        scrollable_class: Option<<Theme as scrollable::Catalog>::Class<'a>>,
         class: &'a <Theme as Catalog>::Class<'b>,
     }
 
     impl<'a, 'b, T, Message, Theme, Renderer> Menu<'a, 'b, T, Message, Theme, Renderer>
     where
-        T: ToString + Clone + PartialEq,
+        T: Clone + PartialEq,
         Message: 'a,
         Theme: Catalog + 'a,
         Renderer: text::Renderer + 'a,
         'b: 'a,
     {
-        /// Creates a new [`Menu`] with the given [`State`], a list of options,
-        /// the message to produced when an option is selected, and its [`Style`].
+        /// Creates a new [`Menu`] with the given [`State`], a list of options, the selected
+        /// ones, the index of the hovered option, the message to produce when an option is
+        /// selected, the label of every option, and its class.
+        ///
+        /// The [`State`] and the hovered option are kept by the owner between frames, since a
+        /// [`Menu`] is built again every time it is shown.
         pub fn new(
             state: &'a mut State,
             options: &'a [T],
             selected: &'a [T],
             hovered_option: &'a mut Option<usize>,
             on_selected: impl FnMut(T) -> Message + 'a,
-            on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+            display: &'a dyn Fn(&T) -> String,
             class: &'a <Theme as Catalog>::Class<'b>,
         ) -> Self {
             Menu {
                 state,
                 options,
+                entries: None,
                 selected,
                 hovered_option,
                 on_selected: Box::new(on_selected),
-                on_option_hovered,
+                on_option_hovered: None,
+                display,
+                mode: Mode::default(),
+                is_same: None,
+                badge: None,
+                option_style: None,
+                option_disabled: None,
+                tooltip: None,
+                tooltip_delay: DEFAULT_TOOLTIP_DELAY,
+                hover_delay: Duration::ZERO,
                 width: 0.0,
                 padding: Padding::ZERO,
                 text_size: None,
                 text_line_height: text::LineHeight::default(),
                 text_shaping: text::Shaping::default(),
+                text_wrapping: text::Wrapping::None,
+                text_ellipsis: false,
+                text_align_x: text::Alignment::Default,
+                text_align_y: alignment::Vertical::Center,
                 font: None,
+                reveal: 1.0,
+                backdrop: false,
+                overscroll: false,
+                margin: DEFAULT_MARGIN,
+                z_index: DEFAULT_Z_INDEX,
+                columns: 1,
+                selection_indicator: SelectionIndicator::default(),
+                hierarchy: None,
+                indentation: None,
+                indent_width: DEFAULT_INDENT_WIDTH,
+                select_children: false,
+                read_only: false,
+                footer: None,
+                header: None,
+                empty_text: None,
+                is_loading: false,
+                option_height: None,
+                checkbox_size: None,
+                checkbox_spacing: DEFAULT_CHECKBOX_SPACING,
+                option_font: None,
+                type_ahead: true,
+                batch: None,
+                keys: None,
+                scrollbar: None,
+                scrollable_class: None,
                 class,
             }
         }
 
         /// Sets the width of the [`Menu`].
+        ///
+        /// A [`Menu`] wider than the viewport is narrowed to fit it, and its options are panned
+        /// sideways with a horizontal wheel, the wheel while holding shift, or a finger.
         pub fn width(mut self, width: f32) -> Self {
             self.width = width;
             self
         }
 
+        /// Sets the [`Mode`] of the [`Menu`].
+        ///
+        /// By default, any amount of options can be selected.
+        pub fn mode(mut self, mode: Mode) -> Self {
+            self.mode = mode;
+            self
+        }
+
+        /// Sets the message to produce when an option is hovered.
+        pub fn on_option_hovered(mut self, on_option_hovered: &'a dyn Fn(T) -> Message) -> Self {
+            self.on_option_hovered = Some(on_option_hovered);
+            self
+        }
+
+        /// Sets the entries of the [`Menu`], deciding the order of its options and
+        /// where separators are drawn.
+        ///
+        /// By default, every option is listed in order.
+        pub fn entries(mut self, entries: &'a [Entry]) -> Self {
+            self.entries = Some(entries);
+            self
+        }
+
+        /// Sets the closure telling whether an option matches a selected value, instead of comparing
+        /// them for equality.
+        pub fn is_same(mut self, is_same: &'a dyn Fn(&T, &T) -> bool) -> Self {
+            self.is_same = Some(is_same);
+            self
+        }
+
+        /// Sets the closure producing the badge drawn at the end of every option, if any.
+        pub fn badge(mut self, badge: &'a dyn Fn(&T) -> Option<String>) -> Self {
+            self.badge = Some(badge);
+            self
+        }
+
+        /// Sets the closure producing the colors of every option given its status, if any.
+        pub fn option_style(mut self, option_style: &'a dyn Fn(&T, Status) -> OptionStyle) -> Self {
+            self.option_style = Some(option_style);
+            self
+        }
+
+        /// Sets the closure telling whether an option is disabled, if any.
+        ///
+        /// Disabled options are drawn with the [`Status::Disabled`] style, and cannot be hovered,
+        /// selected, or reached with the keyboard.
+        pub fn option_disabled(mut self, option_disabled: &'a dyn Fn(&T) -> bool) -> Self {
+            self.option_disabled = Some(option_disabled);
+            self
+        }
+
+        /// Sets the closure producing the tooltip of every option, if any.
+        pub fn tooltip(mut self, tooltip: &'a dyn Fn(&T) -> Option<String>) -> Self {
+            self.tooltip = Some(tooltip);
+            self
+        }
+
+        /// Sets how long an option has to be hovered before its tooltip is shown.
+        ///
+        /// By default, it is [`DEFAULT_TOOLTIP_DELAY`].
+        pub fn tooltip_delay(mut self, delay: Duration) -> Self {
+            self.tooltip_delay = delay;
+            self
+        }
+
+        /// Sets how long an option has to stay hovered before [`on_option_hovered`] is called.
+        ///
+        /// By default, it is called as soon as the option is hovered.
+        ///
+        /// [`on_option_hovered`]: Self::on_option_hovered
+        pub fn hover_delay(mut self, delay: Duration) -> Self {
+            self.hover_delay = delay;
+            self
+        }
+
         /// Sets the [`Padding`] of the [`Menu`].
         pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
             self.padding = padding.into();
@@ -767,7 +2439,6 @@ pub mod menu {
         }
 
         /// Sets the text [`text::LineHeight`] of the [`Menu`].
-        #[allow(unused)]
         pub fn text_line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
             self.text_line_height = line_height.into();
             self
@@ -779,111 +2450,568 @@ pub mod menu {
             self
         }
 
+        /// Sets the [`text::Wrapping`] strategy of the options of the [`Menu`].
+        pub fn text_wrapping(mut self, wrapping: text::Wrapping) -> Self {
+            self.text_wrapping = wrapping;
+            self
+        }
+
+        /// Sets whether options too long for the [`Menu`] are truncated with an ellipsis.
+        ///
+        /// Only options that are not wrapped are truncated.
+        pub fn text_ellipsis(mut self, text_ellipsis: bool) -> Self {
+            self.text_ellipsis = text_ellipsis;
+            self
+        }
+
+        /// Sets the horizontal alignment of the options of the [`Menu`].
+        pub fn text_align_x(mut self, alignment: impl Into<text::Alignment>) -> Self {
+            self.text_align_x = alignment.into();
+            self
+        }
+
+        /// Sets the vertical alignment of the options of the [`Menu`], which shows in rows taller
+        /// than their option.
+        pub fn text_align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+            self.text_align_y = alignment.into();
+            self
+        }
+
         /// Sets the font of the [`Menu`].
         pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
             self.font = Some(font.into());
             self
         }
 
-        /// Turns the [`Menu`] into an overlay [`Element`] at the given target
-        /// position.
+        /// Sets the amount of columns the options of the [`Menu`] flow into.
         ///
-        /// The `target_height` will be used to display the menu either on top
-        /// of the target or under it, depending on the screen position and the
-        /// dimensions of the [`Menu`].
-        pub fn overlay(
-            self,
-            position: Point,
-            viewport: Rectangle,
-            target_height: f32,
-            menu_height: Length,
-        ) -> overlay::Element<'a, Message, Theme, Renderer> {
-            overlay::Element::new(Box::new(Overlay::new(
-                position,
-                viewport,
-                self,
-                target_height,
-                menu_height,
-            )))
+        /// A separator always takes a line of its own.
+        pub fn columns(mut self, columns: usize) -> Self {
+            self.columns = columns.max(1);
+            self
         }
-    }
 
-    /// The local state of a [`Menu`].
-    #[derive(Debug)]
-    pub struct State {
-        tree: Tree,
-    }
+        /// Sets how the selected options of the [`Menu`] are told apart.
+        pub fn selection_indicator(mut self, selection_indicator: SelectionIndicator) -> Self {
+            self.selection_indicator = selection_indicator;
+            self
+        }
 
-    impl State {
-        /// Creates a new [`State`] for a [`Menu`].
-        pub fn new() -> Self {
-            Self { tree: Tree::empty() }
+        /// Sets the depth of every option and whether it is expanded, turning the options into a tree.
+        ///
+        /// Options are expected in depth-first order. Clicking the arrow of a parent toggles its
+        /// expanded state, and the owner of the [`Menu`] is expected to hide its children.
+        pub fn hierarchy(mut self, depths: &'a [usize], expanded: &'a mut [bool]) -> Self {
+            self.hierarchy = Some((depths, expanded));
+            self
         }
-    }
 
-    impl Default for State {
-        fn default() -> Self {
-            Self::new()
+        /// Sets the closure returning the indentation level of every option, which shifts its
+        /// content right by the level times the indent width.
+        ///
+        /// The indentation is added in front of the depth of a [`hierarchy`](Self::hierarchy).
+        pub fn indentation(mut self, indentation: &'a dyn Fn(&T) -> u16) -> Self {
+            self.indentation = Some(indentation);
+            self
         }
-    }
 
-    struct Overlay<'a, 'b, Message, Theme, Renderer>
-    where
-        Theme: Catalog,
-        Renderer: text::Renderer,
-    {
-        position: Point,
-        viewport: Rectangle,
-        tree: &'a mut Tree,
-        list: Scrollable<'a, Message, Theme, Renderer>,
-        width: f32,
-        target_height: f32,
-        class: &'a <Theme as Catalog>::Class<'b>,
-    }
+        /// Sets the width of a level of [`indentation`](Self::indentation).
+        ///
+        /// By default, it is [`DEFAULT_INDENT_WIDTH`].
+        pub fn indent_width(mut self, indent_width: impl Into<Pixels>) -> Self {
+            self.indent_width = indent_width.into().0;
+            self
+        }
 
-    impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
-    where
-        Message: 'a,
-        Theme: Catalog + scrollable::Catalog + 'a,
-        Renderer: text::Renderer + 'a,
-        'b: 'a,
-    {
-        pub fn new<T>(
-            position: Point,
-            viewport: Rectangle,
-            menu: Menu<'a, 'b, T, Message, Theme, Renderer>,
-            target_height: f32,
-            menu_height: Length,
-        ) -> Self
-        where
-            T: Clone + ToString + PartialEq,
-        {
-            let Menu {
+        /// Sets whether selecting a parent option selects its children too.
+        ///
+        /// It only applies to [`Mode::Multiple`].
+        pub fn select_children(mut self, select_children: bool) -> Self {
+            self.select_children = select_children;
+            self
+        }
+
+        /// Sets whether the options of the [`Menu`] can only be inspected, not toggled.
+        pub fn read_only(mut self, read_only: bool) -> Self {
+            self.read_only = read_only;
+            self
+        }
+
+        /// Sets the height of every option of the [`Menu`], instead of deriving it from its text.
+        pub fn option_height(mut self, option_height: impl Into<Pixels>) -> Self {
+            self.option_height = Some(option_height.into().0);
+            self
+        }
+
+        /// Sets the size of the checkbox of every option of the [`Menu`], instead of deriving it
+        /// from the height of its text.
+        pub fn checkbox_size(mut self, checkbox_size: impl Into<Pixels>) -> Self {
+            self.checkbox_size = Some(checkbox_size.into().0);
+            self
+        }
+
+        /// Sets the space around the checkbox of every option of the [`Menu`].
+        pub fn checkbox_spacing(mut self, checkbox_spacing: impl Into<Pixels>) -> Self {
+            self.checkbox_spacing = checkbox_spacing.into().0;
+            self
+        }
+
+        /// Sets the closure returning the [`Font`] of the label of every option, if it differs
+        /// from the font of the [`Menu`].
+        ///
+        /// [`Font`]: text::Renderer::Font
+        pub fn option_font(mut self, option_font: &'a dyn Fn(&T) -> Option<Renderer::Font>) -> Self {
+            self.option_font = Some(option_font);
+            self
+        }
+
+        /// Sets whether typing hovers the next option starting with the typed text.
+        ///
+        /// It is enabled by default. Owners handling the typed text themselves, like a search
+        /// field, should disable it.
+        pub fn type_ahead(mut self, type_ahead: bool) -> Self {
+            self.type_ahead = type_ahead;
+            self
+        }
+
+        /// Sets the indices of the selected options, which toggling an option edits in place instead
+        /// of producing a message.
+        ///
+        /// The owner is expected to publish the whole selection once the [`Menu`] closes. It only
+        /// applies to [`Mode::Multiple`].
+        pub fn batch(mut self, batch: &'a mut Vec<usize>) -> Self {
+            self.batch = Some(batch);
+            self
+        }
+
+        /// Sets the key of every option, so the state of an option follows it when the options change.
+        ///
+        /// By default, the state of an option stays at its index.
+        pub fn keys(mut self, keys: &'a [u64]) -> Self {
+            self.keys = Some(keys);
+            self
+        }
+
+        /// Sets the [`scrollable::Scrollbar`] of the options, with its width, margin and anchor.
+        pub fn scrollbar(mut self, scrollbar: scrollable::Scrollbar) -> Self {
+            self.scrollbar = Some(scrollbar);
+            self
+        }
+
+        /// Sets the class of the [`Scrollable`] holding the options.
+        ///
+        /// By default, it is [`Catalog::default_scrollable`].
+        pub fn scrollable_class(mut self, class: impl Into<<Theme as scrollable::Catalog>::Class<'a>>) -> Self {
+            self.scrollable_class = Some(class.into());
+            self
+        }
+
+        /// Sets the text shown in place of the options while there are none.
+        pub fn empty_text(mut self, empty_text: &'a str) -> Self {
+            self.empty_text = Some(empty_text);
+            self
+        }
+
+        /// Sets whether more options are being loaded, showing a spinner below the options.
+        pub fn loading(mut self, is_loading: bool) -> Self {
+            self.is_loading = is_loading;
+            self
+        }
+
+        /// Sets the element pinned below the options of the [`Menu`].
+        ///
+        /// It is laid out at the width of the [`Menu`] and does not scroll with the options.
+        pub fn footer(mut self, footer: &'a mut Element<'b, Message, Theme, Renderer>) -> Self {
+            self.footer = Some(footer);
+            self
+        }
+
+        /// Sets the title of a header bar pinned above the options of the [`Menu`].
+        ///
+        /// The header has a button dismissing the [`Menu`], see [`State::take_dismissed`].
+        pub fn header(mut self, title: &'a str) -> Self {
+            self.header = Some(title);
+            self
+        }
+
+        /// Sets whether a backdrop is drawn behind the [`Menu`].
+        ///
+        /// Clicking the backdrop dismisses the [`Menu`], see [`State::take_dismissed`].
+        pub fn backdrop(mut self, backdrop: bool) -> Self {
+            self.backdrop = backdrop;
+            self
+        }
+
+        /// Sets whether dragging the options past their edges with a finger stretches them,
+        /// springing back once lifted.
+        pub fn overscroll(mut self, overscroll: bool) -> Self {
+            self.overscroll = overscroll;
+            self
+        }
+
+        /// Sets the space kept between the [`Menu`] and the edges of the viewport.
+        pub fn margin(mut self, margin: impl Into<Pixels>) -> Self {
+            self.margin = margin.into().0;
+            self
+        }
+
+        /// Sets the z-index of the [`Menu`] among the other overlays of the window, which are
+        /// drawn below it when their index is lower.
+        ///
+        /// By default, it is [`DEFAULT_Z_INDEX`].
+        pub fn z_index(mut self, z_index: f32) -> Self {
+            self.z_index = z_index;
+            self
+        }
+
+        /// Sets how much of the [`Menu`] is shown, from `0.0` to `1.0`, to animate it unfolding
+        /// from its target.
+        pub fn reveal(mut self, reveal: f32) -> Self {
+            self.reveal = reveal.clamp(0.0, 1.0);
+            self
+        }
+
+        /// Turns the [`Menu`] into an overlay [`Element`] at the given target
+        /// position.
+        ///
+        /// The `target_height` will be used to display the menu either on top
+        /// of the target or under it, depending on the screen position and the
+        /// dimensions of the [`Menu`], like an [`AnchoredOverlay`].
+        ///
+        /// [`AnchoredOverlay`]: crate::AnchoredOverlay
+        pub fn overlay(
+            self,
+            position: Point,
+            viewport: Rectangle,
+            target_height: f32,
+            menu_height: Length,
+        ) -> overlay::Element<'a, Message, Theme, Renderer> {
+            overlay::Element::new(Box::new(Overlay::new(
+                position,
+                viewport,
+                self,
+                target_height,
+                menu_height,
+            )))
+        }
+    }
+
+    /// A row of a [`Menu`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Entry {
+        /// The option with the given index.
+        Option(usize),
+        /// A divider between options, which cannot be hovered or selected.
+        Separator,
+    }
+
+    impl Entry {
+        /// Returns the index of the option of the [`Entry`], if any.
+        pub fn option(self) -> Option<usize> {
+            match self {
+                Entry::Option(index) => Some(index),
+                Entry::Separator => None,
+            }
+        }
+    }
+
+    /// The mapping between the indices of options before and after they changed, found from
+    /// their keys.
+    #[derive(Debug)]
+    pub(crate) struct Diff {
+        /// The previous index of every option, if it was there.
+        sources: Vec<Option<usize>>,
+        /// The new index of every previous option, if it is still there.
+        targets: Vec<Option<usize>>,
+    }
+
+    impl Diff {
+        /// Matches the previous keys of the options with the new ones.
+        ///
+        /// Repeated keys are matched in order.
+        pub(crate) fn new(previous: &[u64], keys: &[u64]) -> Self {
+            let mut indices: HashMap<u64, VecDeque<usize>> = HashMap::new();
+
+            for (index, key) in previous.iter().enumerate() {
+                indices.entry(*key).or_default().push_back(index);
+            }
+
+            let sources: Vec<_> = keys
+                .iter()
+                .map(|key| indices.get_mut(key).and_then(VecDeque::pop_front))
+                .collect();
+
+            let mut targets = vec![None; previous.len()];
+
+            for (index, source) in sources.iter().enumerate() {
+                if let Some(source) = source {
+                    targets[*source] = Some(index);
+                }
+            }
+
+            Self { sources, targets }
+        }
+
+        /// Returns the new index of the option previously at the given index, if it is still there.
+        pub(crate) fn index(&self, previous: usize) -> Option<usize> {
+            self.targets.get(previous).copied().flatten()
+        }
+
+        /// Moves the items of the previous options to the new index of their option, dropping
+        /// the items of removed options and adding default ones for new options.
+        pub(crate) fn reorder<I: Default>(&self, items: &mut Vec<I>) {
+            let mut previous: Vec<Option<I>> = std::mem::take(items).into_iter().map(Some).collect();
+
+            *items = self
+                .sources
+                .iter()
+                .map(|source| {
+                    source
+                        .and_then(|source| previous.get_mut(source)?.take())
+                        .unwrap_or_default()
+                })
+                .collect();
+        }
+    }
+
+    /// The local state of a [`Menu`].
+    #[derive(Debug)]
+    pub struct State {
+        tree: Tree,
+        footer: Tree,
+        scrollable: widget::Id,
+        pending_scroll: Cell<Option<f32>>,
+        dismissed: Cell<bool>,
+        placement: Cell<Option<Placement>>,
+        pan: Cell<f32>,
+        modifiers: Cell<keyboard::Modifiers>,
+        drag: Cell<Drag>,
+        clipped: Cell<(bool, bool)>,
+    }
+
+    pub use crate::anchored_overlay::Placement;
+    pub use crate::anchored_overlay::Priority;
+
+    /// A finger dragging the options of a [`Menu`], panning them sideways or stretching them
+    /// past their edges.
+    #[derive(Debug, Clone, Copy)]
+    struct Drag {
+        /// The finger and its last position, while it is down.
+        finger: Option<(touch::Finger, Point)>,
+        /// How far the options are pulled past their edges.
+        stretch: Vector,
+        /// The stretch springing back after the finger was lifted, and when it started.
+        spring: Option<(Vector, Option<Instant>)>,
+    }
+
+    impl State {
+        /// Creates a new [`State`] for a [`Menu`].
+        pub fn new() -> Self {
+            Self {
+                tree: Tree::empty(),
+                footer: Tree::empty(),
+                scrollable: widget::Id::unique(),
+                pending_scroll: Cell::new(None),
+                dismissed: Cell::new(false),
+                placement: Cell::new(None),
+                pan: Cell::new(0.0),
+                modifiers: Cell::new(keyboard::Modifiers::default()),
+                drag: Cell::new(Drag {
+                    finger: None,
+                    stretch: Vector::ZERO,
+                    spring: None,
+                }),
+                clipped: Cell::new((false, false)),
+            }
+        }
+
+        /// Returns the [`Placement`] of the [`Menu`] if it was laid out since the last call.
+        pub fn take_placement(&self) -> Option<Placement> {
+            self.placement.take()
+        }
+
+        /// Returns whether the backdrop or the close button of the [`Menu`] was clicked, or an
+        /// option was picked in [`Mode::Single`], since the last call.
+        ///
+        /// The owner of the [`Menu`] is expected to close it when this is `true`.
+        pub fn take_dismissed(&self) -> bool {
+            self.dismissed.take()
+        }
+    }
+
+    impl Default for State {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    struct Overlay<'a, 'b, Message, Theme, Renderer>
+    where
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        position: Point,
+        viewport: Rectangle,
+        window: Size,
+        tree: &'a mut Tree,
+        scrollable: widget::Id,
+        pending_scroll: &'a Cell<Option<f32>>,
+        dismissed: Option<&'a Cell<bool>>,
+        placement: &'a Cell<Option<Placement>>,
+        pan: &'a Cell<f32>,
+        overflow: f32,
+        modifiers: &'a Cell<keyboard::Modifiers>,
+        drag: &'a Cell<Drag>,
+        overscroll: bool,
+        clipped: &'a Cell<(bool, bool)>,
+        list: Scrollable<'a, Message, Theme, Renderer>,
+        footer: Option<(&'a mut Element<'b, Message, Theme, Renderer>, &'a mut Tree)>,
+        header: Option<(&'a str, &'a Cell<bool>)>,
+        width: f32,
+        target_height: f32,
+        margin: f32,
+        z_index: f32,
+        reveal: f32,
+        text_size: Option<Pixels>,
+        text_line_height: text::LineHeight,
+        font: Option<Renderer::Font>,
+        padding: Padding,
+        class: &'a <Theme as Catalog>::Class<'b>,
+    }
+
+    impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
+    where
+        Message: 'a,
+        Theme: Catalog + scrollable::Catalog + 'a,
+        Renderer: text::Renderer + 'a,
+        'b: 'a,
+    {
+        pub fn new<T>(
+            position: Point,
+            viewport: Rectangle,
+            menu: Menu<'a, 'b, T, Message, Theme, Renderer>,
+            target_height: f32,
+            menu_height: Length,
+        ) -> Self
+        where
+            T: Clone + PartialEq,
+        {
+            let Menu {
                 state,
                 options,
+                entries,
                 selected,
                 hovered_option,
                 on_selected,
                 on_option_hovered,
+                display,
+                mode,
+                is_same,
+                badge,
+                option_style,
+                option_disabled,
+                tooltip,
+                tooltip_delay,
+                hover_delay,
                 width,
                 padding,
                 font,
                 text_size,
                 text_line_height,
                 text_shaping,
+                text_wrapping,
+                text_ellipsis,
+                text_align_x,
+                text_align_y,
+                reveal,
+                backdrop,
+                overscroll,
+                margin,
+                z_index,
+                columns,
+                selection_indicator,
+                hierarchy,
+                indentation,
+                indent_width,
+                select_children,
+                read_only,
+                footer,
+                header,
+                empty_text,
+                is_loading,
+                option_height,
+                checkbox_size,
+                checkbox_spacing,
+                option_font,
+                type_ahead,
+                batch,
+                keys,
+                scrollbar,
+                scrollable_class,
                 class,
             } = menu;
 
+            let State {
+                tree,
+                footer: footer_tree,
+                scrollable,
+                pending_scroll,
+                dismissed,
+                placement,
+                pan,
+                modifiers,
+                drag,
+                clipped,
+            } = state;
+            let pending_scroll: &'a Cell<Option<f32>> = pending_scroll;
+            let dismissed: &'a Cell<bool> = dismissed;
+            let clipped: &'a Cell<(bool, bool)> = clipped;
+
             let list = Scrollable::new(List {
                 options,
+                entries,
                 selected,
                 hovered_option,
+                pending_scroll,
                 on_selected,
                 on_option_hovered,
+                display,
+                mode,
+                dismissed,
+                clipped,
+                is_same,
+                badge,
+                option_style,
+                option_disabled,
+                tooltip,
+                tooltip_delay,
+                hover_delay,
+                // However high the menu is raised, its tooltips stay above it.
+                tooltip_z_index: Priority::Tooltip.index().max(z_index + 1.0),
                 font,
                 text_size,
                 text_line_height,
                 text_shaping,
+                text_wrapping,
+                text_ellipsis,
+                text_align_x,
+                text_align_y,
+                columns,
+                selection_indicator,
+                hierarchy,
+                indentation,
+                indent_width,
+                select_children,
+                read_only,
+                empty_text,
+                is_loading,
+                option_height,
+                checkbox_size,
+                checkbox_spacing,
+                option_font,
+                type_ahead,
+                batch,
+                keys,
                 padding,
                 class,
                 icon: Icon {
@@ -894,142 +3022,1236 @@ pub mod menu {
                     shaping: text::Shaping::Basic,
                 },
             })
-            .height(menu_height);
+            .id(scrollable.clone())
+            .height(menu_height)
+            .class(scrollable_class.unwrap_or_else(<Theme as Catalog>::default_scrollable));
+
+            let list = match scrollbar {
+                Some(scrollbar) => list.direction(scrollable::Direction::Vertical(scrollbar)),
+                None => list,
+            };
+
+            tree.diff(&list as &dyn Widget<_, _, _>);
 
-            state.tree.diff(&list as &dyn Widget<_, _, _>);
+            let footer = footer.map(|footer| {
+                footer_tree.diff(footer.as_widget());
+
+                (footer, footer_tree)
+            });
 
             Self {
                 position,
                 viewport,
-                tree: &mut state.tree,
+                window: Size::ZERO,
+                tree,
+                scrollable: scrollable.clone(),
+                pending_scroll,
+                dismissed: backdrop.then_some(dismissed),
+                placement,
+                pan,
+                overflow: 0.0,
+                modifiers,
+                drag,
+                overscroll,
+                clipped,
                 list,
+                footer,
+                header: header.map(|title| (title, dismissed)),
                 width,
                 target_height,
+                margin,
+                z_index,
+                reveal,
+                text_size,
+                text_line_height,
+                font,
+                padding,
                 class,
             }
         }
     }
 
-    impl<Message, Theme, Renderer> iced_core::Overlay<Message, Theme, Renderer>
-        for Overlay<'_, '_, Message, Theme, Renderer>
+    impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
     where
         Theme: Catalog,
         Renderer: text::Renderer,
     {
-        fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
-            let space_below = bounds.height - (self.position.y + self.target_height);
-            let space_above = self.position.y;
+        /// Pans the options sideways with the wheel or a finger, and stretches them past their
+        /// edges while overscrolling, returning whether the event was captured.
+        fn pan_or_stretch(
+            &self,
+            event: &Event,
+            bounds: Rectangle,
+            cursor: mouse::Cursor,
+            shell: &mut Shell<'_, Message>,
+        ) -> bool {
+            let mut drag = self.drag.get();
 
-            let limits = layout::Limits::new(
-                Size::ZERO,
-                Size::new(
-                    bounds.width - self.position.x,
-                    if space_below > space_above {
-                        space_below
-                    } else {
-                        space_above
-                    },
-                ),
-            )
-            .width(self.width);
+            match event {
+                Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                    self.modifiers.set(*modifiers);
+                }
+                Event::Mouse(mouse::Event::WheelScrolled { delta })
+                    if self.overflow > 0.0 && cursor.is_over(bounds) =>
+                {
+                    let (x, y) = match *delta {
+                        mouse::ScrollDelta::Lines { x, y } => (x * WHEEL_LINE, y * WHEEL_LINE),
+                        mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                    };
 
-            let node = self.list.layout(self.tree, renderer, &limits);
-            let size = node.size();
+                    // Holding shift turns the wheel sideways, for mice without a horizontal wheel.
+                    let is_sideways = self.modifiers.get().shift();
 
-            node.move_to(if space_below > space_above {
-                self.position + Vector::new(0.0, self.target_height)
-            } else {
-                self.position - Vector::new(0.0, size.height)
-            })
-        }
+                    self.pan_by(-if is_sideways { x + y } else { x }, shell);
 
-        fn update(
-            &mut self,
-            event: &Event,
-            layout: Layout<'_>,
-            cursor: mouse::Cursor,
-            renderer: &Renderer,
-            clipboard: &mut dyn Clipboard,
-            shell: &mut Shell<'_, Message>,
-        ) {
-            let bounds = layout.bounds();
+                    if is_sideways {
+                        shell.capture_event();
+                        return true;
+                    }
+                }
+                Event::Touch(touch::Event::FingerPressed { id, position }) if cursor.is_over(bounds) => {
+                    drag.finger = Some((*id, *position));
+                    drag.spring = None;
+                }
+                Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                    let Some((finger, last)) = drag.finger.filter(|(finger, _)| finger == id) else {
+                        return false;
+                    };
 
-            self.list
-                .update(self.tree, event, layout, cursor, renderer, clipboard, shell, &bounds);
-        }
+                    let delta = *position - last;
+                    drag.finger = Some((finger, *position));
 
-        fn mouse_interaction(
-            &self,
-            layout: Layout<'_>,
-            cursor: mouse::Cursor,
-            renderer: &Renderer,
-        ) -> mouse::Interaction {
-            self.list
-                .mouse_interaction(self.tree, layout, cursor, &self.viewport, renderer)
-        }
+                    if self.overflow > 0.0 {
+                        if drag.stretch.x == 0.0 {
+                            let pan = self.pan.get() - delta.x;
+                            let excess = pan.clamp(0.0, self.overflow) - pan;
 
-        fn draw(
-            &self,
-            renderer: &mut Renderer,
-            theme: &Theme,
-            defaults: &renderer::Style,
-            layout: Layout<'_>,
-            cursor: mouse::Cursor,
-        ) {
-            let bounds = layout.bounds();
+                            self.pan_by(-delta.x, shell);
 
-            let style = Catalog::style(theme, self.class);
+                            if self.overscroll {
+                                drag.stretch.x = pull(0.0, excess);
+                            }
+                        } else {
+                            drag.stretch.x = pull(drag.stretch.x, delta.x);
+                        }
+                    }
 
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds,
-                    border: style.border,
-                    shadow: style.shadow,
-                    ..renderer::Quad::default()
-                },
-                style.background,
-            );
+                    let (is_clipped_above, is_clipped_below) = self.clipped.get();
+                    let is_past_edge = (delta.y > 0.0 && !is_clipped_above) || (delta.y < 0.0 && !is_clipped_below);
+
+                    if self.overscroll && (is_past_edge || drag.stretch.y != 0.0) {
+                        drag.stretch.y = pull(drag.stretch.y, delta.y);
+                    }
+
+                    if drag.stretch != self.drag.get().stretch {
+                        shell.request_redraw();
+                    }
+                }
+                Event::Touch(touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. })
+                    if drag.finger.is_some_and(|(finger, _)| finger == *id) =>
+                {
+                    drag.finger = None;
+
+                    if drag.stretch != Vector::ZERO {
+                        drag.spring = Some((drag.stretch, None));
+                        shell.request_redraw();
+                    }
+                }
+                Event::Window(window::Event::RedrawRequested(now)) => {
+                    if let Some((from, started)) = drag.spring {
+                        // The spring starts with the first frame after the finger was lifted.
+                        let started = started.unwrap_or(*now);
+                        let progress =
+                            now.saturating_duration_since(started).as_secs_f32() / OVERSCROLL_DURATION.as_secs_f32();
+
+                        // Eases out cubically, so the options slow down as they settle.
+                        drag.stretch = from * (1.0 - progress.min(1.0)).powi(3);
+
+                        if progress < 1.0 {
+                            drag.spring = Some((from, Some(started)));
+                            shell.request_redraw();
+                        } else {
+                            drag.spring = None;
+                            drag.stretch = Vector::ZERO;
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            self.drag.set(drag);
+
+            false
+        }
+
+        /// Pans the options sideways by the given distance, within their overflow.
+        fn pan_by(&self, distance: f32, shell: &mut Shell<'_, Message>) {
+            let pan = (self.pan.get() + distance).clamp(0.0, self.overflow);
+
+            if pan != self.pan.get() {
+                self.pan.set(pan);
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+        }
+
+        /// Returns the height of the header of the [`Menu`], or zero without one.
+        fn header_height(&self, renderer: &Renderer) -> f32 {
+            if self.header.is_none() {
+                return 0.0;
+            }
+
+            let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+            f32::from(self.text_line_height.to_absolute(text_size)) + self.padding.y()
+        }
+
+        /// Draws the header of the [`Menu`], with its title and its close button.
+        fn draw_header(
+            &self,
+            renderer: &mut Renderer,
+            style: &Style,
+            title: &str,
+            bounds: Rectangle,
+            close_bounds: Rectangle,
+            cursor: mouse::Cursor,
+        ) {
+            let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+            let font = self.font.unwrap_or_else(|| renderer.default_font());
+            let title_bounds = Rectangle {
+                width: (close_bounds.x - bounds.x - self.padding.left).max(0.0),
+                height: close_bounds.height,
+                ..bounds
+            };
+
+            renderer.fill_text(
+                Text {
+                    content: title.to_owned(),
+                    bounds: title_bounds.size(),
+                    size: text_size,
+                    line_height: self.text_line_height,
+                    font,
+                    align_x: text::Alignment::Default,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                Point::new(bounds.x + self.padding.left, title_bounds.center_y()),
+                style.text_color,
+                title_bounds,
+            );
+
+            if cursor.is_over(close_bounds) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: close_bounds.shrink(4.0),
+                        border: border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.selected_background,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: String::from("\u{00D7}"),
+                    bounds: close_bounds.size(),
+                    size: text_size,
+                    line_height: self.text_line_height,
+                    font,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                close_bounds.center(),
+                style.text_color,
+                close_bounds,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + close_bounds.height - 1.0,
+                        height: 1.0,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.border.color,
+            );
+        }
+
+        /// Returns the bounds of the close button in the header of a [`Menu`] with the given bounds.
+        fn close_bounds(&self, bounds: Rectangle, renderer: &Renderer) -> Option<Rectangle> {
+            let height = self.header_height(renderer);
+
+            self.header.map(|_| Rectangle {
+                x: bounds.x + bounds.width - height,
+                y: bounds.y,
+                width: height,
+                height,
+            })
+        }
+    }
+
+    impl<Message, Theme, Renderer> iced_core::Overlay<Message, Theme, Renderer>
+        for Overlay<'_, '_, Message, Theme, Renderer>
+    where
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+            let target = Rectangle::new(self.position, Size::new(self.width, self.target_height));
+
+            self.window = bounds;
+
+            // The menu keeps its margin from the edges of the viewport, scrolling its options if needed.
+            let (placement, space) = anchored_overlay::space(target, bounds, self.margin);
+            let (max_width, max_height) = (space.width, space.height);
+
+            let footer = self.footer.as_mut().map(|(footer, tree)| {
+                let limits = layout::Limits::new(Size::ZERO, Size::new(max_width, max_height)).width(self.width);
+
+                footer.as_widget_mut().layout(tree, renderer, &limits)
+            });
+            let footer_height = footer.as_ref().map_or(0.0, |footer| footer.size().height);
+            let header_height = self.header_height(renderer);
+
+            // Options wider than the viewport keep their width, and are panned sideways inside it.
+            let limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new(
+                    self.width.max(max_width),
+                    (max_height - footer_height - header_height).max(0.0),
+                ),
+            )
+            .width(self.width);
+
+            let list = self.list.layout(self.tree, renderer, &limits);
+            let list_size = list.size();
+            let width = list_size.width.min(max_width);
+
+            self.overflow = list_size.width - width;
+            self.pan.set(self.pan.get().clamp(0.0, self.overflow));
+
+            let size = Size::new(
+                footer.as_ref().map_or(width, |footer| width.max(footer.size().width)),
+                header_height + list_size.height + footer_height,
+            );
+
+            // The footer is the second child, pinned below the scrollable options. The header has
+            // no node of its own and sits above them.
+            let children = std::iter::once(list.move_to((-self.pan.get(), header_height)))
+                .chain(footer.map(|footer| footer.move_to((0.0, header_height + list_size.height))))
+                .collect();
+
+            self.placement.set(Some(placement));
+
+            layout::Node::with_children(size, children)
+                .move_to(anchored_overlay::position(target, size, placement, bounds))
+        }
+
+        fn update(
+            &mut self,
+            event: &Event,
+            layout: Layout<'_>,
+            cursor: mouse::Cursor,
+            renderer: &Renderer,
+            clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, Message>,
+        ) {
+            let bounds = layout.bounds();
+
+            let is_press = matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(_)) | Event::Touch(touch::Event::FingerPressed { .. })
+            );
+
+            if let Some(dismissed) = self.dismissed
+                && is_press
+                && !cursor.is_over(bounds)
+            {
+                // The backdrop swallows the click, so the owner of the menu closes it on its next update.
+                dismissed.set(true);
+                shell.capture_event();
+                shell.request_redraw();
+                return;
+            }
+
+            if let Some((_, dismissed)) = self.header
+                && is_press
+                && let Some(close_bounds) = self.close_bounds(bounds, renderer)
+                && cursor.is_over(close_bounds)
+            {
+                dismissed.set(true);
+                shell.capture_event();
+                shell.request_redraw();
+                return;
+            }
+
+            let mut children = layout.children();
+            let Some(list_layout) = children.next() else {
+                return;
+            };
+            let list_bounds = list_layout.bounds();
+
+            if self.pan_or_stretch(event, bounds, cursor, shell) {
+                return;
+            }
 
             self.list
-                .draw(self.tree, renderer, theme, defaults, layout, cursor, &bounds);
+                .update(self.tree, event, list_layout, cursor, renderer, clipboard, shell, &list_bounds);
+
+            if let Some((footer, tree)) = &mut self.footer
+                && let Some(footer_layout) = children.next()
+            {
+                footer
+                    .as_widget_mut()
+                    .update(tree, event, footer_layout, cursor, renderer, clipboard, shell, &bounds);
+            }
+
+            // The list leaves touches uncaptured so they can scroll it, but they must not
+            // reach the owner of the menu, which would close it.
+            if matches!(event, Event::Touch(touch::Event::FingerPressed { .. })) && cursor.is_over(bounds) {
+                shell.capture_event();
+            }
+
+            // The list cannot scroll its parent by itself, so it leaves the required
+            // offset behind whenever a keyboard action moves the hovered option.
+            if let Some(delta) = self.pending_scroll.take() {
+                let mut operation = scroll_by::<()>(self.scrollable.clone(), AbsoluteOffset { x: 0.0, y: delta });
+
+                self.list
+                    .operate(self.tree, list_layout, renderer, &mut operation as &mut dyn Operation);
+
+                shell.request_redraw();
+            }
+        }
+
+        fn mouse_interaction(
+            &self,
+            layout: Layout<'_>,
+            cursor: mouse::Cursor,
+            renderer: &Renderer,
+        ) -> mouse::Interaction {
+            let mut children = layout.children();
+
+            let list = children.next().map(|list_layout| {
+                self.list
+                    .mouse_interaction(self.tree, list_layout, cursor, &self.viewport, renderer)
+            });
+
+            let footer = self
+                .footer
+                .as_ref()
+                .zip(children.next())
+                .map(|((footer, tree), footer_layout)| {
+                    footer
+                        .as_widget()
+                        .mouse_interaction(tree, footer_layout, cursor, &self.viewport, renderer)
+                });
+
+            let close = self
+                .close_bounds(layout.bounds(), renderer)
+                .filter(|close_bounds| cursor.is_over(*close_bounds))
+                .map(|_| mouse::Interaction::Pointer);
+
+            list.into_iter().chain(footer).chain(close).max().unwrap_or_default()
+        }
+
+        fn draw(
+            &self,
+            renderer: &mut Renderer,
+            theme: &Theme,
+            defaults: &renderer::Style,
+            layout: Layout<'_>,
+            cursor: mouse::Cursor,
+        ) {
+            let bounds = layout.bounds();
+
+            let style = Catalog::style(theme, self.class, Status::Active);
+
+            // The backdrop covers the whole window, even when the owner is clipped by a scrollable.
+            if self.dismissed.is_some() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::with_size(self.window),
+                        ..renderer::Quad::default()
+                    },
+                    style.backdrop,
+                );
+            }
+
+            let draw = |renderer: &mut Renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        shadow: style.shadow,
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+
+                if let Some((title, _)) = self.header
+                    && let Some(close_bounds) = self.close_bounds(bounds, renderer)
+                {
+                    self.draw_header(renderer, &style, title, bounds, close_bounds, cursor);
+                }
+
+                let mut children = layout.children();
+
+                if let Some(list_layout) = children.next() {
+                    let stretch = self.drag.get().stretch;
+
+                    let draw_list = |renderer: &mut Renderer| {
+                        renderer.with_translation(stretch, |renderer| {
+                            self.list
+                                .draw(self.tree, renderer, theme, defaults, list_layout, cursor, &list_layout.bounds());
+                        });
+                    };
+
+                    // The options are clipped to the menu while they are panned or stretched.
+                    if self.overflow > 0.0 || stretch != Vector::ZERO {
+                        let list_bounds = Rectangle {
+                            x: bounds.x,
+                            width: bounds.width,
+                            ..list_layout.bounds()
+                        };
+
+                        renderer.with_layer(list_bounds, draw_list);
+                    } else {
+                        draw_list(renderer);
+                    }
+                }
+
+                if let Some((footer, tree)) = &self.footer
+                    && let Some(footer_layout) = children.next()
+                {
+                    let footer_bounds = footer_layout.bounds();
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                height: 1.0,
+                                ..footer_bounds
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.border.color,
+                    );
+
+                    footer
+                        .as_widget()
+                        .draw(tree, renderer, theme, defaults, footer_layout, cursor, &bounds);
+                }
+            };
+
+            if self.reveal < 1.0 {
+                let height = bounds.height * self.reveal;
+
+                // The menu unfolds away from its target, which is below it when opened upwards.
+                let y = if bounds.y < self.position.y {
+                    bounds.y + bounds.height - height
+                } else {
+                    bounds.y
+                };
+
+                renderer.with_layer(Rectangle { y, height, ..bounds }, draw);
+            } else {
+                draw(renderer);
+            }
+        }
+
+        fn overlay<'c>(
+            &'c mut self,
+            layout: Layout<'c>,
+            renderer: &Renderer,
+        ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+            let mut children = layout.children();
+
+            // The tooltip of the hovered option is an overlay of the list, drawn after the others.
+            let list = children.next().and_then(|list_layout| {
+                self.list
+                    .overlay(self.tree, list_layout, renderer, &self.viewport, Vector::ZERO)
+            });
+
+            // The overlays of the footer, like the menu of a pick list, open on top of the menu.
+            let footer = self
+                .footer
+                .as_mut()
+                .zip(children.next())
+                .and_then(|((footer, tree), footer_layout)| {
+                    footer
+                        .as_widget_mut()
+                        .overlay(tree, footer_layout, renderer, &self.viewport, Vector::ZERO)
+                });
+
+            let overlays: Vec<_> = footer.into_iter().chain(list).collect();
+
+            (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+        }
+
+        fn index(&self) -> f32 {
+            self.z_index
+        }
+    }
+
+    struct List<'a, 'b, T, Message, Theme, Renderer>
+    where
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        options: &'a [T],
+        entries: Option<&'a [Entry]>,
+        selected: &'a [T],
+        hovered_option: &'a mut Option<usize>,
+        pending_scroll: &'a Cell<Option<f32>>,
+        on_selected: Box<dyn FnMut(T) -> Message + 'a>,
+        on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+        display: &'a dyn Fn(&T) -> String,
+        mode: Mode,
+        dismissed: &'a Cell<bool>,
+        clipped: &'a Cell<(bool, bool)>,
+        is_same: Option<&'a dyn Fn(&T, &T) -> bool>,
+        badge: Option<&'a dyn Fn(&T) -> Option<String>>,
+        option_style: Option<&'a dyn Fn(&T, Status) -> OptionStyle>,
+        option_disabled: Option<&'a dyn Fn(&T) -> bool>,
+        tooltip: Option<&'a dyn Fn(&T) -> Option<String>>,
+        tooltip_delay: Duration,
+        hover_delay: Duration,
+        tooltip_z_index: f32,
+        padding: Padding,
+        text_size: Option<Pixels>,
+        text_line_height: text::LineHeight,
+        text_shaping: text::Shaping,
+        text_wrapping: text::Wrapping,
+        text_ellipsis: bool,
+        text_align_x: text::Alignment,
+        text_align_y: alignment::Vertical,
+        columns: usize,
+        selection_indicator: SelectionIndicator,
+        hierarchy: Option<(&'a [usize], &'a mut [bool])>,
+        indentation: Option<&'a dyn Fn(&T) -> u16>,
+        indent_width: f32,
+        select_children: bool,
+        read_only: bool,
+        empty_text: Option<&'a str>,
+        is_loading: bool,
+        option_height: Option<f32>,
+        checkbox_size: Option<f32>,
+        checkbox_spacing: f32,
+        option_font: Option<&'a dyn Fn(&T) -> Option<Renderer::Font>>,
+        type_ahead: bool,
+        batch: Option<&'a mut Vec<usize>>,
+        keys: Option<&'a [u64]>,
+        font: Option<Renderer::Font>,
+        class: &'a <Theme as Catalog>::Class<'b>,
+        icon: Icon<Renderer::Font>,
+    }
+
+    /// The default space between a [`Menu`] and the edges of the viewport.
+    pub const DEFAULT_MARGIN: f32 = anchored_overlay::DEFAULT_MARGIN;
+
+    /// The default z-index of a [`Menu`], the one of [`Priority::Menu`], above the panel of a
+    /// [`FloatingPanel`] it is opened from.
+    ///
+    /// [`FloatingPanel`]: crate::FloatingPanel
+    pub const DEFAULT_Z_INDEX: f32 = Priority::Menu.index();
+
+    /// The default width of a level of indentation of an option.
+    pub const DEFAULT_INDENT_WIDTH: f32 = 16.0;
+
+    /// The default amount of time an option has to be hovered before its tooltip is shown.
+    pub const DEFAULT_TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+
+    /// The space between the tooltip of an option and its content.
+    const TOOLTIP_PADDING: f32 = 5.0;
+
+    /// The offset of the tooltip of an option from the cursor.
+    const TOOLTIP_OFFSET: Vector = Vector::new(12.0, 16.0);
+
+    /// How long the type-ahead buffer is kept between key presses.
+    const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+    /// The default space around the checkbox of an option, from the edges of the option and
+    /// from its label.
+    pub const DEFAULT_CHECKBOX_SPACING: f32 = 5.0;
+
+    /// Returns the size of the checkbox of an option, which is 60% of the height of a line of
+    /// text plus the vertical padding unless it is set.
+    pub(super) fn checkbox_size(checkbox_size: Option<f32>, text_line_height: f32, padding: Padding) -> f32 {
+        checkbox_size.unwrap_or((text_line_height + padding.y()) * 0.6)
+    }
+
+    /// The height of the row of a [`Entry::Separator`].
+    const SEPARATOR_HEIGHT: f32 = 9.0;
+
+    /// The distance a finger can travel over an option before the touch becomes a scroll.
+    const TOUCH_SLOP: f32 = 8.0;
+
+    /// The horizontal padding inside the badge of an option.
+    const BADGE_PADDING: f32 = 6.0;
+
+    /// The horizontal space between the label and the badge of an option.
+    const BADGE_SPACING: f32 = 5.0;
+
+    /// The amount of dots of the spinner shown while loading, which makes a turn every second.
+    const SPINNER_DOTS: usize = 8;
+
+    /// The distance a line of the mouse wheel pans the options sideways, like a [`Scrollable`].
+    const WHEEL_LINE: f32 = 60.0;
+
+    /// The farthest the options can be stretched past their edges.
+    const OVERSCROLL_LIMIT: f32 = 80.0;
+
+    /// How long the options take to spring back once the finger stretching them is lifted.
+    const OVERSCROLL_DURATION: Duration = Duration::from_millis(300);
+
+    struct ListState<P: text::Paragraph> {
+        is_hovered: Option<bool>,
+        type_ahead: String,
+        last_key_press: Option<Instant>,
+        labels: Vec<Label<P>>,
+        badges: Vec<Label<P>>,
+        /// The key of every option the labels were laid out for, if the options have keys.
+        keys: Vec<u64>,
+        /// The text shown while there are no rows.
+        empty: Label<P>,
+        /// When the spinner shown while loading started turning, and the time of the last frame.
+        spinner: Option<(Instant, Instant)>,
+        /// The finger pressing an option, where it started, and the option it pressed.
+        touch_press: Option<(touch::Finger, Point, usize)>,
+        /// The option hovered for the tooltip and when it started being hovered.
+        hovered_since: Option<(usize, Instant)>,
+        /// The content of the tooltip shown, and where the cursor was when it was shown.
+        tooltip: Option<(String, Point)>,
+        /// The tooltip, shaped by its overlay.
+        tooltip_label: Label<P>,
+        /// The option waiting for the hover delay before being reported, and the first frame it
+        /// was hovered in.
+        pending_hover: Option<(usize, Option<Instant>)>,
+        /// The vertical offset of every line, followed by the height of the list.
+        offsets: Vec<f32>,
+        /// The rows shown side by side on every line.
+        lines: Vec<Range<usize>>,
+        /// The bounds of every row, relative to the list.
+        cells: Vec<Rectangle>,
+    }
+
+    impl<P: text::Paragraph> ListState<P> {
+        /// Returns the line at the given vertical position of the list.
+        fn line_at(&self, y: f32) -> Option<usize> {
+            let index = self.offsets.partition_point(|&offset| offset <= y).checked_sub(1)?;
+
+            (index + 1 < self.offsets.len()).then_some(index)
+        }
+
+        /// Returns the row at the given position of the list.
+        fn row_at(&self, position: Point) -> Option<usize> {
+            let mut line = self.lines.get(self.line_at(position.y)?)?.clone();
+
+            line.find(|&row| self.cells.get(row).is_some_and(|cell| cell.contains(position)))
+        }
+
+        /// Returns the vertical offset and the height of the given row.
+        fn row_span(&self, row: usize) -> (f32, f32) {
+            self.cells.get(row).map_or((0.0, 0.0), |cell| (cell.y, cell.height))
+        }
+
+        /// Returns the lines intersecting the given vertical span of the list.
+        fn visible(&self, top: f32, bottom: f32) -> Range<usize> {
+            let count = self.offsets.len().saturating_sub(1);
+            let start = self.offsets.partition_point(|&offset| offset <= top).saturating_sub(1);
+            let end = self.offsets.partition_point(|&offset| offset < bottom).min(count);
+
+            start.min(end)..end
+        }
+
+        /// Returns the total height of the rows.
+        fn height(&self) -> f32 {
+            self.offsets.last().copied().unwrap_or(0.0)
+        }
+    }
+
+    /// The label of an option, shaped to fit the width of the [`Menu`].
+    struct Label<P: text::Paragraph> {
+        content: String,
+        display: String,
+        /// The width, text size, font, and shaping the content was truncated for, and whether it
+        /// could be.
+        shaped_for: Option<(f32, Pixels, P::Font, text::Shaping, bool)>,
+        paragraph: paragraph::Plain<P>,
+    }
+
+    impl<P: text::Paragraph> Default for Label<P> {
+        fn default() -> Self {
+            Self {
+                content: String::new(),
+                display: String::new(),
+                shaped_for: None,
+                paragraph: paragraph::Plain::default(),
+            }
+        }
+    }
+
+    impl<P: text::Paragraph> Label<P> {
+        /// Returns the width of the [`Label`] when drawn as a badge, or zero if it is empty.
+        fn badge_width(&self) -> f32 {
+            if self.content.is_empty() {
+                0.0
+            } else {
+                self.paragraph.min_bounds().width + BADGE_PADDING * 2.0
+            }
+        }
+
+        /// Shapes the given content, truncating it with an ellipsis if it does not fit
+        /// the bounds of the text and `ellipsis` is enabled.
+        fn update(&mut self, content: String, text: Text<&str, P::Font>, ellipsis: bool) {
+            let ellipsis = ellipsis && text.wrapping == text::Wrapping::None;
+
+            // Anything changing the width of the text, like the font of an option, truncates it again.
+            let shaped_for = Some((text.bounds.width, text.size, text.font, text.shaping, ellipsis));
+
+            if self.content != content || self.shaped_for != shaped_for {
+                self.display = if ellipsis {
+                    ellipsize::<P>(&content, text)
+                } else {
+                    content.clone()
+                };
+                self.content = content;
+                self.shaped_for = shaped_for;
+            }
+
+            let _ = self.paragraph.update(Text {
+                content: &self.display,
+                ..text
+            });
+        }
+    }
+
+    /// Pulls the options by the given distance past their edge, resisting more the farther they
+    /// are stretched, or lets them back towards it.
+    fn pull(stretch: f32, distance: f32) -> f32 {
+        if stretch * distance < 0.0 {
+            let released = stretch + distance;
+
+            return if released * stretch > 0.0 { released } else { 0.0 };
+        }
+
+        let resistance = 0.5 * (1.0 - stretch.abs() / OVERSCROLL_LIMIT).max(0.0);
+
+        (stretch + distance * resistance).clamp(-OVERSCROLL_LIMIT, OVERSCROLL_LIMIT)
+    }
+
+    /// Truncates the content with an ellipsis until it fits the width of the text bounds.
+    pub(super) fn ellipsize<P: text::Paragraph>(content: &str, text: Text<&str, P::Font>) -> String {
+        let fits = |candidate: &str| {
+            P::with_text(Text {
+                content: candidate,
+                bounds: Size::new(f32::INFINITY, text.bounds.height),
+                ..text
+            })
+            .min_width()
+                <= text.bounds.width
+        };
+
+        if fits(content) {
+            return content.to_owned();
+        }
+
+        let boundaries: Vec<usize> = content.char_indices().map(|(i, _)| i).collect();
+        let truncated = |chars: usize| format!("{}…", content[..boundaries[chars]].trim_end());
+
+        // The largest amount of characters that still fits next to the ellipsis.
+        let (mut low, mut high) = (0, boundaries.len() - 1);
+
+        while low < high {
+            let middle = (low + high).div_ceil(2);
+
+            if fits(&truncated(middle)) {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        truncated(low)
+    }
+
+    impl<T, Message, Theme, Renderer> List<'_, '_, T, Message, Theme, Renderer>
+    where
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        /// Finds the next option starting with the typed prefix.
+        ///
+        /// Typing the same character repeatedly cycles through the options starting with it.
+        fn type_ahead<P: text::Paragraph>(&self, state: &mut ListState<P>, text: &str) -> Option<usize> {
+            let now = Instant::now();
+
+            if state
+                .last_key_press
+                .is_none_or(|last_key_press| now.duration_since(last_key_press) > TYPE_AHEAD_TIMEOUT)
+            {
+                state.type_ahead.clear();
+            }
+
+            state.type_ahead.push_str(&text.to_lowercase());
+            state.last_key_press = Some(now);
+
+            let mut chars = state.type_ahead.chars();
+            let first = chars.next()?;
+            let is_repeated = chars.all(|c| c == first);
+
+            let (start, prefix) = match self.hovered_option.and_then(|hovered| self.row_of(hovered)) {
+                Some(row) if is_repeated => (row + 1, &state.type_ahead[..first.len_utf8()]),
+                Some(row) => (row, state.type_ahead.as_str()),
+                None => (0, state.type_ahead.as_str()),
+            };
+
+            let rows = self.rows();
+
+            (0..rows)
+                .filter_map(|i| self.entry((start + i) % rows).option())
+                .filter(|&index| !self.is_disabled(index))
+                .find(|&index| {
+                    self.options
+                        .get(index)
+                        .is_some_and(|option| (self.display)(option).to_lowercase().starts_with(prefix))
+                })
+        }
+    }
+
+    impl<T, Message, Theme, Renderer> List<'_, '_, T, Message, Theme, Renderer>
+    where
+        T: Clone,
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        /// Finds the option a navigation key moves the hovered option to.
+        ///
+        /// Home and End jump to the first and last options, while PageUp and PageDown move by
+        /// the height of the visible part of the list.
+        fn jump<P: text::Paragraph>(
+            &self,
+            state: &ListState<P>,
+            key: keyboard::key::Named,
+            page: f32,
+        ) -> Option<usize> {
+            let rows = self.rows();
+            let current = self.hovered_option.and_then(|hovered| self.row_of(hovered));
+            let options = |range: Range<usize>| {
+                range.filter(move |&row| self.entry(row).option().is_some_and(|index| !self.is_disabled(index)))
+            };
+
+            let row = match key {
+                keyboard::key::Named::Home => options(0..rows).next(),
+                keyboard::key::Named::End => options(0..rows).next_back(),
+                keyboard::key::Named::PageDown => {
+                    let start = current.unwrap_or(0);
+                    let target = state.row_span(start).0 + page;
+
+                    options(start..rows)
+                        .take_while(|&row| state.row_span(row).0 <= target)
+                        .last()
+                        .filter(|&row| Some(row) != current)
+                        .or_else(|| options(start + 1..rows).next())
+                }
+                keyboard::key::Named::PageUp => {
+                    let start = current.unwrap_or(0);
+                    let target = state.row_span(start).0 - page;
+
+                    options(0..start + 1)
+                        .rev()
+                        .take_while(|&row| state.row_span(row).0 >= target)
+                        .last()
+                        .filter(|&row| Some(row) != current)
+                        .or_else(|| options(0..start).next_back())
+                }
+                _ => None,
+            }?;
+
+            self.entry(row).option()
+        }
+
+        /// Calls `on_option_hovered` with the given newly hovered option, or waits for the hover
+        /// delay to pass first.
+        fn report_hover<P: text::Paragraph>(
+            &self,
+            state: &mut ListState<P>,
+            index: usize,
+            shell: &mut Shell<'_, Message>,
+        ) {
+            let Some(on_option_hovered) = self.on_option_hovered else {
+                return;
+            };
+
+            if self.hover_delay.is_zero() {
+                shell.publish(on_option_hovered(self.options[index].clone()));
+            } else {
+                // The delay starts with the next frame, since events carry no timestamp.
+                state.pending_hover = Some((index, None));
+                shell.request_redraw();
+            }
+        }
+
+        /// Calls `on_option_hovered` once the option waiting for it has been hovered for the
+        /// hover delay.
+        fn update_pending_hover<P: text::Paragraph>(
+            &self,
+            state: &mut ListState<P>,
+            now: Instant,
+            shell: &mut Shell<'_, Message>,
+        ) {
+            let Some((index, since)) = state.pending_hover else {
+                return;
+            };
+
+            if *self.hovered_option != Some(index) {
+                state.pending_hover = None;
+                return;
+            }
+
+            let deadline = since.unwrap_or(now) + self.hover_delay;
+
+            if now >= deadline {
+                state.pending_hover = None;
+
+                if let Some(on_option_hovered) = self.on_option_hovered
+                    && let Some(option) = self.options.get(index)
+                {
+                    shell.publish(on_option_hovered(option.clone()));
+                }
+            } else {
+                state.pending_hover = Some((index, Some(since.unwrap_or(now))));
+                shell.request_redraw_at(window::RedrawRequest::At(deadline));
+            }
+        }
+
+        /// Hovers the given option after a keyboard action, scrolling it into view.
+        fn hover<P: text::Paragraph>(
+            &mut self,
+            state: &mut ListState<P>,
+            index: usize,
+            bounds: Rectangle,
+            viewport: &Rectangle,
+            shell: &mut Shell<'_, Message>,
+        ) {
+            if *self.hovered_option != Some(index) {
+                self.report_hover(state, index, shell);
+
+                *self.hovered_option = Some(index);
+                shell.request_redraw();
+            }
+
+            let (top, option_height) = self.row_of(index).map_or((0.0, 0.0), |row| state.row_span(row));
+            let offset = viewport.y - bounds.y;
+
+            if top < offset {
+                self.pending_scroll.set(Some(top - offset));
+            } else if top + option_height > offset + viewport.height {
+                self.pending_scroll
+                    .set(Some(top + option_height - offset - viewport.height));
+            }
+        }
+    }
+
+    impl<T, Message, Theme, Renderer> List<'_, '_, T, Message, Theme, Renderer>
+    where
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        /// Returns the amount of rows of the list.
+        fn rows(&self) -> usize {
+            self.entries.map_or(self.options.len(), <[Entry]>::len)
+        }
+
+        /// Returns the [`Entry`] shown in the given row.
+        fn entry(&self, row: usize) -> Entry {
+            self.entries.map_or(Entry::Option(row), |entries| entries[row])
+        }
+
+        /// Returns whether the given option is disabled, and cannot be hovered or selected.
+        fn is_disabled(&self, index: usize) -> bool {
+            self.option_disabled
+                .zip(self.options.get(index))
+                .is_some_and(|(option_disabled, option)| option_disabled(option))
+        }
+
+        /// Returns the row showing the option with the given index.
+        fn row_of(&self, index: usize) -> Option<usize> {
+            match self.entries {
+                Some(entries) => entries.iter().position(|entry| *entry == Entry::Option(index)),
+                None => (index < self.options.len()).then_some(index),
+            }
+        }
+
+        /// Returns the depth of the given option in the hierarchy.
+        fn depth(&self, index: usize) -> usize {
+            self.hierarchy
+                .as_ref()
+                .and_then(|(depths, _)| depths.get(index).copied())
+                .unwrap_or(0)
+        }
+
+        /// Returns the indices of the descendants of the given option in the hierarchy.
+        fn descendants(&self, index: usize) -> Range<usize> {
+            let Some((depths, _)) = &self.hierarchy else {
+                return index + 1..index + 1;
+            };
+
+            let depth = self.depth(index);
+            let end = depths
+                .iter()
+                .skip(index + 1)
+                .position(|&next| next <= depth)
+                .map_or(depths.len(), |count| index + 1 + count);
+
+            index + 1..end.max(index + 1)
+        }
+
+        /// Returns whether the given option is expanded, if it has children.
+        fn is_expanded(&self, index: usize) -> Option<bool> {
+            let (_, expanded) = self.hierarchy.as_ref()?;
+
+            if self.descendants(index).is_empty() {
+                return None;
+            }
+
+            Some(expanded.get(index).copied().unwrap_or(false))
+        }
+
+        /// Returns the horizontal shift of the given option from its indentation level.
+        fn shift(&self, index: usize) -> f32 {
+            self.indentation
+                .zip(self.options.get(index))
+                .map_or(0.0, |(indentation, option)| {
+                    f32::from(indentation(option)) * self.indent_width
+                })
+        }
+
+        /// Returns the horizontal space in front of the checkbox of the given option, which
+        /// holds its indentation and its arrow.
+        fn indent(&self, index: usize, box_size: f32) -> f32 {
+            if self.hierarchy.is_none() {
+                return self.shift(index);
+            }
+
+            self.shift(index) + self.depth(index) as f32 * box_size + box_size + self.checkbox_spacing
+        }
+
+        fn box_size(&self, text_line_height: f32) -> f32 {
+            checkbox_size(self.checkbox_size, text_line_height, self.padding)
         }
     }
 
-    struct List<'a, 'b, T, Message, Theme, Renderer>
-    where
-        Theme: Catalog,
-        Renderer: text::Renderer,
-    {
-        options: &'a [T],
-        selected: &'a [T],
-        hovered_option: &'a mut Option<usize>,
-        on_selected: Box<dyn FnMut(T) -> Message + 'a>,
-        on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
-        padding: Padding,
-        text_size: Option<Pixels>,
-        text_line_height: text::LineHeight,
-        text_shaping: text::Shaping,
-        font: Option<Renderer::Font>,
-        class: &'a <Theme as Catalog>::Class<'b>,
-        icon: Icon<Renderer::Font>,
-    }
+    impl<T, Message, Theme, Renderer> List<'_, '_, T, Message, Theme, Renderer>
+    where
+        T: Clone + PartialEq,
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        /// Toggles the given option, and its children if they follow it, or picks it and
+        /// dismisses the [`Menu`] in [`Mode::Single`].
+        fn select(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+            if self.read_only || index >= self.options.len() || self.is_disabled(index) {
+                return;
+            }
+
+            if self.mode == Mode::Single {
+                shell.publish((self.on_selected)(self.options[index].clone()));
+                self.dismissed.set(true);
+                return;
+            }
+
+            // Children only follow a parent that is not selected yet.
+            let is_selected = !self.is_selected(index);
+
+            self.toggle(index, shell);
+
+            if self.select_children {
+                for child in self.descendants(index) {
+                    let is_enabled = child < self.options.len() && !self.is_disabled(child);
+
+                    if is_enabled && self.is_selected(child) != is_selected {
+                        self.toggle(child, shell);
+                    }
+                }
+            }
+        }
+
+        /// Toggles the given option in the batch, if any, or publishes its selection.
+        fn toggle(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+            if let Some(batch) = &mut self.batch {
+                match batch.iter().position(|selected| *selected == index) {
+                    Some(position) => _ = batch.remove(position),
+                    None => batch.push(index),
+                }
+
+                shell.request_redraw();
+            } else if let Some(option) = self.options.get(index) {
+                shell.publish((self.on_selected)(option.clone()));
+            }
+        }
+
+        /// Returns whether the given option is selected, in the batch if any.
+        fn is_selected(&self, index: usize) -> bool {
+            match &self.batch {
+                Some(batch) => batch.contains(&index),
+                None => self
+                    .options
+                    .get(index)
+                    .is_some_and(|option| super::contains(self.selected, option, self.is_same)),
+            }
+        }
+
+        /// Toggles whether the given option is expanded, if it has children.
+        fn toggle_expanded(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+            if self.is_expanded(index).is_none() {
+                return;
+            }
 
-    struct ListState {
-        is_hovered: Option<bool>,
+            if let Some((_, expanded)) = &mut self.hierarchy
+                && let Some(is_expanded) = expanded.get_mut(index)
+            {
+                *is_expanded = !*is_expanded;
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+        }
     }
 
     impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for List<'_, '_, T, Message, Theme, Renderer>
     where
-        T: Clone + ToString + PartialEq,
+        T: Clone + PartialEq,
         Theme: Catalog,
         Renderer: text::Renderer,
     {
         fn tag(&self) -> tree::Tag {
-            tree::Tag::of::<Option<bool>>()
+            tree::Tag::of::<ListState<Renderer::Paragraph>>()
         }
 
         fn state(&self) -> tree::State {
-            tree::State::new(ListState { is_hovered: None })
+            tree::State::new(ListState::<Renderer::Paragraph> {
+                is_hovered: None,
+                type_ahead: String::new(),
+                last_key_press: None,
+                labels: Vec::new(),
+                badges: Vec::new(),
+                keys: Vec::new(),
+                empty: Label::default(),
+                spinner: None,
+                touch_press: None,
+                hovered_since: None,
+                tooltip: None,
+                tooltip_label: Label::default(),
+                pending_hover: None,
+                offsets: Vec::new(),
+                lines: Vec::new(),
+                cells: Vec::new(),
+            })
         }
 
         fn size(&self) -> Size<Length> {
@@ -1039,16 +4261,149 @@ pub mod menu {
             }
         }
 
-        fn layout(&mut self, _tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
             use std::f32;
 
+            let state = tree.state.downcast_mut::<ListState<Renderer::Paragraph>>();
+
             let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
-            let text_line_height = self.text_line_height.to_absolute(text_size);
+            let text_line_height = f32::from(self.text_line_height.to_absolute(text_size));
+            let box_size = self.box_size(text_line_height);
+            let width = limits.max().width;
+            let column_width = width / self.columns as f32;
+            let (leading, trailing) = self.selection_indicator.reserved(box_size, self.checkbox_spacing);
+            let text_width = (column_width - self.padding.x() - leading - trailing).max(0.0);
+
+            let text = Text {
+                content: "",
+                bounds: Size::new(text_width, f32::INFINITY),
+                size: text_size,
+                line_height: self.text_line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                align_x: text::Alignment::Default,
+                align_y: alignment::Vertical::Center,
+                shaping: self.text_shaping,
+                wrapping: self.text_wrapping,
+            };
+
+            if let Some(keys) = self.keys
+                && keys != state.keys
+            {
+                let diff = Diff::new(&state.keys, keys);
+
+                diff.reorder(&mut state.labels);
+                diff.reorder(&mut state.badges);
+
+                state.keys = keys.to_vec();
+            }
+
+            state.labels.resize_with(self.options.len(), Label::default);
+            state.badges.resize_with(self.options.len(), Label::default);
+
+            // The hovered option can be gone after the options changed.
+            if self.hovered_option.is_some_and(|index| index >= self.options.len()) {
+                *self.hovered_option = None;
+            }
+
+            let badge_text = Text {
+                bounds: Size::INFINITE,
+                wrapping: text::Wrapping::None,
+                ..text
+            };
+
+            let labels = state.labels.iter_mut().zip(&mut state.badges);
+
+            for (index, (option, (label, badge))) in self.options.iter().zip(labels).enumerate() {
+                let content = self.badge.and_then(|to_badge| to_badge(option)).unwrap_or_default();
+                badge.update(content, badge_text, false);
+
+                let reserved = if badge.content.is_empty() {
+                    0.0
+                } else {
+                    badge.badge_width() + BADGE_SPACING
+                };
+                let reserved = reserved + self.indent(index, box_size);
+
+                let text = Text {
+                    bounds: Size::new((text_width - reserved).max(0.0), f32::INFINITY),
+                    align_x: self.text_align_x,
+                    align_y: self.text_align_y,
+                    font: self
+                        .option_font
+                        .and_then(|option_font| option_font(option))
+                        .unwrap_or(text.font),
+                    ..text
+                };
+
+                label.update((self.display)(option), text, self.text_ellipsis);
+            }
+
+            state.offsets.clear();
+            state.offsets.push(0.0);
+            state.lines.clear();
+            state.cells.clear();
+
+            let rows = self.rows();
+            let mut offset = 0.0;
+            let mut row = 0;
+
+            // Options flow into the columns of a line until a separator, which spans a line of its own.
+            while row < rows {
+                if self.entry(row) == Entry::Separator {
+                    state
+                        .cells
+                        .push(Rectangle::new(Point::new(0.0, offset), Size::new(width, SEPARATOR_HEIGHT)));
+                    state.lines.push(row..row + 1);
+                    offset += SEPARATOR_HEIGHT;
+                    state.offsets.push(offset);
+                    row += 1;
+
+                    continue;
+                }
+
+                let end = (row..rows)
+                    .take(self.columns)
+                    .take_while(|&row| self.entry(row) != Entry::Separator)
+                    .count()
+                    + row;
+
+                let height = self.option_height.unwrap_or_else(|| {
+                    (row..end)
+                        .filter_map(|row| self.entry(row).option())
+                        .filter_map(|index| state.labels.get(index))
+                        .map(|label| label.paragraph.min_bounds().height.max(text_line_height) + self.padding.y())
+                        .fold(0.0, f32::max)
+                });
+
+                for column in 0..end - row {
+                    state.cells.push(Rectangle::new(
+                        Point::new(column_width * column as f32, offset),
+                        Size::new(column_width, height),
+                    ));
+                }
+
+                state.lines.push(row..end);
+                offset += height;
+                state.offsets.push(offset);
+                row = end;
+            }
+
+            let height = if self.is_loading {
+                state.height() + self.option_height.unwrap_or(text_line_height + self.padding.y())
+            } else if rows == 0
+                && let Some(empty_text) = self.empty_text
+            {
+                state.empty.update(empty_text.to_owned(), text, self.text_ellipsis);
+
+                let text_height = state.empty.paragraph.min_bounds().height.max(text_line_height);
+
+                self.option_height.unwrap_or(text_height + self.padding.y())
+            } else {
+                state.height()
+            };
+
             let size = {
-                let intrinsic = Size::new(
-                    0.0,
-                    (f32::from(text_line_height) + self.padding.y()) * self.options.len() as f32,
-                );
+                let intrinsic = Size::new(0.0, height);
 
                 limits.resolve(Length::Fill, Length::Shrink, intrinsic)
             };
@@ -1064,62 +4419,164 @@ pub mod menu {
             renderer: &Renderer,
             _clipboard: &mut dyn Clipboard,
             shell: &mut Shell<'_, Message>,
-            _viewport: &Rectangle,
+            viewport: &Rectangle,
         ) {
             match event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    text: Some(text),
+                    modifiers,
+                    ..
+                }) if self.type_ahead
+                    && !modifiers.command()
+                    && !modifiers.alt()
+                    && !text.chars().any(char::is_control) =>
+                {
+                    let state = tree.state.downcast_mut::<ListState<Renderer::Paragraph>>();
+
+                    if let Some(index) = self.type_ahead(state, text) {
+                        self.hover(state, index, layout.bounds(), viewport, shell);
+                    }
+
+                    shell.capture_event();
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key:
+                        keyboard::Key::Named(
+                            key @ (keyboard::key::Named::Home
+                            | keyboard::key::Named::End
+                            | keyboard::key::Named::PageUp
+                            | keyboard::key::Named::PageDown),
+                        ),
+                    ..
+                }) => {
+                    let state = tree.state.downcast_mut::<ListState<Renderer::Paragraph>>();
+
+                    if let Some(index) = self.jump(state, *key, viewport.height) {
+                        self.hover(state, index, layout.bounds(), viewport, shell);
+                    }
+
+                    shell.capture_event();
+                }
                 Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                    if cursor.is_over(layout.bounds())
-                        && let Some(index) = *self.hovered_option
-                        && let Some(option) = self.options.get(index)
+                    let state = tree.state.downcast_ref::<ListState<Renderer::Paragraph>>();
+
+                    // The option is hit-tested again, since the cursor may have left the hovered
+                    // option for a separator without a `CursorMoved` reaching the list.
+                    if let Some(cursor_position) = cursor.position_in(layout.bounds())
+                        && let Some(index) = state.row_at(cursor_position).and_then(|row| self.entry(row).option())
                     {
-                        shell.publish((self.on_selected)(option.clone()));
+                        // A disabled option still captures the click, so the menu stays open, and
+                        // can still be expanded.
+                        if self.is_over_arrow(state, renderer, index, cursor_position) {
+                            self.toggle_expanded(index, shell);
+                        } else if !self.is_disabled(index) {
+                            *self.hovered_option = Some(index);
+                            self.select(index, shell);
+                        }
+
                         shell.capture_event();
                     }
                 }
                 Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                     if let Some(cursor_position) = cursor.position_in(layout.bounds()) {
-                        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+                        let state = tree.state.downcast_mut::<ListState<Renderer::Paragraph>>();
 
-                        let option_height = f32::from(self.text_line_height.to_absolute(text_size)) + self.padding.y();
-
-                        let new_hovered_option = (cursor_position.y / option_height) as usize;
-
-                        if *self.hovered_option != Some(new_hovered_option)
-                            && let Some(option) = self.options.get(new_hovered_option)
+                        if let Some(new_hovered_option) = state
+                            .row_at(cursor_position)
+                            .and_then(|row| self.entry(row).option())
+                            .filter(|&index| !self.is_disabled(index))
                         {
-                            if let Some(on_option_hovered) = self.on_option_hovered {
-                                shell.publish(on_option_hovered(option.clone()));
+                            if *self.hovered_option != Some(new_hovered_option)
+                                && new_hovered_option < self.options.len()
+                            {
+                                self.report_hover(state, new_hovered_option, shell);
+
+                                shell.request_redraw();
                             }
 
+                            *self.hovered_option = Some(new_hovered_option);
+                        } else if self.hovered_option.take().is_some() {
+                            // The cursor is over a separator, a disabled option, or the space below
+                            // the last line.
                             shell.request_redraw();
                         }
+                    }
+                }
+                Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                    let state = tree.state.downcast_mut::<ListState<Renderer::Paragraph>>();
+
+                    // The option is only toggled once the finger is lifted, so the press is left
+                    // uncaptured for the scrollable to start a drag.
+                    state.touch_press = cursor
+                        .position_in(layout.bounds())
+                        .and_then(|cursor_position| state.row_at(cursor_position))
+                        .and_then(|row| self.entry(row).option())
+                        .filter(|&index| !self.is_disabled(index))
+                        .map(|index| (*id, *position, index));
+
+                    if let Some((_, _, index)) = state.touch_press {
+                        *self.hovered_option = Some(index);
+                        shell.request_redraw();
+                    }
+                }
+                Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                    let state = tree.state.downcast_mut::<ListState<Renderer::Paragraph>>();
 
-                        *self.hovered_option = Some(new_hovered_option);
+                    if state
+                        .touch_press
+                        .is_some_and(|(finger, start, _)| finger == *id && start.distance(*position) > TOUCH_SLOP)
+                    {
+                        state.touch_press = None;
                     }
                 }
-                Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if let Some(cursor_position) = cursor.position_in(layout.bounds()) {
-                        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+                Event::Touch(touch::Event::FingerLifted { id, .. }) => {
+                    let state = tree.state.downcast_mut::<ListState<Renderer::Paragraph>>();
 
-                        let option_height = f32::from(self.text_line_height.to_absolute(text_size)) + self.padding.y();
+                    if let Some((finger, _, index)) = state.touch_press.take()
+                        && finger == *id
+                    {
+                        let is_over_arrow = cursor
+                            .position_in(layout.bounds())
+                            .is_some_and(|cursor_position| self.is_over_arrow(state, renderer, index, cursor_position));
+
+                        if is_over_arrow {
+                            self.toggle_expanded(index, shell);
+                        } else {
+                            self.select(index, shell);
+                        }
 
-                        *self.hovered_option = Some((cursor_position.y / option_height) as usize);
+                        shell.capture_event();
+                    }
+                }
+                Event::Touch(touch::Event::FingerLost { id, .. }) => {
+                    let state = tree.state.downcast_mut::<ListState<Renderer::Paragraph>>();
 
-                        if let Some(index) = *self.hovered_option
-                            && let Some(option) = self.options.get(index)
-                        {
-                            shell.publish((self.on_selected)(option.clone()));
-                            shell.capture_event();
-                        }
+                    if state.touch_press.is_some_and(|(finger, _, _)| finger == *id) {
+                        state.touch_press = None;
                     }
                 }
                 _ => {}
             }
 
-            let state = tree.state.downcast_mut::<ListState>();
+            let state = tree.state.downcast_mut::<ListState<Renderer::Paragraph>>();
 
-            if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            if let Event::Window(window::Event::RedrawRequested(now)) = event {
                 state.is_hovered = Some(cursor.is_over(layout.bounds()));
+
+                if self.is_loading {
+                    let started = state.spinner.map_or(*now, |(started, _)| started);
+
+                    state.spinner = Some((started, *now));
+                    shell.request_redraw();
+                } else {
+                    state.spinner = None;
+                }
+
+                if let Some(tooltip) = self.tooltip {
+                    self.update_tooltip(state, tooltip, cursor, layout.bounds(), *now, shell);
+                }
+
+                self.update_pending_hover(state, *now, shell);
             } else if state
                 .is_hovered
                 .is_some_and(|is_hovered| is_hovered != cursor.is_over(layout.bounds()))
@@ -1147,7 +4604,7 @@ pub mod menu {
 
         fn draw(
             &self,
-            _tree: &Tree,
+            tree: &Tree,
             renderer: &mut Renderer,
             theme: &Theme,
             _style: &renderer::Style,
@@ -1156,110 +4613,480 @@ pub mod menu {
             viewport: &Rectangle,
         ) {
             {
-                let style = Catalog::style(theme, self.class);
+                let state = tree.state.downcast_ref::<ListState<Renderer::Paragraph>>();
+                let style = Catalog::style(theme, self.class, Status::Active);
                 let bounds = layout.bounds();
 
+                let is_clipped_above = viewport.y > bounds.y + 0.5;
+                let is_clipped_below = viewport.y + viewport.height < bounds.y + bounds.height - 0.5;
+
+                // The overlay only stretches the options past the edges they are not clipped at.
+                self.clipped.set((is_clipped_above, is_clipped_below));
+
                 let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
-                let option_height = f32::from(self.text_line_height.to_absolute(text_size)) + self.padding.y();
+                let text_line_height = f32::from(self.text_line_height.to_absolute(text_size));
+
+                if self.is_loading {
+                    let height = self.option_height.unwrap_or(text_line_height + self.padding.y());
+                    let center = Point::new(bounds.center_x(), bounds.y + state.height() + height / 2.0);
+                    let elapsed = state.spinner.map_or(0.0, |(started, now)| {
+                        now.saturating_duration_since(started).as_secs_f32()
+                    });
+
+                    self.draw_spinner(renderer, center, text_line_height, style.empty_text_color, elapsed);
+                } else if self.rows() == 0 && self.empty_text.is_some() {
+                    renderer.fill_paragraph(
+                        state.empty.paragraph.raw(),
+                        Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                        style.empty_text_color,
+                        *viewport,
+                    );
+                }
 
                 let offset = viewport.y - bounds.y;
-                let start = (offset / option_height) as usize;
-                let end = ((offset + viewport.height) / option_height).ceil() as usize;
+                let visible = state.visible(offset, offset + viewport.height);
+
+                for row in visible.flat_map(|line| state.lines[line].clone()) {
+                    let Some(cell) = state.cells.get(row) else {
+                        continue;
+                    };
+
+                    let option_bounds = *cell + Vector::new(bounds.x, bounds.y);
+
+                    let i = match self.entry(row) {
+                        Entry::Option(index) => index,
+                        Entry::Separator => {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x: option_bounds.x + self.padding.left,
+                                        y: option_bounds.center_y().floor(),
+                                        width: (option_bounds.width - self.padding.x()).max(0.0),
+                                        height: 1.0,
+                                    },
+                                    ..renderer::Quad::default()
+                                },
+                                style.border.color,
+                            );
+
+                            continue;
+                        }
+                    };
+
+                    let Some(option) = self.options.get(i) else {
+                        continue;
+                    };
+
+                    let is_selected = self.is_selected(i);
+                    let is_hovered = *self.hovered_option == Some(i) && !self.read_only;
+                    let is_highlighted = is_selected && self.selection_indicator == SelectionIndicator::Highlight;
+                    let status = match (is_selected, is_hovered) {
+                        _ if self.read_only || self.is_disabled(i) => Status::Disabled,
+                        (false, false) => Status::Active,
+                        (false, true) => Status::Hovered,
+                        // Highlighted options have no other indicator, so they are drawn like hovered ones.
+                        (true, false) if !is_highlighted => Status::Selected,
+                        (true, _) => Status::SelectedHovered,
+                    };
+                    let mut row_style = Catalog::style(theme, self.class, status);
+
+                    if let Some(to_style) = self.option_style {
+                        let OptionStyle { text_color, background } = to_style(option, status);
+
+                        if let Some(text_color) = text_color {
+                            row_style.text_color = text_color;
+                            row_style.selected_text_color = text_color;
+                        }
+
+                        if let Some(background) = background {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x: option_bounds.x + row_style.border.width,
+                                        width: option_bounds.width - row_style.border.width * 2.0,
+                                        ..option_bounds
+                                    },
+                                    border: border::rounded(row_style.border.radius),
+                                    ..renderer::Quad::default()
+                                },
+                                background,
+                            );
+                        }
+                    }
+
+                    let box_size = self.box_size(text_line_height);
+                    let indent = self.indent(i, box_size);
+                    let (leading, trailing) = self.selection_indicator.reserved(box_size, self.checkbox_spacing);
+                    let box_bounds = Rectangle {
+                        x: match self.selection_indicator {
+                            SelectionIndicator::TrailingCheckmark => {
+                                option_bounds.x + option_bounds.width - self.padding.right - box_size
+                            }
+                            _ => option_bounds.x + self.checkbox_spacing + indent,
+                        },
+                        // Rows of an explicit height may be much taller than their checkbox.
+                        y: if self.option_height.is_some() {
+                            option_bounds.center_y() - box_size / 2.0
+                        } else {
+                            option_bounds.y + self.checkbox_spacing
+                        },
+                        width: box_size,
+                        height: box_size,
+                    };
 
-                let visible_options = &self.options[start..end.min(self.options.len())];
+                    if status != Status::Active {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: option_bounds.x + row_style.border.width,
+                                    width: option_bounds.width - row_style.border.width * 2.0,
+                                    ..option_bounds
+                                },
+                                border: border::rounded(row_style.border.radius),
+                                ..renderer::Quad::default()
+                            },
+                            row_style.selected_background,
+                        );
+                    }
+
+                    if let Some(is_expanded) = self.is_expanded(i) {
+                        let arrow_bounds = Rectangle {
+                            x: option_bounds.x
+                                + self.checkbox_spacing
+                                + self.shift(i)
+                                + self.depth(i) as f32 * box_size,
+                            width: box_size,
+                            ..box_bounds
+                        };
+
+                        renderer.fill_text(
+                            text::Text {
+                                content: if is_expanded { '\u{25BE}' } else { '\u{25B8}' }.to_string(),
+                                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                                size: Pixels(box_size),
+                                line_height: text::LineHeight::default(),
+                                bounds: arrow_bounds.size(),
+                                align_x: text::Alignment::Center,
+                                align_y: alignment::Vertical::Center,
+                                shaping: text::Shaping::Basic,
+                                wrapping: text::Wrapping::default(),
+                            },
+                            arrow_bounds.center(),
+                            row_style.text_color,
+                            *viewport,
+                        );
+                    }
+
+                    // A trailing checkmark has no box to fill, so it keeps the colors of the row.
+                    let checkbox = if status == Status::SelectedHovered
+                        && self.selection_indicator == SelectionIndicator::Checkbox
+                    {
+                        row_style.checkbox_hovered
+                    } else {
+                        row_style.checkbox
+                    };
+
+                    if self.selection_indicator == SelectionIndicator::Checkbox {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: box_bounds,
+                                border: checkbox.border,
+                                ..renderer::Quad::default()
+                            },
+                            checkbox.background,
+                        );
+                    }
+
+                    let Icon {
+                        font,
+                        code_point,
+                        size,
+                        line_height,
+                        shaping,
+                    } = &self.icon;
+                    let size = size.unwrap_or(Pixels(box_bounds.height * 0.7));
+                    if is_selected && self.selection_indicator != SelectionIndicator::Highlight {
+                        renderer.fill_text(
+                            text::Text {
+                                content: code_point.to_string(),
+                                font: *font,
+                                size,
+                                line_height: *line_height,
+                                bounds: box_bounds.size(),
+                                align_x: text::Alignment::Center,
+                                align_y: alignment::Vertical::Center,
+                                shaping: *shaping,
+                                wrapping: text::Wrapping::default(),
+                            },
+                            box_bounds.center(),
+                            checkbox.icon_color,
+                            *viewport,
+                        );
+                    }
+
+                    if let Some(label) = state.labels.get(i) {
+                        let x = option_bounds.x + self.padding.left + indent + leading;
+                        let width = text::Paragraph::bounds(label.paragraph.raw()).width;
+
+                        // The label is laid out in its own bounds, so it is drawn from its alignment point.
+                        let x = match self.text_align_x {
+                            text::Alignment::Center => x + width / 2.0,
+                            text::Alignment::Right => x + width,
+                            text::Alignment::Default | text::Alignment::Left | text::Alignment::Justified => x,
+                        };
+                        let y = match self.text_align_y {
+                            alignment::Vertical::Top => option_bounds.y + self.padding.top,
+                            alignment::Vertical::Center => option_bounds.center_y(),
+                            alignment::Vertical::Bottom => option_bounds.y + option_bounds.height - self.padding.bottom,
+                        };
+
+                        renderer.fill_paragraph(
+                            label.paragraph.raw(),
+                            Point::new(x, y),
+                            if is_highlighted {
+                                row_style.selected_text_color
+                            } else {
+                                row_style.text_color
+                            },
+                            *viewport,
+                        );
+                    }
+
+                    if let Some(badge) = state.badges.get(i).filter(|badge| !badge.content.is_empty()) {
+                        let width = badge.badge_width();
+                        let badge_bounds = Rectangle {
+                            x: option_bounds.x + option_bounds.width - self.padding.right - trailing - width,
+                            y: option_bounds.center_y() - text_line_height / 2.0,
+                            width,
+                            height: text_line_height,
+                        };
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: badge_bounds,
+                                border: border::rounded(badge_bounds.height / 2.0),
+                                ..renderer::Quad::default()
+                            },
+                            row_style.badge_background,
+                        );
+
+                        renderer.fill_paragraph(
+                            badge.paragraph.raw(),
+                            Point::new(badge_bounds.x + BADGE_PADDING, badge_bounds.center_y()),
+                            row_style.badge_text_color,
+                            *viewport,
+                        );
+                    }
+                }
+
+                if let Some(indicator) = style.scroll_indicator {
+                    if is_clipped_above {
+                        self.draw_scroll_indicator(renderer, indicator, viewport, text_size, Edge::Top);
+                    }
+
+                    if is_clipped_below {
+                        self.draw_scroll_indicator(renderer, indicator, viewport, text_size, Edge::Bottom);
+                    }
+                }
+            }
+        }
+
+        fn overlay<'c>(
+            &'c mut self,
+            tree: &'c mut Tree,
+            _layout: Layout<'c>,
+            _renderer: &Renderer,
+            _viewport: &Rectangle,
+            translation: Vector,
+        ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+            let ListState {
+                tooltip, tooltip_label, ..
+            } = tree.state.downcast_mut::<ListState<Renderer::Paragraph>>();
+            let (content, anchor) = tooltip.as_ref()?;
+
+            Some(overlay::Element::new(Box::new(Tooltip {
+                content,
+                label: tooltip_label,
+                anchor: *anchor + translation,
+                z_index: self.tooltip_z_index,
+                text_size: self.text_size,
+                text_line_height: self.text_line_height,
+                font: self.font,
+                class: self.class,
+            })))
+        }
+    }
+
+    /// An edge of the visible part of a [`List`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Edge {
+        Top,
+        Bottom,
+    }
+
+    impl<T, Message, Theme, Renderer> List<'_, '_, T, Message, Theme, Renderer>
+    where
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        /// Returns whether the given position of the list is over the arrow of the given option.
+        fn is_over_arrow(
+            &self,
+            state: &ListState<Renderer::Paragraph>,
+            renderer: &Renderer,
+            index: usize,
+            position: Point,
+        ) -> bool {
+            if self.is_expanded(index).is_none() {
+                return false;
+            }
+
+            let Some(cell) = self.row_of(index).and_then(|row| state.cells.get(row)) else {
+                return false;
+            };
+
+            let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+            let text_line_height = f32::from(self.text_line_height.to_absolute(text_size));
+            let box_size = self.box_size(text_line_height);
+            let x = cell.x + self.checkbox_spacing + self.shift(index) + self.depth(index) as f32 * box_size;
+
+            cell.contains(position) && (x..x + box_size + self.checkbox_spacing).contains(&position.x)
+        }
+
+        /// Shows the tooltip of the hovered option next to the cursor once it has been hovered
+        /// for the tooltip delay.
+        ///
+        /// The tooltip is an overlay of the [`List`], so showing or hiding it invalidates the
+        /// layout of the overlays.
+        fn update_tooltip(
+            &self,
+            state: &mut ListState<Renderer::Paragraph>,
+            tooltip: &dyn Fn(&T) -> Option<String>,
+            cursor: mouse::Cursor,
+            bounds: Rectangle,
+            now: Instant,
+            shell: &mut Shell<'_, Message>,
+        ) {
+            let hovered_option = self.hovered_option.filter(|_| cursor.is_over(bounds));
+
+            if state.hovered_since.map(|(index, _)| index) != hovered_option {
+                state.hovered_since = hovered_option.map(|index| (index, now));
+
+                if state.tooltip.take().is_some() {
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+
+            let Some((index, since)) = state.hovered_since else {
+                return;
+            };
+
+            if state.tooltip.is_some() {
+                return;
+            }
+
+            let deadline = since + self.tooltip_delay;
+
+            if now >= deadline {
+                state.tooltip = self.options.get(index).and_then(tooltip).zip(cursor.position());
+                shell.invalidate_layout();
+                shell.request_redraw();
+            } else {
+                shell.request_redraw_at(window::RedrawRequest::At(deadline));
+            }
+        }
+
+        /// Draws the spinner shown while loading, as a ring of dots of the given size fading
+        /// behind the one leading its turn.
+        fn draw_spinner(&self, renderer: &mut Renderer, center: Point, size: f32, color: Color, elapsed: f32) {
+            let dots = SPINNER_DOTS as f32;
+            let lead = (elapsed * dots) % dots;
+            let radius = size * 0.4;
+            let dot_size = size * 0.18;
+
+            for dot in 0..SPINNER_DOTS {
+                let angle = dot as f32 / dots * std::f32::consts::TAU;
+                let behind = (lead - dot as f32).rem_euclid(dots);
+                let position = center + Vector::new(angle.sin() * radius, -angle.cos() * radius);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: position.x - dot_size / 2.0,
+                            y: position.y - dot_size / 2.0,
+                            width: dot_size,
+                            height: dot_size,
+                        },
+                        border: border::rounded(dot_size / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    Color {
+                        a: color.a * (1.0 - behind / dots),
+                        ..color
+                    },
+                );
+            }
+        }
 
-                for (i, option) in visible_options.iter().enumerate() {
-                    let i = start + i;
-                    let is_selected = self.selected.contains(option);
-                    let is_hovered = *self.hovered_option == Some(i);
+        /// Draws the [`ScrollIndicator`] at the given edge of the viewport.
+        fn draw_scroll_indicator(
+            &self,
+            renderer: &mut Renderer,
+            indicator: ScrollIndicator,
+            viewport: &Rectangle,
+            text_size: Pixels,
+            edge: Edge,
+        ) {
+            let height = f32::from(self.text_line_height.to_absolute(text_size)).min(viewport.height / 2.0);
 
-                    let option_bounds = Rectangle {
-                        x: bounds.x,
-                        y: bounds.y + (option_height * i as f32),
-                        width: bounds.width,
-                        height: option_height,
-                    };
+            let bounds = Rectangle {
+                y: match edge {
+                    Edge::Top => viewport.y,
+                    Edge::Bottom => viewport.y + viewport.height - height,
+                },
+                height,
+                ..*viewport
+            };
 
-                    let box_size = option_height * 0.6;
-                    let box_bounds = Rectangle {
-                        x: bounds.x + 5.0,
-                        y: bounds.y + 5.0 + (option_height * i as f32),
-                        width: box_size,
-                        height: box_size,
+            match indicator {
+                ScrollIndicator::Fade(color) => {
+                    // A zero angle runs from the bottom to the top of the bounds.
+                    let angle = match edge {
+                        Edge::Top => Radians::PI,
+                        Edge::Bottom => Radians(0.0),
                     };
 
-                    if is_hovered {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: Rectangle {
-                                    x: option_bounds.x + style.border.width,
-                                    width: option_bounds.width - style.border.width * 2.0,
-                                    ..option_bounds
-                                },
-                                border: border::rounded(style.border.radius),
-                                ..renderer::Quad::default()
-                            },
-                            style.selected_background,
-                        );
-                    }
-
                     renderer.fill_quad(
                         renderer::Quad {
-                            bounds: box_bounds,
-                            border: style.checkbox.border,
+                            bounds,
                             ..renderer::Quad::default()
                         },
-                        style.checkbox.background,
+                        gradient::Linear::new(angle)
+                            .add_stop(0.0, color)
+                            .add_stop(1.0, Color { a: 0.0, ..color }),
                     );
-
-                    let Icon {
-                        font,
-                        code_point,
-                        size,
-                        line_height,
-                        shaping,
-                    } = &self.icon;
-                    let size = size.unwrap_or(Pixels(box_bounds.height * 0.7));
-                    if is_selected {
-                        renderer.fill_text(
-                            text::Text {
-                                content: code_point.to_string(),
-                                font: *font,
-                                size,
-                                line_height: *line_height,
-                                bounds: box_bounds.size(),
-                                align_x: text::Alignment::Center,
-                                align_y: alignment::Vertical::Center,
-                                shaping: *shaping,
-                                wrapping: text::Wrapping::default(),
-                            },
-                            box_bounds.center(),
-                            if is_hovered {
-                                style.selected_text_color
-                            } else {
-                                style.checkbox.icon_color
-                            },
-                            *viewport,
-                        );
-                    }
+                }
+                ScrollIndicator::Chevron(color) => {
+                    let code_point = match edge {
+                        Edge::Top => '\u{25B4}',
+                        Edge::Bottom => '\u{25BE}',
+                    };
 
                     renderer.fill_text(
                         Text {
-                            content: option.to_string(),
-                            bounds: Size::new(f32::INFINITY, option_bounds.height),
+                            content: code_point.to_string(),
+                            bounds: bounds.size(),
                             size: text_size,
                             line_height: self.text_line_height,
                             font: self.font.unwrap_or_else(|| renderer.default_font()),
-                            align_x: text::Alignment::Default,
+                            align_x: text::Alignment::Center,
                             align_y: alignment::Vertical::Center,
-                            shaping: self.text_shaping,
-                            wrapping: text::Wrapping::default(),
+                            shaping: text::Shaping::Advanced,
+                            wrapping: text::Wrapping::None,
                         },
-                        Point::new(
-                            option_bounds.x + self.padding.left + box_size + 5.0,
-                            option_bounds.center_y(),
-                        ),
-                        style.text_color,
+                        bounds.center(),
+                        color,
                         *viewport,
                     );
                 }
@@ -1270,7 +5097,7 @@ pub mod menu {
     impl<'a, 'b, T, Message, Theme, Renderer> From<List<'a, 'b, T, Message, Theme, Renderer>>
         for Element<'a, Message, Theme, Renderer>
     where
-        T: ToString + Clone + PartialEq,
+        T: Clone + PartialEq,
         Message: 'a,
         Theme: 'a + Catalog,
         Renderer: 'a + text::Renderer,
@@ -1281,6 +5108,103 @@ pub mod menu {
         }
     }
 
+    /// The tooltip of an option, opened from the [`List`] of a [`Menu`] so it is drawn above the
+    /// menu and the overlays of its footer.
+    struct Tooltip<'a, 'b, Theme, Renderer>
+    where
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        content: &'a str,
+        label: &'a mut Label<Renderer::Paragraph>,
+        anchor: Point,
+        z_index: f32,
+        text_size: Option<Pixels>,
+        text_line_height: text::LineHeight,
+        font: Option<Renderer::Font>,
+        class: &'a <Theme as Catalog>::Class<'b>,
+    }
+
+    impl<Message, Theme, Renderer> iced_core::Overlay<Message, Theme, Renderer> for Tooltip<'_, '_, Theme, Renderer>
+    where
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+            // The label is only shaped again when its content or the width of the window changes.
+            self.label.update(
+                self.content.to_owned(),
+                Text {
+                    content: self.content,
+                    bounds: Size::new(bounds.width / 2.0, f32::INFINITY),
+                    size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                    line_height: self.text_line_height,
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    align_x: text::Alignment::Default,
+                    align_y: alignment::Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::Word,
+                },
+                false,
+            );
+
+            let size = self
+                .label
+                .paragraph
+                .min_bounds()
+                .expand(Size::new(TOOLTIP_PADDING * 2.0, TOOLTIP_PADDING * 2.0));
+
+            // The tooltip sits below the cursor, or above it when it would overflow the window.
+            let target = Rectangle {
+                x: self.anchor.x + TOOLTIP_OFFSET.x,
+                y: self.anchor.y - TOOLTIP_OFFSET.y,
+                width: 0.0,
+                height: TOOLTIP_OFFSET.y * 2.0,
+            };
+            let placement = if target.y + target.height + size.height > bounds.height {
+                Placement::Above
+            } else {
+                Placement::Below
+            };
+            let position = anchored_overlay::position(target, size, placement, bounds);
+
+            layout::Node::new(size).move_to(Point::new(position.x, position.y.max(0.0)))
+        }
+
+        fn draw(
+            &self,
+            renderer: &mut Renderer,
+            theme: &Theme,
+            _defaults: &renderer::Style,
+            layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+        ) {
+            let bounds = layout.bounds();
+            let style = Catalog::style(theme, self.class, Status::Active);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                style.tooltip_background,
+            );
+
+            renderer.fill_paragraph(
+                self.label.paragraph.raw(),
+                Point::new(bounds.x + TOOLTIP_PADDING, bounds.y + TOOLTIP_PADDING),
+                style.tooltip_text_color,
+                bounds,
+            );
+        }
+
+        fn index(&self) -> f32 {
+            self.z_index
+        }
+    }
+
     /// The appearance of a [`Menu`].
     #[derive(Debug, Clone, Copy, PartialEq)]
     pub struct Style {
@@ -1298,6 +5222,96 @@ pub mod menu {
         pub shadow: Shadow,
         /// The style of the checkbox
         pub checkbox: CheckboxStyle,
+        /// The style of the checkbox of a selected option while it is hovered, drawn over the
+        /// highlight of the option instead of [`checkbox`](Self::checkbox).
+        pub checkbox_hovered: CheckboxStyle,
+        /// The [`Background`] of the badge of an option.
+        pub badge_background: Background,
+        /// The text [`Color`] of the badge of an option.
+        pub badge_text_color: Color,
+        /// The [`Background`] of the tooltip of an option.
+        pub tooltip_background: Background,
+        /// The text [`Color`] of the tooltip of an option.
+        pub tooltip_text_color: Color,
+        /// The [`Background`] of the backdrop behind the menu, when enabled.
+        pub backdrop: Background,
+        /// The indicator drawn at the edges of the menu that hide more options, if any.
+        pub scroll_indicator: Option<ScrollIndicator>,
+        /// The text [`Color`] of the text shown while the menu has no options, and of the spinner
+        /// shown while loading more.
+        pub empty_text_color: Color,
+    }
+
+    /// The colors of a single option of a [`Menu`], drawn over its [`Style`].
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    pub struct OptionStyle {
+        /// The text [`Color`] of the option, if it differs from the [`Style`].
+        pub text_color: Option<Color>,
+        /// The [`Background`] of the option, drawn below the highlight of a hovered or selected
+        /// option, if any.
+        pub background: Option<Background>,
+    }
+
+    #[cfg(feature = "stock-styles")]
+    impl From<Style> for iced_widget::overlay::menu::Style {
+        fn from(style: Style) -> Self {
+            Self {
+                background: style.background,
+                border: style.border,
+                text_color: style.text_color,
+                selected_text_color: style.selected_text_color,
+                selected_background: style.selected_background,
+                shadow: style.shadow,
+            }
+        }
+    }
+
+    /// The indicator of a [`Menu`] that can be scrolled further.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum ScrollIndicator {
+        /// Fades the clipped edges out from the given [`Color`].
+        Fade(Color),
+        /// Draws a chevron with the given [`Color`] at the clipped edges.
+        Chevron(Color),
+    }
+
+    /// How many options of a [`Menu`] can be selected.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum Mode {
+        /// Selecting an option toggles it, and the [`Menu`] stays open.
+        ///
+        /// This is the default.
+        #[default]
+        Multiple,
+        /// Selecting an option picks it, and the [`Menu`] is dismissed, see
+        /// [`State::take_dismissed`].
+        ///
+        /// The owner is expected to replace its selection with the picked option.
+        Single,
+    }
+
+    /// How the selected options of a [`Menu`] are told apart.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum SelectionIndicator {
+        /// Every option has a leading checkbox, holding a checkmark once selected.
+        #[default]
+        Checkbox,
+        /// Selected options are drawn with the background of a hovered option, without a checkbox.
+        Highlight,
+        /// Selected options have a checkmark at their end, without a checkbox.
+        TrailingCheckmark,
+    }
+
+    impl SelectionIndicator {
+        /// Returns the horizontal space taken by the indicator before and after the label of an
+        /// option, given the size of its checkbox and the space around it.
+        pub(super) fn reserved(self, box_size: f32, spacing: f32) -> (f32, f32) {
+            match self {
+                SelectionIndicator::Checkbox => (box_size + spacing, 0.0),
+                SelectionIndicator::Highlight => (0.0, 0.0),
+                SelectionIndicator::TrailingCheckmark => (0.0, box_size + spacing),
+            }
+        }
     }
 
     /// The theme catalog of a [`Menu`].
@@ -1314,11 +5328,30 @@ pub mod menu {
         }
 
         /// The [`Style`] of a class with the given status.
-        fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+        ///
+        /// The parts of the [`Style`] shared by every option are taken from [`Status::Active`].
+        fn style(&self, class: &<Self as Catalog>::Class<'_>, status: Status) -> Style;
+    }
+
+    /// The status of an option of a [`Menu`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Status {
+        /// The option is neither selected nor hovered.
+        Active,
+        /// The option is hovered.
+        Hovered,
+        /// The option is selected.
+        Selected,
+        /// The option is selected and hovered.
+        SelectedHovered,
+        /// The option cannot be toggled.
+        Disabled,
     }
 
     /// A styling function for a [`Menu`].
-    pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+    ///
+    /// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+    pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
     impl Catalog for Theme {
         type Class<'a> = StyleFn<'a, Self>;
@@ -1327,13 +5360,15 @@ pub mod menu {
             Box::new(default)
         }
 
-        fn style(&self, class: &StyleFn<'_, Self>) -> Style {
-            class(self)
+        fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+            class(self, status)
         }
     }
 
     /// The default style of the list of a [`Menu`].
-    pub fn default(theme: &Theme) -> Style {
+    ///
+    /// Hovered options are highlighted, selected ones are only told apart by their checkbox.
+    pub fn default(theme: &Theme, status: Status) -> Style {
         let palette = theme.extended_palette();
 
         let checkbox = CheckboxStyle {
@@ -1347,7 +5382,7 @@ pub mod menu {
             text_color: None,
         };
 
-        Style {
+        let active = Style {
             background: palette.background.weak.color.into(),
             border: Border {
                 width: 1.0,
@@ -1359,6 +5394,110 @@ pub mod menu {
             selected_background: palette.primary.strong.color.into(),
             shadow: Shadow::default(),
             checkbox,
+            // The checkbox is filled with the color of the highlighted text, so its checkmark
+            // stands out from the highlight whatever the palette.
+            checkbox_hovered: CheckboxStyle {
+                background: palette.primary.strong.text.into(),
+                icon_color: palette.primary.strong.color,
+                border: Border {
+                    color: palette.primary.strong.text,
+                    ..checkbox.border
+                },
+                ..checkbox
+            },
+            badge_background: palette.background.strong.color.into(),
+            badge_text_color: palette.background.strong.text,
+            tooltip_background: palette.background.base.color.into(),
+            tooltip_text_color: palette.background.base.text,
+            backdrop: Color { a: 0.4, ..Color::BLACK }.into(),
+            scroll_indicator: None,
+            empty_text_color: palette.background.strong.color,
+        };
+
+        match status {
+            Status::Active | Status::Selected => Style {
+                selected_background: Color::TRANSPARENT.into(),
+                ..active
+            },
+            Status::Hovered | Status::SelectedHovered => Style {
+                checkbox: CheckboxStyle {
+                    icon_color: active.selected_text_color,
+                    ..checkbox
+                },
+                ..active
+            },
+            Status::Disabled => Style {
+                text_color: palette.background.strong.color,
+                selected_background: Color::TRANSPARENT.into(),
+                checkbox: CheckboxStyle {
+                    icon_color: palette.background.strong.color,
+                    ..checkbox
+                },
+                ..active
+            },
+        }
+    }
+
+    /// A [`Menu`] without a border or a shadow, blending with the surface below it.
+    pub fn flat(theme: &Theme, status: Status) -> Style {
+        Style {
+            border: Border::default(),
+            shadow: Shadow::default(),
+            ..default(theme, status)
+        }
+    }
+
+    /// A [`Menu`] with a rounded border and no shadow.
+    pub fn bordered(theme: &Theme, status: Status) -> Style {
+        Style {
+            border: Border {
+                width: 1.0,
+                radius: 4.0.into(),
+                color: theme.extended_palette().background.strong.color,
+            },
+            shadow: Shadow::default(),
+            ..default(theme, status)
+        }
+    }
+
+    /// A [`Menu`] floating above the surface below it, at an elevation of 2.
+    pub fn elevated(theme: &Theme, status: Status) -> Style {
+        elevate(default(theme, status), 2)
+    }
+
+    /// Returns a styling function for a [`Menu`] floating at the given elevation.
+    ///
+    /// An elevation of 0 is the same as [`flat`], and every level casts a longer shadow.
+    pub fn elevation(level: u8) -> impl Fn(&Theme, Status) -> Style {
+        move |theme, status| elevate(default(theme, status), level)
+    }
+
+    fn elevate(style: Style, level: u8) -> Style {
+        if level == 0 {
+            return Style {
+                border: Border::default(),
+                shadow: Shadow::default(),
+                ..style
+            };
+        }
+
+        let level = f32::from(level);
+
+        Style {
+            border: Border {
+                width: 0.0,
+                radius: 4.0.into(),
+                ..style.border
+            },
+            shadow: Shadow {
+                color: Color {
+                    a: (0.12 + 0.04 * level).min(0.4),
+                    ..Color::BLACK
+                },
+                offset: Vector::new(0.0, level),
+                blur_radius: 3.0 * level,
+            },
+            ..style
         }
     }
 
@@ -1371,11 +5510,276 @@ pub mod menu {
         pub shaping: text::Shaping,
     }
 
+    /// The appearance of the checkbox of an option of a [`Menu`].
+    ///
+    /// Its size and spacing lay the options out, which happens without a theme, so they are set
+    /// with [`Menu::checkbox_size`] and [`Menu::checkbox_spacing`] instead.
     #[derive(Debug, Clone, Copy, PartialEq)]
     pub struct CheckboxStyle {
+        /// The [`Background`] of the checkbox.
         pub background: Background,
+        /// The [`Color`] of the check mark of a selected option.
         pub icon_color: Color,
+        /// The [`Border`] of the checkbox.
         pub border: Border,
+        /// The text [`Color`] of the checkbox, if any.
         pub text_color: Option<Color>,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_core::keyboard;
+    use iced_core::time::Duration;
+    use iced_core::{Border, Color, Point, Rectangle, Theme};
+    use proptest::prelude::*;
+
+    use super::MultiPickList;
+    use super::menu::{self, Diff};
+    use crate::testing::{Harness, Primitive, Recorder};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggled(String),
+    }
+
+    const OPTION_HEIGHT: f32 = 30.0;
+    const MENU_HEIGHT: f32 = 100.0;
+
+    fn options(count: usize) -> Vec<String> {
+        (0..count).map(|index| format!("Option {index}")).collect()
+    }
+
+    fn harness<'a>(options: &'a [String], selected: &'a [String]) -> Harness<'a, Message, Theme, Recorder> {
+        let pick_list = MultiPickList::new(options, selected, Message::Toggled)
+            .width(200)
+            .option_height(OPTION_HEIGHT)
+            .menu_height(MENU_HEIGHT)
+            .menu_animation(Duration::ZERO);
+
+        Harness::with_renderer(pick_list, Recorder::default())
+    }
+
+    /// Draws the harness, returning the quads and the labels of the options it drew.
+    fn draw(harness: &mut Harness<'_, Message, Theme, Recorder>) -> (Vec<Rectangle>, Vec<(String, Point, Rectangle)>) {
+        harness.draw(&Theme::Light);
+
+        let primitives = harness.renderer_mut().take();
+
+        let quads = primitives
+            .iter()
+            .filter_map(|primitive| match primitive {
+                Primitive::Quad { bounds, .. } => Some(*bounds),
+                _ => None,
+            })
+            .collect();
+
+        let labels = primitives
+            .into_iter()
+            .filter_map(|primitive| match primitive {
+                Primitive::Paragraph {
+                    content,
+                    position,
+                    clip_bounds,
+                    ..
+                } if content.starts_with("Option ") => Some((content, position, clip_bounds)),
+                _ => None,
+            })
+            .collect();
+
+        (quads, labels)
+    }
+
+    fn contents(labels: &[(String, Point, Rectangle)]) -> Vec<&str> {
+        labels.iter().map(|(content, _, _)| content.as_str()).collect()
+    }
+
+    fn is_close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 0.01
+    }
+
+    /// Returns the new index of each of the given amount of previous options.
+    fn indices(diff: &Diff, count: usize) -> Vec<Option<usize>> {
+        (0..count).map(|index| diff.index(index)).collect()
+    }
+
+    #[test]
+    fn scrolled_menu_draws_the_visible_rows() {
+        let options = options(20);
+        let mut harness = harness(&options, &[]);
+
+        harness.click((10.0, 10.0));
+
+        let (_, labels) = draw(&mut harness);
+
+        // The last row is cut by the bottom of the menu, but still drawn.
+        assert_eq!(contents(&labels), ["Option 0", "Option 1", "Option 2", "Option 3"]);
+
+        harness.press_key(
+            keyboard::Key::Named(keyboard::key::Named::End),
+            keyboard::Modifiers::default(),
+        );
+
+        let (quads, labels) = draw(&mut harness);
+
+        // The last option is scrolled to the bottom of the menu, cutting the first row at its top.
+        assert_eq!(contents(&labels), ["Option 16", "Option 17", "Option 18", "Option 19"]);
+
+        let menu = labels[0].2;
+        let scrolled = options.len() as f32 * OPTION_HEIGHT - MENU_HEIGHT;
+
+        assert!(is_close(menu.height, MENU_HEIGHT));
+
+        for (row, (content, position, clip_bounds)) in (16..).zip(&labels) {
+            let center = menu.y + row as f32 * OPTION_HEIGHT - scrolled + OPTION_HEIGHT / 2.0;
+
+            assert_eq!(*clip_bounds, menu, "{content} is clipped to the menu");
+            assert!(
+                is_close(position.y, center),
+                "{content} is drawn in the middle of its row"
+            );
+
+            // The checkbox of every row is centered on it.
+            assert!(
+                quads
+                    .iter()
+                    .any(|quad| is_close(quad.width, quad.height) && is_close(quad.center_y(), center)),
+                "{content} has a checkbox"
+            );
+        }
+
+        // The hovered last option fills its whole row.
+        let rows: Vec<_> = quads
+            .iter()
+            .filter(|quad| is_close(quad.height, OPTION_HEIGHT))
+            .collect();
+
+        assert_eq!(rows.len(), 1);
+        assert!(is_close(rows[0].y, menu.y + MENU_HEIGHT - OPTION_HEIGHT));
+        assert!(rows[0].x >= menu.x && rows[0].x + rows[0].width <= menu.x + menu.width);
+    }
+
+    #[test]
+    fn diff_keeps_the_items_of_inserted_options_apart() {
+        let diff = Diff::new(&[1, 2, 3], &[1, 4, 2, 3]);
+
+        assert_eq!(indices(&diff, 3), [Some(0), Some(2), Some(3)]);
+
+        let mut items = vec!["a", "b", "c"];
+        diff.reorder(&mut items);
+
+        assert_eq!(items, ["a", "", "b", "c"]);
+    }
+
+    #[test]
+    fn diff_drops_the_items_of_removed_options() {
+        let diff = Diff::new(&[1, 2, 3], &[1, 3]);
+
+        assert_eq!(indices(&diff, 3), [Some(0), None, Some(1)]);
+        assert_eq!(diff.index(3), None);
+
+        let mut items = vec!["a", "b", "c"];
+        diff.reorder(&mut items);
+
+        assert_eq!(items, ["a", "c"]);
+    }
+
+    #[test]
+    fn diff_moves_the_items_of_reordered_options() {
+        let diff = Diff::new(&[1, 2, 3], &[3, 1, 2]);
+
+        assert_eq!(indices(&diff, 3), [Some(1), Some(2), Some(0)]);
+
+        let mut items = vec!["a", "b", "c"];
+        diff.reorder(&mut items);
+
+        assert_eq!(items, ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn diff_matches_duplicate_keys_in_order() {
+        let diff = Diff::new(&[7, 1, 7], &[7, 7, 7, 1]);
+
+        // The first two sevens keep their items in order, and the third one is new.
+        assert_eq!(indices(&diff, 3), [Some(0), Some(3), Some(1)]);
+
+        let mut items = vec!["a", "b", "c"];
+        diff.reorder(&mut items);
+
+        assert_eq!(items, ["a", "c", "", "b"]);
+
+        let diff = Diff::new(&[7, 7], &[7]);
+        let mut items = vec!["a", "b"];
+        diff.reorder(&mut items);
+
+        assert_eq!(diff.index(1), None);
+        assert_eq!(items, ["a"]);
+    }
+
+    proptest! {
+        /// Whatever the size of the window and the menu, and however far it is scrolled, a click
+        /// toggles the option drawn under the cursor, or nothing below the last one.
+        #[test]
+        fn clicks_toggle_the_option_drawn_under_the_cursor(
+            count in 1_usize..60,
+            option_height in 24.0_f32..48.0,
+            menu_height in 60.0_f32..300.0,
+            window in (240.0_f32..800.0, 200.0_f32..800.0),
+            pages in 0_usize..4,
+            cursor in (0.05_f32..0.8, 0.01_f32..0.99),
+            padding in 0.0_f32..12.0,
+            text_size in 10.0_f32..20.0,
+            border_width in 0.0_f32..4.0,
+        ) {
+            let options = options(count);
+            let pick_list = MultiPickList::new(&options[..], &[][..], Message::Toggled)
+                .width(200)
+                .padding(padding)
+                .text_size(text_size)
+                .option_height(option_height)
+                .menu_height(menu_height)
+                .menu_style(move |theme, status| menu::Style {
+                    border: Border {
+                        color: Color::BLACK,
+                        width: border_width,
+                        radius: 4.0.into(),
+                    },
+                    ..menu::default(theme, status)
+                })
+                .menu_animation(Duration::ZERO);
+            let mut harness: Harness<'_, Message, Theme, Recorder> =
+                Harness::with_renderer(pick_list, Recorder::default()).size(window);
+
+            harness.click((10.0, 10.0));
+
+            for _ in 0..pages {
+                harness.press_key(
+                    keyboard::Key::Named(keyboard::key::Named::PageDown),
+                    keyboard::Modifiers::default(),
+                );
+            }
+
+            let (_, labels) = draw(&mut harness);
+            prop_assume!(!labels.is_empty());
+
+            let menu = labels[0].2;
+            let position = Point::new(menu.x + menu.width * cursor.0, menu.y + menu.height * cursor.1);
+            let distance = |label: &Point| (position.y - label.y).abs();
+
+            // The labels are drawn in the middle of their rows, and the edges between rows are left out.
+            prop_assume!(labels.iter().all(|(_, label, _)| (distance(label) - option_height / 2.0).abs() > 0.5));
+
+            let expected: Vec<_> = labels
+                .iter()
+                .find(|(_, label, _)| distance(label) < option_height / 2.0)
+                .map(|(content, _, _)| Message::Toggled(content.clone()))
+                .into_iter()
+                .collect();
+
+            let _ = harness.take_messages();
+            harness.click(position);
+
+            prop_assert_eq!(harness.take_messages(), expected);
+        }
+    }
+}
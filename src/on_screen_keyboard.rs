@@ -0,0 +1,724 @@
+//! Type text on touch screens without a keyboard, like on a kiosk.
+//!
+//! An [`OnScreenKeyboard`] shows the rows of keys of a [`Keymap`], with a layer of letters that
+//! can be shifted and a layer of symbols, and produces a message with every [`Input`] typed.
+//! Like a [`Keypad`], it does not own the text being typed: the application applies every
+//! [`Input`] to its field, which [`Input::apply`] does for plain text. Characters and backspace
+//! repeat while held.
+//!
+//! [`Keypad`]: crate::Keypad
+use iced_core::Background;
+use iced_core::Border;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Text;
+use iced_core::Theme;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::alignment;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::time::Duration;
+use iced_core::time::Instant;
+use iced_core::touch;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+use iced_core::window;
+
+/// A key of a [`Keymap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// Types a character, in upper case while shifted.
+    Character(char),
+    /// Types a space.
+    Space,
+    /// Removes the last character.
+    Backspace,
+    /// Confirms the text.
+    Enter,
+    /// Shifts the next character to upper case.
+    Shift,
+    /// Switches between the letters and the symbols.
+    Symbols,
+}
+
+impl Key {
+    /// Returns the width of the [`Key`], relative to the width of a character.
+    fn width(self) -> f32 {
+        match self {
+            Key::Character(_) => 1.0,
+            Key::Space => 5.0,
+            Key::Enter => 2.0,
+            Key::Backspace | Key::Shift | Key::Symbols => 1.5,
+        }
+    }
+
+    /// Returns whether the [`Key`] repeats while held.
+    fn is_repeated(self) -> bool {
+        matches!(self, Key::Character(_) | Key::Space | Key::Backspace)
+    }
+}
+
+/// The input typed with an [`OnScreenKeyboard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Input {
+    /// A character, including spaces.
+    Character(char),
+    /// Removes the last character.
+    Backspace,
+    /// Confirms the text.
+    Enter,
+}
+
+impl Input {
+    /// Applies the [`Input`] to the given text, returning whether it confirmed it.
+    pub fn apply(self, text: &mut String) -> bool {
+        match self {
+            Input::Character(character) => text.push(character),
+            Input::Backspace => _ = text.pop(),
+            Input::Enter => return true,
+        }
+
+        false
+    }
+}
+
+/// The rows of keys of an [`OnScreenKeyboard`], for its letters and its symbols.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap {
+    letters: Vec<Vec<Key>>,
+    symbols: Vec<Vec<Key>>,
+}
+
+impl Keymap {
+    /// Creates a new [`Keymap`] with the given rows of letters, from top to bottom.
+    ///
+    /// The letters are put in upper case while shifted.
+    pub fn new(letters: impl IntoIterator<Item = impl IntoIterator<Item = Key>>) -> Self {
+        Self {
+            letters: rows(letters),
+            symbols: Vec::new(),
+        }
+    }
+
+    /// Sets the rows of symbols of the [`Keymap`], shown after tapping [`Key::Symbols`].
+    pub fn symbols(mut self, symbols: impl IntoIterator<Item = impl IntoIterator<Item = Key>>) -> Self {
+        self.symbols = rows(symbols);
+        self
+    }
+
+    /// Creates the QWERTY [`Keymap`] of an English keyboard, with digits and punctuation among
+    /// its symbols.
+    pub fn qwerty() -> Self {
+        Self::new([
+            characters("qwertyuiop"),
+            characters("asdfghjkl"),
+            [vec![Key::Shift], characters("zxcvbnm"), vec![Key::Backspace]].concat(),
+            vec![
+                Key::Symbols,
+                Key::Character(','),
+                Key::Space,
+                Key::Character('.'),
+                Key::Enter,
+            ],
+        ])
+        .symbols([
+            characters("1234567890"),
+            characters("@#$%&-+()"),
+            [characters("/*\"':;!?"), vec![Key::Backspace]].concat(),
+            vec![
+                Key::Symbols,
+                Key::Character(','),
+                Key::Space,
+                Key::Character('.'),
+                Key::Enter,
+            ],
+        ])
+    }
+
+    /// Returns the rows of the given [`Layer`].
+    fn rows(&self, layer: Layer) -> &[Vec<Key>] {
+        match layer {
+            Layer::Letters => &self.letters,
+            Layer::Symbols => &self.symbols,
+        }
+    }
+
+    /// Returns the amount of rows of the tallest layer, so switching layers keeps the height.
+    fn height(&self) -> usize {
+        self.letters.len().max(self.symbols.len())
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::qwerty()
+    }
+}
+
+/// Collects the given rows of keys.
+fn rows(rows: impl IntoIterator<Item = impl IntoIterator<Item = Key>>) -> Vec<Vec<Key>> {
+    rows.into_iter().map(|row| row.into_iter().collect()).collect()
+}
+
+/// Returns a key for every character of the given text.
+fn characters(text: &str) -> Vec<Key> {
+    text.chars().map(Key::Character).collect()
+}
+
+/// Rows of keys to type text by tapping them.
+pub struct OnScreenKeyboard<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    on_input: Box<dyn Fn(Input) -> Message + 'a>,
+    keymap: Keymap,
+    width: Length,
+    key_height: f32,
+    spacing: f32,
+    repeat_delay: Duration,
+    repeat_interval: Duration,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> OnScreenKeyboard<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default height of a key of an [`OnScreenKeyboard`].
+    pub const DEFAULT_KEY_HEIGHT: f32 = 48.0;
+
+    /// The default space between the keys of an [`OnScreenKeyboard`].
+    pub const DEFAULT_SPACING: f32 = 6.0;
+
+    /// The default time a key is held before it repeats.
+    pub const DEFAULT_REPEAT_DELAY: Duration = Duration::from_millis(500);
+
+    /// The default time between two repeats of a held key.
+    pub const DEFAULT_REPEAT_INTERVAL: Duration = Duration::from_millis(50);
+
+    /// Creates a new [`OnScreenKeyboard`] with the message to produce with every typed [`Input`].
+    pub fn new(on_input: impl Fn(Input) -> Message + 'a) -> Self {
+        Self {
+            on_input: Box::new(on_input),
+            keymap: Keymap::default(),
+            width: Length::Fill,
+            key_height: Self::DEFAULT_KEY_HEIGHT,
+            spacing: Self::DEFAULT_SPACING,
+            repeat_delay: Self::DEFAULT_REPEAT_DELAY,
+            repeat_interval: Self::DEFAULT_REPEAT_INTERVAL,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Keymap`] of the [`OnScreenKeyboard`].
+    ///
+    /// By default, it is [`Keymap::qwerty`].
+    pub fn keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Sets the width of the [`OnScreenKeyboard`].
+    ///
+    /// The keys grow with it, so the widest row fills it.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of a key of the [`OnScreenKeyboard`].
+    ///
+    /// Keys under 44 pixels are hard to hit with a finger.
+    pub fn key_height(mut self, key_height: impl Into<Pixels>) -> Self {
+        self.key_height = key_height.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the space between the keys of the [`OnScreenKeyboard`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0.max(0.0);
+        self
+    }
+
+    /// Sets the time a character or a backspace is held before it repeats.
+    pub fn repeat_delay(mut self, repeat_delay: Duration) -> Self {
+        self.repeat_delay = repeat_delay;
+        self
+    }
+
+    /// Sets the time between two repeats of a held character or backspace.
+    pub fn repeat_interval(mut self, repeat_interval: Duration) -> Self {
+        self.repeat_interval = repeat_interval.max(Duration::from_millis(1));
+        self
+    }
+
+    /// Sets the text size of the labels of the keys.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the labels of the keys.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`OnScreenKeyboard`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`OnScreenKeyboard`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the keys of the given [`Layer`] and their bounds, relative to the top left corner
+    /// of an [`OnScreenKeyboard`] of the given width.
+    ///
+    /// Every row is centered, and the keys are as wide as the widest row allows.
+    fn keys(&self, layer: Layer, width: f32) -> Vec<(Key, Rectangle)> {
+        let rows = self.keymap.rows(layer);
+        let unit = rows
+            .iter()
+            .filter(|row| !row.is_empty())
+            .map(|row| {
+                let units: f32 = row.iter().map(|key| key.width()).sum();
+                let gaps = (row.len() - 1) as f32 * self.spacing;
+
+                ((width - gaps) / units).max(0.0)
+            })
+            .fold(f32::INFINITY, f32::min);
+
+        let mut keys = Vec::new();
+
+        for (index, row) in rows.iter().enumerate() {
+            let row_width: f32 = row.iter().map(|key| key.width() * unit + self.spacing).sum::<f32>() - self.spacing;
+            let y = index as f32 * (self.key_height + self.spacing);
+            let mut x = (width - row_width) / 2.0;
+
+            for key in row {
+                let key_width = key.width() * unit;
+
+                keys.push((
+                    *key,
+                    Rectangle::new(Point::new(x, y), Size::new(key_width, self.key_height)),
+                ));
+
+                x += key_width + self.spacing;
+            }
+        }
+
+        keys
+    }
+
+    /// Returns the index of the key under the given position, relative to the top left corner.
+    fn key_at(&self, layer: Layer, width: f32, position: Point) -> Option<usize> {
+        self.keys(layer, width)
+            .iter()
+            .position(|(_, bounds)| bounds.contains(position))
+    }
+
+    /// Returns the label of the given [`Key`].
+    fn label(&self, state: &State, key: Key) -> String {
+        match key {
+            Key::Character(character) if state.is_shifted => character.to_uppercase().collect(),
+            Key::Character(character) => character.to_string(),
+            Key::Space => String::new(),
+            Key::Backspace => String::from("\u{232B}"),
+            Key::Enter => String::from("\u{23CE}"),
+            Key::Shift => String::from("\u{21E7}"),
+            Key::Symbols => match state.layer {
+                Layer::Letters => String::from("?123"),
+                Layer::Symbols => String::from("ABC"),
+            },
+        }
+    }
+
+    /// Types the given [`Key`], switching layers for the keys that do not produce an [`Input`].
+    fn type_key(&self, state: &mut State, key: Key, shell: &mut Shell<'_, Message>) {
+        let input = match key {
+            Key::Character(character) if state.is_shifted => {
+                state.is_shifted = false;
+
+                character.to_uppercase().next().map(Input::Character)
+            }
+            Key::Character(character) => Some(Input::Character(character)),
+            Key::Space => Some(Input::Character(' ')),
+            Key::Backspace => Some(Input::Backspace),
+            Key::Enter => Some(Input::Enter),
+            Key::Shift => {
+                state.is_shifted = !state.is_shifted;
+
+                None
+            }
+            Key::Symbols => {
+                state.layer = match state.layer {
+                    Layer::Letters => Layer::Symbols,
+                    Layer::Symbols => Layer::Letters,
+                };
+                state.is_shifted = false;
+                state.hovered = None;
+
+                None
+            }
+        };
+
+        if let Some(input) = input {
+            shell.publish((self.on_input)(input));
+        }
+    }
+}
+
+/// A set of rows of a [`Keymap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Layer {
+    #[default]
+    Letters,
+    Symbols,
+}
+
+/// A key of an [`OnScreenKeyboard`] being held, started on the first frame after it.
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    index: usize,
+    started: Option<Instant>,
+    repeats: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    layer: Layer,
+    is_shifted: bool,
+    pressed: Option<Press>,
+    hovered: Option<usize>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for OnScreenKeyboard<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let rows = self.keymap.height() as f32;
+        let height = (rows * self.key_height + (rows - 1.0) * self.spacing).max(0.0);
+
+        layout::atomic(limits, self.width, height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let index = cursor
+            .position_in(bounds)
+            .and_then(|position| self.key_at(state.layer, bounds.width, position));
+        let key_of = |state: &State, index: usize| self.keys(state.layer, bounds.width).get(index).map(|(key, _)| *key);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) = index {
+                    state.pressed = Some(Press {
+                        index,
+                        started: None,
+                        repeats: 0,
+                    });
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if state.hovered != index {
+                    state.hovered = index;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                // A key is only tapped when released over it, unless it was already repeated.
+                if let Some(press) = state.pressed.take() {
+                    if index == Some(press.index)
+                        && press.repeats == 0
+                        && let Some(key) = key_of(state, press.index)
+                    {
+                        self.type_key(state, key, shell);
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.pressed.take().is_some() {
+                    shell.request_redraw();
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let Some(mut press) = state.pressed else {
+                    return;
+                };
+
+                let Some(pressed) = key_of(state, press.index).filter(|key| key.is_repeated()) else {
+                    return;
+                };
+
+                let started = *press.started.get_or_insert(*now);
+                let held = now.saturating_duration_since(started);
+
+                // A key slid away from stops repeating until it is slid back onto.
+                if index != Some(press.index) {
+                    state.pressed = Some(press);
+                    return;
+                }
+
+                if let Some(repeating) = held.checked_sub(self.repeat_delay) {
+                    let due = (repeating.as_secs_f32() / self.repeat_interval.as_secs_f32()) as u32 + 1;
+
+                    // Late frames skip the repeats they missed instead of catching up at once.
+                    if due > press.repeats {
+                        press.repeats = due;
+                        self.type_key(state, pressed, shell);
+                    }
+                }
+
+                state.pressed = Some(press);
+                shell.request_redraw_at(window::RedrawRequest::At(
+                    started + self.repeat_delay + self.repeat_interval * press.repeats,
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let is_over_key = cursor
+            .position_in(bounds)
+            .and_then(|position| self.key_at(state.layer, bounds.width, position))
+            .is_some();
+
+        if is_over_key {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let hovered = state.hovered.filter(|_| cursor.is_over(bounds));
+        let pressed = state.pressed.map(|press| press.index);
+
+        for (index, (key, key_bounds)) in self.keys(state.layer, bounds.width).into_iter().enumerate() {
+            // A shifted keyboard keeps its shift key down until the next character.
+            let status = if pressed == Some(index) || (key == Key::Shift && state.is_shifted) {
+                Status::Pressed
+            } else if hovered == Some(index) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let style = theme.style(&self.class, status);
+            let key_bounds = key_bounds + Vector::new(bounds.x, bounds.y);
+            let (background, text_color) = match key {
+                Key::Character(_) | Key::Space => (style.key_background, style.text_color),
+                _ => (style.control_background, style.control_text_color),
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: key_bounds,
+                    border: style.key_border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+
+            renderer.fill_text(
+                Text {
+                    content: self.label(state, key),
+                    bounds: key_bounds.size(),
+                    size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                    line_height: text::LineHeight::default(),
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                key_bounds.center(),
+                text_color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<OnScreenKeyboard<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(keyboard: OnScreenKeyboard<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(keyboard)
+    }
+}
+
+/// The possible status of a key of an [`OnScreenKeyboard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The key is idle.
+    Active,
+    /// The key is hovered.
+    Hovered,
+    /// The key is being pressed, or it is the shift key of a shifted keyboard.
+    Pressed,
+}
+
+/// The appearance of a key of an [`OnScreenKeyboard`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the character keys.
+    pub key_background: Background,
+    /// The [`Border`] of the keys.
+    pub key_border: Border,
+    /// The [`Color`] of the labels of the character keys.
+    pub text_color: Color,
+    /// The [`Background`] of the keys that do not type a character, like shift.
+    pub control_background: Background,
+    /// The [`Color`] of the labels of the keys that do not type a character.
+    pub control_text_color: Color,
+}
+
+/// The theme catalog of an [`OnScreenKeyboard`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for an [`OnScreenKeyboard`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of an [`OnScreenKeyboard`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        key_background: palette.background.weak.color.into(),
+        key_border: Border {
+            radius: 6.0.into(),
+            ..Border::default()
+        },
+        text_color: palette.background.weak.text,
+        control_background: palette.background.strong.color.into(),
+        control_text_color: palette.background.strong.text,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            key_background: palette.background.strong.color.into(),
+            control_background: palette.background.strongest.color.into(),
+            control_text_color: palette.background.strongest.text,
+            ..active
+        },
+        Status::Pressed => Style {
+            key_background: palette.primary.weak.color.into(),
+            text_color: palette.primary.weak.text,
+            control_background: palette.primary.base.color.into(),
+            control_text_color: palette.primary.base.text,
+            ..active
+        },
+    }
+}
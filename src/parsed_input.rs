@@ -0,0 +1,240 @@
+//! A text input editing a value parsed from its text.
+//!
+//! The text typed in a [`ParsedInput`] is kept in its state, so it can be cleared or half
+//! typed, and the value only changes once the text parses.
+use std::str::FromStr;
+
+use iced_core::Clipboard;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Widget;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::widget::Operation;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+use iced_widget::TextInput;
+use iced_widget::text_input;
+
+/// A text input editing a value parsed from its text.
+pub(crate) struct ParsedInput<'a, T, Message> {
+    value: T,
+    placeholder: &'a str,
+    width: Length,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+}
+
+#[derive(Debug)]
+struct State<T> {
+    text: String,
+    value: T,
+}
+
+impl<'a, T, Message> ParsedInput<'a, T, Message>
+where
+    T: FromStr + ToString + PartialEq + Clone + 'static,
+{
+    /// Creates a new [`ParsedInput`] showing the given value, and the message to produce with
+    /// every text that parses.
+    pub(crate) fn new(placeholder: &'a str, value: T, on_change: impl Fn(T) -> Message + 'a) -> Self {
+        Self {
+            value,
+            placeholder,
+            width: Length::Fill,
+            on_change: Box::new(on_change),
+        }
+    }
+
+    /// Sets the width of the [`ParsedInput`].
+    pub(crate) fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    fn input<Theme, Renderer>(&self, text: &str) -> TextInput<'_, String, Theme, Renderer>
+    where
+        Theme: text_input::Catalog,
+        Renderer: text::Renderer,
+    {
+        text_input(self.placeholder, text)
+            .on_input(|input| input)
+            .width(self.width)
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ParsedInput<'_, T, Message>
+where
+    T: FromStr + ToString + PartialEq + Clone + 'static,
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            text: self.value.to_string(),
+            value: self.value.clone(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.input::<Theme, Renderer>("") as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        // A value changed by the application replaces the text being typed.
+        if state.value != self.value {
+            state.value = self.value.clone();
+            state.text = self.value.to_string();
+        }
+
+        tree.children[0].diff(&self.input::<Theme, Renderer>("") as &dyn Widget<_, _, _>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<String, Theme, Renderer>::size(&self.input::<Theme, Renderer>(""))
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let text = tree.state.downcast_ref::<State<T>>().text.clone();
+
+        // Called through the trait, as the text input has inherent methods of the same names.
+        Widget::<String, Theme, Renderer>::layout(&mut self.input(&text), &mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let text = tree.state.downcast_ref::<State<T>>().text.clone();
+        let mut inputs = Vec::new();
+        let mut local = Shell::new(&mut inputs);
+
+        Widget::update(
+            &mut self.input::<Theme, Renderer>(&text),
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local,
+            viewport,
+        );
+
+        if local.is_event_captured() {
+            shell.capture_event();
+        }
+
+        if local.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if local.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        shell.request_redraw_at(local.redraw_request());
+        shell.request_input_method(local.input_method());
+
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        for input in inputs {
+            // Text that does not parse is shown, but leaves the value unchanged.
+            if let Ok(value) = input.parse::<T>() {
+                state.value = value.clone();
+                shell.publish((self.on_change)(value));
+            }
+
+            state.text = input;
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<T>>();
+
+        Widget::<String, Theme, Renderer>::mouse_interaction(
+            &self.input(&state.text),
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<T>>();
+
+        Widget::<String, Theme, Renderer>::draw(
+            &self.input(&state.text),
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        let text = tree.state.downcast_ref::<State<T>>().text.clone();
+
+        Widget::<String, Theme, Renderer>::operate(
+            &mut self.input(&text),
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<ParsedInput<'a, T, Message>> for Element<'a, Message, Theme, Renderer>
+where
+    T: FromStr + ToString + PartialEq + Clone + 'static,
+    Message: 'a,
+    Theme: text_input::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(input: ParsedInput<'a, T, Message>) -> Self {
+        Element::new(input)
+    }
+}
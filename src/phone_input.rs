@@ -0,0 +1,759 @@
+//! Type a phone number and pick its country code.
+//!
+//! A [`PhoneInput`] pairs a searchable list of countries, drawn with their flag and dial code,
+//! with a number field only accepting digits and separators. The list reuses the [`Menu`] of a
+//! [`MultiPickList`], like a [`FontPicker`], so typing while it is open filters the countries.
+//! Picking a country keeps the typed number, while pasting a number that starts with `+`
+//! picks the country of its dial code.
+//!
+//! [`Menu`]: crate::multi_pick_list::menu::Menu
+//! [`MultiPickList`]: crate::MultiPickList
+//! [`FontPicker`]: crate::FontPicker
+use std::fmt;
+use std::rc::Rc;
+
+use iced_core::Clipboard;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Padding;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::alignment;
+use iced_core::keyboard;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::touch;
+use iced_core::widget::tree;
+use iced_core::widget::tree::Tree;
+use iced_core::window;
+use iced_widget::Row;
+use iced_widget::text_input;
+
+use crate::multi_pick_list::Catalog;
+use crate::multi_pick_list::Status;
+use crate::multi_pick_list::menu;
+
+/// The most digits a phone number has, dial code included.
+const MAX_DIGITS: usize = 15;
+
+/// The characters a number may be typed with, besides its digits.
+const SEPARATORS: [char; 5] = [' ', '-', '.', '(', ')'];
+
+/// A country a [`PhoneNumber`] is dialed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Country {
+    /// The ISO 3166-1 alpha-2 code of the country, like `"US"`.
+    pub code: &'static str,
+    /// The name of the country.
+    pub name: &'static str,
+    /// The international dial code of the country, without its `+`.
+    pub dial_code: &'static str,
+    /// The prefix dialed before a number within the country and dropped from its international
+    /// form, like the `0` of the United Kingdom.
+    ///
+    /// It is `None` where numbers keep all of their digits, like in Italy.
+    pub trunk_prefix: Option<&'static str>,
+}
+
+impl Country {
+    /// A selection of countries, in alphabetical order.
+    pub const ALL: &'static [Country] = &[
+        Country::new("AR", "Argentina", "54", Some("0")),
+        Country::new("AU", "Australia", "61", Some("0")),
+        Country::new("AT", "Austria", "43", Some("0")),
+        Country::new("BE", "Belgium", "32", Some("0")),
+        Country::new("BR", "Brazil", "55", Some("0")),
+        Country::new("CA", "Canada", "1", Some("1")),
+        Country::new("CN", "China", "86", Some("0")),
+        Country::new("DK", "Denmark", "45", None),
+        Country::new("FI", "Finland", "358", Some("0")),
+        Country::new("FR", "France", "33", Some("0")),
+        Country::new("DE", "Germany", "49", Some("0")),
+        Country::new("IN", "India", "91", Some("0")),
+        Country::new("IE", "Ireland", "353", Some("0")),
+        Country::new("IT", "Italy", "39", None),
+        Country::new("JP", "Japan", "81", Some("0")),
+        Country::new("MX", "Mexico", "52", None),
+        Country::new("NL", "Netherlands", "31", Some("0")),
+        Country::new("NZ", "New Zealand", "64", Some("0")),
+        Country::new("NO", "Norway", "47", None),
+        Country::new("PL", "Poland", "48", None),
+        Country::new("PT", "Portugal", "351", None),
+        Country::new("KR", "South Korea", "82", Some("0")),
+        Country::new("ES", "Spain", "34", None),
+        Country::new("SE", "Sweden", "46", Some("0")),
+        Country::new("CH", "Switzerland", "41", Some("0")),
+        Country::new("GB", "United Kingdom", "44", Some("0")),
+        Country::new("US", "United States", "1", Some("1")),
+    ];
+
+    /// Creates a new [`Country`] with the given code, name, dial code, and trunk prefix.
+    pub const fn new(
+        code: &'static str,
+        name: &'static str,
+        dial_code: &'static str,
+        trunk_prefix: Option<&'static str>,
+    ) -> Self {
+        Self {
+            code,
+            name,
+            dial_code,
+            trunk_prefix,
+        }
+    }
+
+    /// Returns the flag of the [`Country`], made of the regional indicators of its code.
+    pub fn flag(&self) -> String {
+        self.code
+            .chars()
+            .filter_map(|letter| {
+                char::from_u32(0x1F1E6 + (letter.to_ascii_uppercase() as u32).checked_sub('A' as u32)?)
+            })
+            .collect()
+    }
+
+    /// Returns whether the [`Country`] matches the given search, by name, code, or dial code.
+    fn matches(&self, query: &str) -> bool {
+        let query = query.trim().trim_start_matches('+').to_lowercase();
+
+        self.name.to_lowercase().contains(&query)
+            || self.code.eq_ignore_ascii_case(&query)
+            || self.dial_code.starts_with(&query)
+    }
+}
+
+impl Default for Country {
+    fn default() -> Self {
+        Country::new("US", "United States", "1", Some("1"))
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}  {}  +{}", self.flag(), self.name, self.dial_code)
+    }
+}
+
+/// A phone number, as typed in a [`PhoneInput`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PhoneNumber {
+    /// The [`Country`] the number is dialed in.
+    pub country: Country,
+    /// The number within the country, with the separators it was typed with.
+    pub number: String,
+}
+
+impl PhoneNumber {
+    /// Creates a new empty [`PhoneNumber`] dialed in the given [`Country`].
+    pub fn new(country: Country) -> Self {
+        Self {
+            country,
+            number: String::new(),
+        }
+    }
+
+    /// Returns whether no digit of the [`PhoneNumber`] has been typed yet.
+    pub fn is_empty(&self) -> bool {
+        !self.number.chars().any(|c| c.is_ascii_digit())
+    }
+
+    /// Returns the [`PhoneNumber`] in E.164 form, like `+15551234567`.
+    ///
+    /// The separators are dropped, and so is the trunk prefix of the [`Country`], once, when the
+    /// number starts with it.
+    pub fn e164(&self) -> String {
+        let digits: String = self.number.chars().filter(char::is_ascii_digit).collect();
+        let national = self
+            .country
+            .trunk_prefix
+            .and_then(|prefix| digits.strip_prefix(prefix))
+            .unwrap_or(&digits);
+
+        format!("+{}{}", self.country.dial_code, national)
+    }
+}
+
+impl fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.e164())
+    }
+}
+
+/// Keeps the digits and separators of a typed number, up to the given amount of digits.
+fn mask(input: &str, max_digits: usize) -> String {
+    let mut digits = 0;
+
+    input
+        .chars()
+        .filter(|c| {
+            if c.is_ascii_digit() {
+                digits += 1;
+                digits <= max_digits
+            } else {
+                SEPARATORS.contains(c)
+            }
+        })
+        .collect()
+}
+
+/// A country code picker followed by a number field, editing a [`PhoneNumber`].
+pub struct PhoneInput<'a, Message> {
+    phone: &'a PhoneNumber,
+    countries: &'a [Country],
+    on_change: Rc<dyn Fn(PhoneNumber) -> Message + 'a>,
+    placeholder: String,
+    width: Length,
+    spacing: f32,
+}
+
+impl<'a, Message> PhoneInput<'a, Message> {
+    /// The default spacing between the country code and the number of a [`PhoneInput`].
+    pub const DEFAULT_SPACING: f32 = 4.0;
+
+    /// Creates a new [`PhoneInput`] editing the given [`PhoneNumber`], and the message to
+    /// produce with the changed one.
+    pub fn new(phone: &'a PhoneNumber, on_change: impl Fn(PhoneNumber) -> Message + 'a) -> Self {
+        Self {
+            phone,
+            countries: Country::ALL,
+            on_change: Rc::new(on_change),
+            placeholder: String::from("Phone number"),
+            width: Length::Fixed(180.0),
+            spacing: Self::DEFAULT_SPACING,
+        }
+    }
+
+    /// Sets the countries listed by the [`PhoneInput`]. Defaults to [`Country::ALL`].
+    pub fn countries(mut self, countries: &'a [Country]) -> Self {
+        self.countries = countries;
+        self
+    }
+
+    /// Sets the placeholder of the number field, shown while it is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the number field of the [`PhoneInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the country code and the number of the [`PhoneInput`].
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<PhoneInput<'a, Message>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + text_input::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(input: PhoneInput<'a, Message>) -> Self {
+        let phone = input.phone;
+        let countries = input.countries;
+
+        let country = {
+            let change = Rc::clone(&input.on_change);
+            let current = phone.clone();
+
+            CountryPicker::new(countries, phone.country, move |country| {
+                change(PhoneNumber {
+                    country,
+                    ..current.clone()
+                })
+            })
+        };
+
+        let number = {
+            let change = Rc::clone(&input.on_change);
+            let current = phone.clone();
+
+            iced_widget::text_input(&input.placeholder, &phone.number)
+                .on_input(move |typed| {
+                    let mut country = current.country;
+                    let mut typed = typed.trim_start().to_owned();
+
+                    // A pasted international number picks its country by the longest dial code it starts with.
+                    if let Some(international) = typed.strip_prefix('+') {
+                        let digits: String = international.chars().filter(char::is_ascii_digit).collect();
+
+                        if let Some(found) = countries
+                            .iter()
+                            .filter(|country| digits.starts_with(country.dial_code))
+                            .max_by_key(|country| country.dial_code.len())
+                        {
+                            country = *found;
+                            typed = digits[found.dial_code.len()..].to_owned();
+                        }
+                    }
+
+                    change(PhoneNumber {
+                        country,
+                        number: mask(&typed, MAX_DIGITS - country.dial_code.len()),
+                    })
+                })
+                .width(input.width)
+        };
+
+        Row::new()
+            .push(country)
+            .push(number)
+            .spacing(input.spacing)
+            .align_y(alignment::Vertical::Center)
+            .into()
+    }
+}
+
+/// A field showing the flag and dial code of a [`Country`], opening a searchable list of them.
+struct CountryPicker<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    countries: &'a [Country],
+    selected: Country,
+    on_select: Box<dyn Fn(Country) -> Message + 'a>,
+    search_placeholder: String,
+    empty_text: String,
+    padding: Padding,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+    last_status: Option<Status>,
+}
+
+impl<'a, Message, Theme> CountryPicker<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The width of the field of a [`CountryPicker`].
+    const WIDTH: f32 = 96.0;
+
+    /// The narrowest the menu of a [`CountryPicker`] gets, so the names of the countries fit.
+    const MENU_WIDTH: f32 = 240.0;
+
+    /// The height of the menu of a [`CountryPicker`].
+    const MENU_HEIGHT: f32 = 240.0;
+
+    fn new(countries: &'a [Country], selected: Country, on_select: impl Fn(Country) -> Message + 'a) -> Self {
+        Self {
+            countries,
+            selected,
+            on_select: Box::new(on_select),
+            search_placeholder: String::from("Search..."),
+            empty_text: String::from("No countries"),
+            padding: iced_widget::button::DEFAULT_PADDING,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+            last_status: None,
+        }
+    }
+
+    /// Lists the countries matching the given search.
+    fn entries(&self, query: &str) -> Vec<menu::Entry> {
+        self.countries
+            .iter()
+            .enumerate()
+            .filter(|(_, country)| country.matches(query))
+            .map(|(index, _)| menu::Entry::Option(index))
+            .collect()
+    }
+
+    fn open_menu(&self, state: &mut State) {
+        state.is_open = true;
+        state.query.clear();
+        state.hovered_option = None;
+        state.entries = self.entries(&state.query);
+    }
+
+    fn close_menu(&self, state: &mut State) {
+        let _ = state.menu.take_placement();
+        state.is_open = false;
+        state.query.clear();
+        state.hovered_option = None;
+        state.placement = None;
+    }
+
+    /// Filters the countries again after the search changed.
+    fn search(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.entries = self.entries(&state.query);
+        state.hovered_option = state.entries.first().and_then(|entry| entry.option());
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+        shell.capture_event();
+    }
+}
+
+struct State {
+    menu: menu::State,
+    is_open: bool,
+    is_focused: bool,
+    query: String,
+    hovered_option: Option<usize>,
+    entries: Vec<menu::Entry>,
+    /// Where the menu opened, once it has been laid out.
+    placement: Option<menu::Placement>,
+}
+
+/// Returns the label of a country in the menu of a [`CountryPicker`].
+fn label(country: &Country) -> String {
+    country.to_string()
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for CountryPicker<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            menu: menu::State::new(),
+            is_open: false,
+            is_focused: false,
+            query: String::new(),
+            hovered_option: None,
+            entries: Vec::new(),
+            placement: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(Self::WIDTH),
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let width = Length::Fixed(Self::WIDTH);
+        let intrinsic = Size::new(
+            0.0,
+            f32::from(text::LineHeight::default().to_absolute(renderer.default_size())),
+        );
+
+        layout::Node::new(
+            limits
+                .width(width)
+                .shrink(self.padding)
+                .resolve(width, Length::Shrink, intrinsic)
+                .expand(self.padding),
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The menu is dismissed once a country is picked, so the picker closes on its next update.
+        if state.menu.take_dismissed() && state.is_open {
+            self.close_menu(state);
+            shell.request_redraw();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if state.is_open {
+                    // The press was not captured by the menu, so it happened outside of it.
+                    self.close_menu(state);
+                    state.is_focused = cursor.is_over(layout.bounds());
+                    shell.request_redraw();
+                    shell.capture_event();
+                } else if cursor.is_over(layout.bounds()) {
+                    self.open_menu(state);
+                    state.is_focused = true;
+                    shell.request_redraw();
+                    shell.capture_event();
+                } else {
+                    state.is_focused = false;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key, text, modifiers, ..
+            }) if state.is_open => match key {
+                keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                    self.close_menu(state);
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+                keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                    let country = state
+                        .hovered_option
+                        .or_else(|| state.entries.first().and_then(|entry| entry.option()))
+                        .and_then(|index| self.countries.get(index).copied());
+
+                    if let Some(country) = country {
+                        shell.publish((self.on_select)(country));
+                    }
+
+                    self.close_menu(state);
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+                keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                    if state.query.pop().is_some() {
+                        self.search(state, shell);
+                    }
+                }
+                _ => {
+                    if let Some(text) = text
+                        && !modifiers.command()
+                        && !text.chars().any(char::is_control)
+                    {
+                        state.query.push_str(text);
+                        self.search(state, shell);
+                    }
+                }
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Enter | keyboard::key::Named::Space),
+                ..
+            }) if state.is_focused => {
+                self.open_menu(state);
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Window(window::Event::Unfocused) if state.is_open => {
+                self.close_menu(state);
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+
+        if state.is_open
+            && let Some(placement) = state.menu.take_placement()
+        {
+            state.placement = Some(placement);
+        }
+
+        let status = {
+            let is_hovered = cursor.is_over(layout.bounds());
+
+            if state.is_open {
+                Status::Opened {
+                    is_hovered,
+                    is_above: state.placement == Some(menu::Placement::Above),
+                    selected_count: 1,
+                }
+            } else if is_hovered {
+                Status::Hovered { selected_count: 1 }
+            } else if state.is_focused {
+                Status::Focused { selected_count: 1 }
+            } else {
+                Status::Active { selected_count: 1 }
+            }
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_status = Some(status);
+        } else if self.last_status.is_some_and(|last_status| last_status != status) {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = Catalog::style(
+            theme,
+            &self.class,
+            self.last_status.unwrap_or(Status::Active { selected_count: 1 }),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let text_size = renderer.default_size();
+
+        renderer.fill_text(
+            text::Text {
+                content: Renderer::ARROW_DOWN_ICON.to_string(),
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                font: Renderer::ICON_FONT,
+                bounds: Size::new(
+                    bounds.width,
+                    f32::from(text::LineHeight::default().to_absolute(text_size)),
+                ),
+                align_x: text::Alignment::Right,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::default(),
+            },
+            Point::new(bounds.x + bounds.width - self.padding.right, bounds.center_y()),
+            style.handle_color,
+            *viewport,
+        );
+
+        // The field shows the search while open, and the flag and dial code of the country otherwise.
+        let (content, color) = if state.is_open {
+            if state.query.is_empty() {
+                (self.search_placeholder.clone(), style.placeholder_color)
+            } else {
+                (state.query.clone(), style.text_color)
+            }
+        } else {
+            (
+                format!("{} +{}", self.selected.flag(), self.selected.dial_code),
+                style.text_color,
+            )
+        };
+
+        renderer.fill_text(
+            text::Text {
+                content,
+                bounds: Size::new((bounds.width - self.padding.x() - text_size.0).max(0.0), bounds.height),
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                font: renderer.default_font(),
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            },
+            Point::new(bounds.x + self.padding.left, bounds.center_y()),
+            color,
+            bounds,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let menu = menu::Menu::new(
+            &mut state.menu,
+            self.countries,
+            std::slice::from_ref(&self.selected),
+            &mut state.hovered_option,
+            self.on_select.as_ref(),
+            &label,
+            &self.menu_class,
+        )
+        .mode(menu::Mode::Single)
+        .width(bounds.width.max(Self::MENU_WIDTH))
+        .padding(self.padding)
+        .text_shaping(text::Shaping::Advanced)
+        .type_ahead(false)
+        .entries(&state.entries)
+        .empty_text(&self.empty_text);
+
+        Some(menu.overlay(
+            layout.position() + translation,
+            *viewport,
+            bounds.height,
+            Length::Fixed(Self::MENU_HEIGHT),
+        ))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CountryPicker<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(country_picker: CountryPicker<'a, Message, Theme>) -> Self {
+        Self::new(country_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Country, PhoneNumber};
+
+    fn phone(code: &str, number: &str) -> PhoneNumber {
+        let country = Country::ALL
+            .iter()
+            .find(|country| country.code == code)
+            .copied()
+            .unwrap();
+
+        PhoneNumber {
+            country,
+            number: String::from(number),
+        }
+    }
+
+    #[test]
+    fn e164_keeps_the_leading_zero_of_italian_numbers() {
+        assert_eq!(phone("IT", "06 1234 5678").e164(), "+390612345678");
+    }
+
+    #[test]
+    fn e164_drops_the_trunk_prefix_of_british_numbers_once() {
+        assert_eq!(phone("GB", "020 7946 0018").e164(), "+442079460018");
+        assert_eq!(phone("GB", "(0) 20-7946-0018").e164(), "+442079460018");
+        assert_eq!(phone("GB", "0020 7946 0018").e164(), "+4402079460018");
+    }
+}
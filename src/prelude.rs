@@ -0,0 +1,57 @@
+//! The widgets of the crate, their helper functions, and the modules holding their styles.
+pub use crate::anchored_overlay;
+pub use crate::anchored_overlay::AnchoredOverlay;
+pub use crate::animated_list;
+pub use crate::animated_list::AnimatedList;
+pub use crate::bottom_sheet;
+pub use crate::bottom_sheet::BottomSheet;
+pub use crate::card_input;
+pub use crate::card_input::Card;
+pub use crate::card_input::CardInput;
+pub use crate::cropper;
+pub use crate::cropper::Cropper;
+pub use crate::debounce;
+pub use crate::debounce::Debounced;
+pub use crate::floating_panel;
+pub use crate::floating_panel::FloatingPanel;
+pub use crate::font_picker;
+pub use crate::font_picker::FontPicker;
+pub use crate::grid_picker;
+pub use crate::grid_picker::GridPicker;
+pub use crate::helpers::*;
+pub use crate::hold_button;
+pub use crate::hold_button::HoldButton;
+pub use crate::keypad;
+pub use crate::keypad::Keypad;
+pub use crate::marquee;
+pub use crate::marquee::Marquee;
+pub use crate::measurement_input;
+pub use crate::measurement_input::MeasurementInput;
+pub use crate::multi_pick_list;
+pub use crate::multi_pick_list::MultiPickList;
+pub use crate::on_screen_keyboard;
+pub use crate::on_screen_keyboard::OnScreenKeyboard;
+pub use crate::phone_input;
+pub use crate::phone_input::PhoneInput;
+pub use crate::phone_input::PhoneNumber;
+pub use crate::progress_bar_ext;
+pub use crate::progress_bar_ext::ProgressBar;
+pub use crate::qr_code;
+pub use crate::qr_code::QrCode;
+pub use crate::schedule_builder;
+pub use crate::schedule_builder::Schedule;
+pub use crate::schedule_builder::ScheduleBuilder;
+pub use crate::signature_pad;
+pub use crate::signature_pad::Signature;
+pub use crate::signature_pad::SignaturePad;
+pub use crate::slider_bank;
+pub use crate::slider_bank::SliderBank;
+pub use crate::square_radio;
+pub use crate::square_radio::SquareRadio;
+pub use crate::tree_table;
+pub use crate::tree_table::TreeTable;
+pub use crate::virtual_list;
+pub use crate::virtual_list::VirtualList;
+pub use crate::week_planner;
+pub use crate::week_planner::WeekPlan;
+pub use crate::week_planner::WeekPlanner;
@@ -23,14 +23,17 @@ use core::f32;
 use std::ops::RangeInclusive;
 
 use iced_core::Background;
+use iced_core::Clipboard;
 use iced_core::Color;
 use iced_core::Element;
+use iced_core::Event;
 use iced_core::Layout;
 use iced_core::Length;
 use iced_core::Padding;
 use iced_core::Pixels;
 use iced_core::Point;
 use iced_core::Rectangle;
+use iced_core::Shell;
 use iced_core::Size;
 use iced_core::Text;
 use iced_core::Theme;
@@ -42,7 +45,13 @@ use iced_core::layout;
 use iced_core::mouse;
 use iced_core::renderer;
 use iced_core::text;
+use iced_core::text::Paragraph;
+use iced_core::theme::palette;
+use iced_core::time::Duration;
+use iced_core::time::Instant;
 use iced_core::widget::Tree;
+use iced_core::widget::tree;
+use iced_core::window;
 use iced_core::{self};
 
 /// A bar that displays progress.
@@ -66,26 +75,47 @@ use iced_core::{self};
 ///     progress_bar(0.0..=100.0, state.progress).into()
 /// }
 /// ```
-pub struct ProgressBar<'a, Theme, Renderer>
+pub struct ProgressBar<'a, Message, Theme, Renderer>
 where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
     range: RangeInclusive<f32>,
     value: f32,
+    secondary_value: Option<f32>,
+    segments: Vec<(f32, Background)>,
+    legend: Option<Box<dyn Fn(usize, f32) -> String + 'a>>,
+    is_striped: bool,
+    is_pulsing: bool,
+    is_edge_rounded: bool,
+    steps: Option<u32>,
+    step_gap: f32,
+    step_labels: Vec<String>,
+    ticks: u32,
+    captions: Option<(String, String)>,
+    countdown: Option<(Duration, bool)>,
+    started_at: Option<Instant>,
+    on_complete: Option<Message>,
+    is_paused: bool,
+    is_errored: bool,
     length: Length,
     girth: Length,
     is_vertical: bool,
+    is_reversed: bool,
+    scale: Scale,
     show_percentage: bool,
+    text_position: TextPosition,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     padding: Padding,
     alignment: alignment::Horizontal,
+    text_alignment_vertical: alignment::Vertical,
     font: Option<Renderer::Font>,
     class: Theme::Class<'a>,
+    thresholds: Vec<(f32, StyleFn<'a, Theme>)>,
 }
 
-impl<'a, Theme, Renderer> ProgressBar<'a, Theme, Renderer>
+impl<'a, Message, Theme, Renderer> ProgressBar<'a, Message, Theme, Renderer>
 where
     Theme: Catalog,
     Renderer: text::Renderer,
@@ -93,6 +123,9 @@ where
     /// The default girth of a [`ProgressBar`].
     pub const DEFAULT_GIRTH: f32 = 30.0;
 
+    /// The default gap between the steps of a [`ProgressBar`] split into steps.
+    pub const DEFAULT_STEP_GAP: f32 = 4.0;
+
     /// Creates a new [`ProgressBar`].
     ///
     /// It expects:
@@ -102,19 +135,188 @@ where
         ProgressBar {
             value: value.clamp(*range.start(), *range.end()),
             range,
+            secondary_value: None,
+            segments: Vec::new(),
+            legend: None,
+            is_striped: false,
+            is_pulsing: false,
+            is_edge_rounded: true,
+            steps: None,
+            step_gap: Self::DEFAULT_STEP_GAP,
+            step_labels: Vec::new(),
+            ticks: 0,
+            captions: None,
+            countdown: None,
+            started_at: None,
+            on_complete: None,
+            is_paused: false,
+            is_errored: false,
             length: Length::Fill,
             girth: Length::from(Self::DEFAULT_GIRTH),
             is_vertical: false,
+            is_reversed: false,
+            scale: Scale::Linear,
             show_percentage: true,
+            text_position: TextPosition::Inside,
             text_size: None,
             text_line_height: text::LineHeight::default(),
             padding: Padding::ZERO,
             alignment: alignment::Horizontal::Left,
+            text_alignment_vertical: alignment::Vertical::Center,
             font: None,
             class: Theme::default(),
+            thresholds: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`ProgressBar`] stacking the given segments, like the passed, failed and
+    /// skipped tests of a run.
+    ///
+    /// Every segment is an amount of the range and the [`Background`] it is drawn with. The
+    /// segments follow each other from the start of the range, and the value of the
+    /// [`ProgressBar`] is their total. The percentage is hidden unless shown again.
+    pub fn stacked(range: RangeInclusive<f32>, segments: Vec<(f32, Background)>) -> Self {
+        let total = segments.iter().map(|(amount, _)| amount.max(0.0)).sum::<f32>();
+
+        ProgressBar {
+            segments,
+            show_percentage: false,
+            ..Self::new(range.clone(), range.start() + total)
         }
     }
 
+    /// Sets the closure producing the legend of every segment of a stacked [`ProgressBar`],
+    /// from its index and its amount.
+    ///
+    /// The legend is centered in its segment, and clipped to it.
+    pub fn legend(mut self, legend: impl Fn(usize, f32) -> String + 'a) -> Self {
+        self.legend = Some(Box::new(legend));
+        self
+    }
+
+    /// Sets a second value of the [`ProgressBar`], like the buffered part of a download, drawn
+    /// behind the current value with the secondary bar of the [`Style`].
+    pub fn secondary_value(mut self, secondary_value: f32) -> Self {
+        self.secondary_value = Some(secondary_value.clamp(*self.range.start(), *self.range.end()));
+        self
+    }
+
+    /// Sets whether diagonal stripes move along the filled part of the [`ProgressBar`], to show
+    /// it is busy even while the value does not change.
+    pub fn striped(mut self, is_striped: bool) -> Self {
+        self.is_striped = is_striped;
+        self
+    }
+
+    /// Sets whether the filled part of the [`ProgressBar`] pulses, fading in and out.
+    pub fn pulse(mut self, is_pulsing: bool) -> Self {
+        self.is_pulsing = is_pulsing;
+        self
+    }
+
+    /// Sets whether the moving edge of the fill of the [`ProgressBar`] is rounded like the ends
+    /// of its track.
+    ///
+    /// Otherwise, the edge is square. By default, it is rounded.
+    pub fn rounded_edge(mut self, is_edge_rounded: bool) -> Self {
+        self.is_edge_rounded = is_edge_rounded;
+        self
+    }
+
+    /// Splits the [`ProgressBar`] into the given amount of steps, like the pages of a wizard,
+    /// filling a whole step once the value reaches its end.
+    ///
+    /// The percentage is not shown in this mode.
+    pub fn steps(mut self, steps: u32) -> Self {
+        self.steps = Some(steps.max(1));
+        self
+    }
+
+    /// Sets the gap between the steps of a [`ProgressBar`] split into [`steps`](Self::steps).
+    ///
+    /// By default, it is [`DEFAULT_STEP_GAP`](Self::DEFAULT_STEP_GAP).
+    pub fn step_gap(mut self, step_gap: impl Into<Pixels>) -> Self {
+        self.step_gap = step_gap.into().0;
+        self
+    }
+
+    /// Sets the labels shown under the steps of a horizontal [`ProgressBar`] split into
+    /// [`steps`](Self::steps), in order.
+    pub fn step_labels(mut self, step_labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.step_labels = step_labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Splits the track of the [`ProgressBar`] into the given amount of intervals, with a tick
+    /// mark between every two of them.
+    pub fn ticks(mut self, ticks: u32) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets the captions of the start and the end of the range, like `"0"` and `"100 GB"`.
+    ///
+    /// They are shown under a horizontal [`ProgressBar`], at its ends, and below and above a
+    /// vertical one.
+    pub fn captions(mut self, start: impl Into<String>, end: impl Into<String>) -> Self {
+        self.captions = Some((start.into(), end.into()));
+        self
+    }
+
+    /// Turns the [`ProgressBar`] into a countdown, emptying by itself from the end of the range to
+    /// its start over the given [`Duration`], like the time left of a toast.
+    ///
+    /// The countdown starts when the [`ProgressBar`] is first drawn, unless
+    /// [`started_at`](Self::started_at) says otherwise, and its value is ignored. It starts over
+    /// whenever its [`Duration`] changes. The percentage is hidden unless shown again.
+    pub fn countdown(mut self, duration: Duration) -> Self {
+        self.countdown = Some((duration, false));
+        self.show_percentage = false;
+        self
+    }
+
+    /// Turns the [`ProgressBar`] into a timer, filling by itself from the start of the range to
+    /// its end over the given [`Duration`].
+    ///
+    /// It works like a [`countdown`](Self::countdown) going the other way.
+    pub fn timer(mut self, duration: Duration) -> Self {
+        self.countdown = Some((duration, true));
+        self.show_percentage = false;
+        self
+    }
+
+    /// Sets when a [`countdown`](Self::countdown) or a [`timer`](Self::timer) started, like when
+    /// its toast was shown.
+    ///
+    /// Giving a new start restarts it, even after it is over.
+    pub fn started_at(mut self, started_at: Instant) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    /// Sets the message produced once a [`countdown`](Self::countdown) or a
+    /// [`timer`](Self::timer) is over.
+    pub fn on_complete(mut self, on_complete: Message) -> Self {
+        self.on_complete = Some(on_complete);
+        self
+    }
+
+    /// Sets whether the [`ProgressBar`] is paused, like a download on hold, drawn with the
+    /// [`Status::Paused`] style.
+    pub fn paused(mut self, is_paused: bool) -> Self {
+        self.is_paused = is_paused;
+        self
+    }
+
+    /// Sets whether the operation of the [`ProgressBar`] failed, drawn with the
+    /// [`Status::Errored`] style.
+    ///
+    /// It takes precedence over [`paused`](Self::paused).
+    pub fn errored(mut self, is_errored: bool) -> Self {
+        self.is_errored = is_errored;
+        self
+    }
+
     /// Sets the width of the [`ProgressBar`].
     pub fn length(mut self, length: impl Into<Length>) -> Self {
         self.length = length.into();
@@ -135,9 +337,27 @@ where
         self
     }
 
+    /// Sets whether the [`ProgressBar`] fills from the right, or from the top when vertical, like
+    /// in right-to-left layouts or for the time left of a countdown.
+    ///
+    /// By default, a [`ProgressBar`] fills from the left, or from the bottom when vertical.
+    pub fn reversed(mut self, is_reversed: bool) -> Self {
+        self.is_reversed = is_reversed;
+        self
+    }
+
+    /// Sets the [`Scale`] mapping the value of the [`ProgressBar`] to the length of its fill, like
+    /// [`Scale::Log`] for file sizes or latencies spanning orders of magnitude.
+    ///
+    /// By default, the scale is [`Scale::Linear`].
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
     /// Sets the style of the [`ProgressBar`].
     #[must_use]
-    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
     where
         Theme::Class<'a>: From<StyleFn<'a, Theme>>,
     {
@@ -152,6 +372,18 @@ where
         self
     }
 
+    /// Sets the styles the [`ProgressBar`] switches to once its value reaches their limit, like
+    /// [`danger`] past 90% of a disk.
+    ///
+    /// The style of the highest limit reached is used, and the style of the [`ProgressBar`]
+    /// below all of them.
+    #[must_use]
+    pub fn thresholds(mut self, thresholds: Vec<(f32, StyleFn<'a, Theme>)>) -> Self {
+        self.thresholds = thresholds;
+        self.thresholds.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        self
+    }
+
     /// Sets the text size of the [`ProgressBar`] current value.
     #[must_use]
     pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
@@ -182,6 +414,16 @@ where
         self
     }
 
+    /// Sets the vertical alignment of the value within the [`ProgressBar`], like the top of a
+    /// [`vertical`](Self::vertical) one, where it stays clear of the fill rising from the bottom.
+    ///
+    /// By default, the value is [`Vertical::Center`](alignment::Vertical::Center).
+    #[must_use]
+    pub fn text_alignment_vertical(mut self, alignment: alignment::Vertical) -> Self {
+        self.text_alignment_vertical = alignment;
+        self
+    }
+
     /// Show the current percentage of the [`ProgressBar`].
     ///
     /// By default, the percentage is shown.
@@ -191,6 +433,17 @@ where
         self
     }
 
+    /// Sets where the percentage is shown, like [`TextPosition::Trailing`] for a bar too thin to
+    /// hold it.
+    ///
+    /// Outside of the bar, the percentage takes room of its own next to it. By default, it is
+    /// [`TextPosition::Inside`].
+    #[must_use]
+    pub fn text_position(mut self, text_position: TextPosition) -> Self {
+        self.text_position = text_position;
+        self
+    }
+
     /// Set the font of the [`ProgressBar`].
     #[must_use]
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
@@ -205,13 +458,561 @@ where
     fn height(&self) -> Length {
         if self.is_vertical { self.length } else { self.girth }
     }
+
+    /// Returns the bounds filled up to the given value, within the given bounds of the bar.
+    fn filled(&self, bounds: Rectangle, value: f32) -> Rectangle {
+        self.span(bounds, *self.range.start(), value)
+    }
+
+    /// Returns the bounds filled between the given values, within the given bounds of the bar.
+    fn span(&self, bounds: Rectangle, from: f32, to: f32) -> Rectangle {
+        let length = if self.is_vertical { bounds.height } else { bounds.width };
+        let offset = |value: f32| length * self.scale.fraction(&self.range, value);
+        let (start, end) = (offset(from), offset(to));
+
+        match (self.is_vertical, self.is_reversed) {
+            (true, false) => Rectangle {
+                y: bounds.y + bounds.height - end,
+                height: end - start,
+                ..bounds
+            },
+            (true, true) => Rectangle {
+                y: bounds.y + start,
+                height: end - start,
+                ..bounds
+            },
+            (false, false) => Rectangle {
+                x: bounds.x + start,
+                width: end - start,
+                ..bounds
+            },
+            (false, true) => Rectangle {
+                x: bounds.x + bounds.width - end,
+                width: end - start,
+                ..bounds
+            },
+        }
+    }
+
+    /// Fills the given part of the bounds of the bar with the given [`Background`], inside the
+    /// border of the track.
+    ///
+    /// A part with a rounded moving edge is a quad of its own, rounded like the track as far as
+    /// its size allows. Otherwise, the whole track is filled and clipped to the part, so the part
+    /// follows the rounded ends of the track exactly and a gradient stays in place as the value
+    /// changes.
+    fn fill_part(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        part: Rectangle,
+        border: Border,
+        is_edge_rounded: bool,
+        background: Background,
+    ) {
+        let track = bounds.shrink(border.width);
+        let Some(part) = part.intersection(&track) else {
+            return;
+        };
+
+        let inset = |radius: f32| (radius - border.width).max(0.0);
+        let radius = border::Radius {
+            top_left: inset(border.radius.top_left),
+            top_right: inset(border.radius.top_right),
+            bottom_right: inset(border.radius.bottom_right),
+            bottom_left: inset(border.radius.bottom_left),
+        };
+
+        if is_edge_rounded && !matches!(background, Background::Gradient(_)) {
+            // Corners larger than half the part would make a short fill bulge out of the track.
+            let limit = part.width.min(part.height) / 2.0;
+            let radius = border::Radius {
+                top_left: radius.top_left.min(limit),
+                top_right: radius.top_right.min(limit),
+                bottom_right: radius.bottom_right.min(limit),
+                bottom_left: radius.bottom_left.min(limit),
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: part,
+                    border: border::rounded(radius),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        } else {
+            renderer.with_layer(part, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: track,
+                        border: border::rounded(radius),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            });
+        }
+    }
+
+    /// Returns the value shown by the [`ProgressBar`], moved along by its countdown if any.
+    fn current_value(&self, state: &State) -> f32 {
+        let Some((duration, fills)) = self.countdown else {
+            return self.value;
+        };
+
+        let (range_start, range_end) = self.range.clone().into_inner();
+        let progress = if duration.is_zero() {
+            1.0
+        } else {
+            (state.countdown_elapsed().as_secs_f32() / duration.as_secs_f32()).min(1.0)
+        };
+        let progress = if fills { progress } else { 1.0 - progress };
+
+        range_start + (range_end - range_start) * progress
+    }
+
+    /// Returns the [`Status`] of the [`ProgressBar`] showing the given value.
+    fn status(&self, value: f32, is_hovered: bool) -> Status {
+        if self.is_errored {
+            Status::Errored
+        } else if self.is_paused {
+            Status::Paused
+        } else if value >= *self.range.end() {
+            Status::Completed
+        } else if is_hovered {
+            Status::Hovered
+        } else {
+            Status::Normal
+        }
+    }
+
+    /// Returns the [`Style`] of the [`ProgressBar`] with the given status, from the highest
+    /// threshold the given value reached.
+    fn current_style(&self, theme: &Theme, value: f32, status: Status) -> Style {
+        match self.thresholds.iter().rfind(|(limit, _)| value >= *limit) {
+            Some((_, style)) => style(theme, status),
+            None => theme.style(&self.class, status),
+        }
+    }
+
+    /// Returns whether labels are shown under the steps of the [`ProgressBar`], and the height
+    /// of a line of their text.
+    fn step_label_height(&self, renderer: &Renderer) -> Option<f32> {
+        if self.steps.is_none() || self.step_labels.is_empty() || self.is_vertical {
+            return None;
+        }
+
+        Some(self.line_height(renderer))
+    }
+
+    /// Returns the height of a line of the text of the [`ProgressBar`].
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.text_line_height.to_absolute(text_size))
+    }
+
+    /// Returns where the percentage is shown outside of the bar, if it is.
+    fn outer_text_position(&self) -> Option<TextPosition> {
+        let is_shown = self.show_percentage && self.steps.is_none();
+
+        (is_shown && self.text_position != TextPosition::Inside).then_some(self.text_position)
+    }
+
+    /// Returns the width of the percentage showing the given value.
+    fn percentage_width(&self, value: f32, renderer: &Renderer) -> f32 {
+        let content = format!("{}%", value);
+
+        Renderer::Paragraph::with_text(Text {
+            content: content.as_str(),
+            bounds: Size::INFINITE,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.text_line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        })
+        .min_width()
+    }
+
+    /// Returns the space kept after the bar for a trailing percentage.
+    ///
+    /// It fits the percentage of the current value and of the end of the range, so the bar does
+    /// not shrink as the value grows.
+    fn trailing(&self, renderer: &Renderer) -> f32 {
+        if self.outer_text_position() != Some(TextPosition::Trailing) {
+            return 0.0;
+        }
+
+        let width = self
+            .percentage_width(self.value, renderer)
+            .max(self.percentage_width(*self.range.end(), renderer));
+
+        width + STEP_LABEL_SPACING
+    }
+
+    /// Returns the space kept above and below the bar for its captions, the labels of its steps,
+    /// and a percentage shown above or below it.
+    fn reserved(&self, renderer: &Renderer) -> (f32, f32) {
+        let step_labels = self
+            .step_label_height(renderer)
+            .map_or(0.0, |height| height + STEP_LABEL_SPACING);
+        let caption = if self.captions.is_some() {
+            self.line_height(renderer) + STEP_LABEL_SPACING
+        } else {
+            0.0
+        };
+
+        let (above, below) = if self.is_vertical {
+            (caption, step_labels + caption)
+        } else {
+            (0.0, step_labels + caption)
+        };
+
+        let text = self.line_height(renderer) + STEP_LABEL_SPACING;
+
+        match self.outer_text_position() {
+            Some(TextPosition::Above) => (above + text, below),
+            Some(TextPosition::Below) => (above, below + text),
+            _ => (above, below),
+        }
+    }
+
+    /// Returns the bounds of the bar itself, out of the given bounds of the [`ProgressBar`].
+    fn bar_bounds(&self, bounds: Rectangle, renderer: &Renderer) -> Rectangle {
+        let (above, below) = self.reserved(renderer);
+        let trailing = self.trailing(renderer);
+
+        Rectangle {
+            y: bounds.y + above,
+            width: (bounds.width - trailing).max(0.0),
+            height: (bounds.height - above - below).max(0.0),
+            ..bounds
+        }
+    }
+
+    /// Draws the tick marks between the intervals of the track of the given bar.
+    fn draw_ticks(&self, renderer: &mut Renderer, bar: Rectangle, color: Color) {
+        for tick in 1..self.ticks {
+            let offset = tick as f32 / self.ticks as f32;
+
+            let bounds = if self.is_vertical {
+                Rectangle {
+                    y: (bar.y + bar.height * (1.0 - offset) - TICK_WIDTH / 2.0).round(),
+                    height: TICK_WIDTH,
+                    ..bar
+                }
+            } else {
+                Rectangle {
+                    x: (bar.x + bar.width * offset - TICK_WIDTH / 2.0).round(),
+                    width: TICK_WIDTH,
+                    ..bar
+                }
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+
+    /// Draws the captions of the start and the end of the range around the given bar.
+    fn draw_captions(&self, renderer: &mut Renderer, bar: Rectangle, color: Color, viewport: &Rectangle) {
+        let Some((start, end)) = &self.captions else {
+            return;
+        };
+
+        let line_height = self.line_height(renderer);
+        let step_labels = self
+            .step_label_height(renderer)
+            .map_or(0.0, |height| height + STEP_LABEL_SPACING);
+        let below = bar.y + bar.height + step_labels + STEP_LABEL_SPACING;
+
+        // The start of the range is where the fill starts from.
+        let (start, end) = if self.is_reversed { (end, start) } else { (start, end) };
+
+        let captions = if self.is_vertical {
+            [
+                (start, Point::new(bar.center_x(), below), text::Alignment::Center),
+                (
+                    end,
+                    Point::new(bar.center_x(), bar.y - STEP_LABEL_SPACING - line_height),
+                    text::Alignment::Center,
+                ),
+            ]
+        } else {
+            [
+                (start, Point::new(bar.x, below), text::Alignment::Left),
+                (end, Point::new(bar.x + bar.width, below), text::Alignment::Right),
+            ]
+        };
+
+        for (content, position, align_x) in captions {
+            renderer.fill_text(
+                Text {
+                    content: content.clone(),
+                    bounds: Size::new(f32::INFINITY, line_height),
+                    size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                    line_height: self.text_line_height,
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    align_x,
+                    align_y: alignment::Vertical::Top,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                position,
+                color,
+                *viewport,
+            );
+        }
+    }
+
+    /// Draws the given bar split into the given amount of steps, filled up to the given fraction
+    /// of the range, and their labels.
+    fn draw_steps(
+        &self,
+        renderer: &mut Renderer,
+        bar: Rectangle,
+        steps: u32,
+        style: &Style,
+        progress: f32,
+        viewport: &Rectangle,
+    ) {
+        let label_height = self.step_label_height(renderer);
+        let count = steps as f32;
+        let length = if self.is_vertical { bar.height } else { bar.width };
+        let step_length = ((length - self.step_gap * (count - 1.0)) / count).max(0.0);
+
+        // The tolerance keeps a step from staying empty when its end is not exactly representable.
+        let filled = (progress * count + 1e-4).floor() as u32;
+
+        for step in 0..steps {
+            let offset = step as f32 * (step_length + self.step_gap);
+            let offset = if self.is_reversed {
+                length - step_length - offset
+            } else {
+                offset
+            };
+            let step_bounds = if self.is_vertical {
+                Rectangle {
+                    y: bar.y + bar.height - offset - step_length,
+                    height: step_length,
+                    ..bar
+                }
+            } else {
+                Rectangle {
+                    x: bar.x + offset,
+                    width: step_length,
+                    ..bar
+                }
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: step_bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                if step < filled { style.bar } else { style.background },
+            );
+
+            if let Some(label_height) = label_height
+                && let Some(label) = self.step_labels.get(step as usize)
+            {
+                renderer.fill_text(
+                    Text {
+                        content: label.clone(),
+                        bounds: Size::new(step_length, label_height),
+                        size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                        line_height: self.text_line_height,
+                        font: self.font.unwrap_or_else(|| renderer.default_font()),
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Top,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                    },
+                    Point::new(step_bounds.center_x(), bar.y + bar.height + STEP_LABEL_SPACING),
+                    style.color,
+                    *viewport,
+                );
+            }
+        }
+    }
+
+    /// Returns whether the [`ProgressBar`] animates its filled part.
+    fn is_animated(&self) -> bool {
+        self.is_striped || self.is_pulsing
+    }
+
+    /// Draws the stripes of a striped [`ProgressBar`] over its filled part, moved by the given
+    /// phase.
+    fn draw_stripes(&self, renderer: &mut Renderer, filled: Rectangle, phase: f32, color: Color) {
+        renderer.with_layer(filled, |renderer| {
+            let mut y = filled.y;
+
+            // Quads cannot be rotated, so every stripe is a staircase of thin slices, each one
+            // shifted by its offset to slant the stripes by 45 degrees.
+            while y < filled.y + filled.height {
+                let height = STRIPE_STEP.min(filled.y + filled.height - y);
+                let shift = (phase + y - filled.y) % STRIPE_PERIOD;
+                let mut x = filled.x - STRIPE_PERIOD + shift;
+
+                while x < filled.x + filled.width {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x,
+                                y,
+                                width: STRIPE_WIDTH,
+                                height,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        color,
+                    );
+
+                    x += STRIPE_PERIOD;
+                }
+
+                y += STRIPE_STEP;
+            }
+        });
+    }
 }
 
-impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ProgressBar<'_, Theme, Renderer>
+/// The width of a stripe of a striped [`ProgressBar`].
+const STRIPE_WIDTH: f32 = 8.0;
+
+/// The distance between the starts of two stripes of a striped [`ProgressBar`].
+const STRIPE_PERIOD: f32 = 16.0;
+
+/// The height of the slices a stripe is made of.
+const STRIPE_STEP: f32 = 2.0;
+
+/// The space between the bar of a [`ProgressBar`] and the text around it.
+const STEP_LABEL_SPACING: f32 = 4.0;
+
+/// How the value of a [`ProgressBar`] maps to the length of its fill.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Scale {
+    /// The fill grows by as much for every unit of the range.
+    #[default]
+    Linear,
+    /// The fill grows with the logarithm of the distance from the start of the range, so small
+    /// values stay visible when the range spans orders of magnitude.
+    ///
+    /// The distance is taken as a part of the range, so the curve is the same whatever its units:
+    /// a thousandth of the range fills a tenth of the bar, a hundredth about a third, and a tenth
+    /// about two thirds.
+    Log,
+    /// The fill grows linearly with the given function of the value, which must be increasing
+    /// over the range.
+    Custom(fn(f32) -> f32),
+}
+
+impl Scale {
+    /// Returns the part of the given range filled up to the given value, from `0.0` to `1.0`.
+    fn fraction(self, range: &RangeInclusive<f32>, value: f32) -> f32 {
+        let (start, end) = range.clone().into_inner();
+
+        if start >= end {
+            return 0.0;
+        }
+
+        let map = |value: f32| match self {
+            Scale::Linear => value - start,
+            Scale::Log => ((value - start) / (end - start) * LOG_SPAN).max(0.0).ln_1p(),
+            Scale::Custom(map) => map(value) - map(start),
+        };
+        let total = map(end);
+
+        if total > 0.0 {
+            (map(value) / total).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// The ratio between the range of a [`Scale::Log`] and the distance from its start filling a
+/// tenth of the bar.
+const LOG_SPAN: f32 = 1000.0;
+
+/// Where the percentage of a [`ProgressBar`] is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextPosition {
+    /// The percentage is over the bar.
+    #[default]
+    Inside,
+    /// The percentage is above the bar.
+    Above,
+    /// The percentage is below the bar.
+    Below,
+    /// The percentage is after the end of the bar, on its right.
+    Trailing,
+}
+
+/// The width of a tick mark of a [`ProgressBar`].
+const TICK_WIDTH: f32 = 1.0;
+
+/// How fast the stripes of a striped [`ProgressBar`] move, in pixels per second.
+const STRIPE_SPEED: f32 = 24.0;
+
+/// How long a pulsing [`ProgressBar`] takes to fade out and back in.
+const PULSE_PERIOD: Duration = Duration::from_millis(1500);
+
+/// The opacity of a pulsing [`ProgressBar`] when it is faded out the most.
+const PULSE_OPACITY: f32 = 0.4;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// When the animation started, and the time of the last frame.
+    animation: Option<(Instant, Instant)>,
+    /// The duration, direction and start of the countdown the state is kept for.
+    countdown_of: Option<(Duration, bool, Option<Instant>)>,
+    /// When the countdown started, and the time of the last frame.
+    countdown: Option<(Instant, Instant)>,
+    /// Whether the countdown is over.
+    is_complete: bool,
+    /// Whether the cursor is over the progress bar.
+    is_hovered: bool,
+}
+
+impl State {
+    /// Returns the seconds elapsed since the animation started.
+    fn elapsed(&self) -> f32 {
+        self.animation.map_or(0.0, |(started, now)| {
+            now.saturating_duration_since(started).as_secs_f32()
+        })
+    }
+
+    /// Returns the time elapsed since the countdown started.
+    fn countdown_elapsed(&self) -> Duration {
+        self.countdown
+            .map_or(Duration::ZERO, |(started, now)| now.saturating_duration_since(started))
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ProgressBar<'_, Message, Theme, Renderer>
 where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width(),
@@ -219,30 +1020,125 @@ where
         }
     }
 
-    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
-        layout::atomic(limits, self.width(), self.height())
+    fn layout(&mut self, _tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        // The captions, the labels of the steps and an outer percentage are laid out around the bar,
+        // which shrinks to leave them room within the limits.
+        let (above, below) = self.reserved(renderer);
+        let trailing = self.trailing(renderer);
+
+        let node = layout::atomic(
+            &limits.shrink(Size::new(trailing, above + below)),
+            self.width(),
+            self.height(),
+        );
+        let size = node.size();
+
+        layout::Node::new(Size::new(size.width + trailing, size.height + above + below))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let countdown_of = self
+            .countdown
+            .map(|(duration, fills)| (duration, fills, self.started_at));
+
+        // Another countdown in the same place starts over, even if the previous one is over.
+        if countdown_of != state.countdown_of {
+            state.countdown_of = countdown_of;
+            state.countdown = None;
+            state.is_complete = false;
+            shell.request_redraw();
+        }
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. }) = event {
+            let is_hovered = cursor.is_over(layout.bounds());
+
+            if is_hovered != state.is_hovered {
+                state.is_hovered = is_hovered;
+                shell.request_redraw();
+            }
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if let Some((duration, _)) = self.countdown
+                && !state.is_complete
+            {
+                let started = self
+                    .started_at
+                    .or(state.countdown.map(|(started, _)| started))
+                    .unwrap_or(*now);
+
+                state.countdown = Some((started, *now));
+
+                if state.countdown_elapsed() >= duration {
+                    state.is_complete = true;
+
+                    if let Some(on_complete) = self.on_complete.take() {
+                        shell.publish(on_complete);
+                    }
+                } else {
+                    shell.request_redraw();
+                }
+            }
+
+            if self.is_animated() {
+                let started = state.animation.map_or(*now, |(started, _)| started);
+
+                state.animation = Some((started, *now));
+                shell.request_redraw();
+            } else {
+                state.animation = None;
+            }
+        }
     }
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _defaults: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let bounds = layout.bounds();
-        let (range_start, range_end) = self.range.clone().into_inner();
-        let length = if self.is_vertical { bounds.height } else { bounds.width };
-        let active_progress_length = if range_start >= range_end {
-            0.0
+        let bounds = self.bar_bounds(layout.bounds(), renderer);
+        let state = tree.state.downcast_ref::<State>();
+        let value = self.current_value(state);
+        let status = self.status(value, state.is_hovered && cursor.is_over(layout.bounds()));
+        let style = self.current_style(theme, value, status);
+        let elapsed = state.elapsed();
+
+        // The filled part fades out and back in once per period, starting fully opaque.
+        let opacity = if self.is_pulsing {
+            let wave = (elapsed / PULSE_PERIOD.as_secs_f32() * std::f32::consts::TAU).cos();
+
+            PULSE_OPACITY + (1.0 - PULSE_OPACITY) * (wave + 1.0) / 2.0
         } else {
-            length * (self.value - range_start) / (range_end - range_start)
+            1.0
         };
 
-        let style = theme.style(&self.class);
+        if let Some(steps) = self.steps {
+            let progress = self.scale.fraction(&self.range, value);
+            let style = Style {
+                bar: style.bar.scale_alpha(opacity),
+                ..style
+            };
+
+            self.draw_steps(renderer, bounds, steps, &style, progress, viewport);
+            self.draw_captions(renderer, bounds, style.caption, viewport);
+            return;
+        }
 
         renderer.fill_quad(
             renderer::Quad {
@@ -253,70 +1149,307 @@ where
             style.background,
         );
 
-        if active_progress_length > 0.0 {
-            let bounds = if self.is_vertical {
-                Rectangle {
-                    y: bounds.y + bounds.height - active_progress_length,
-                    height: active_progress_length,
-                    ..bounds
-                }
-            } else {
-                Rectangle {
-                    width: active_progress_length,
-                    ..bounds
-                }
-            };
+        let bars = [
+            self.secondary_value.map(|value| (value, style.secondary_bar)),
+            self.segments
+                .is_empty()
+                .then_some((value, style.bar.scale_alpha(opacity))),
+        ];
 
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds,
-                    border: Border {
-                        color: Color::TRANSPARENT,
-                        ..style.border
+        // The secondary bar is drawn first, so the current value covers it where they overlap.
+        for (value, bar) in bars.into_iter().flatten() {
+            let filled = self.filled(bounds, value);
+
+            if filled.width <= 0.0 || filled.height <= 0.0 {
+                continue;
+            }
+
+            self.fill_part(renderer, bounds, filled, style.border, self.is_edge_rounded, bar);
+        }
+
+        let mut start = *self.range.start();
+
+        for (index, (amount, background)) in self.segments.iter().enumerate() {
+            let end = (start + amount.max(0.0)).min(*self.range.end());
+            let segment = self.span(bounds, start, end);
+
+            start = end;
+
+            if segment.width <= 0.0 || segment.height <= 0.0 {
+                continue;
+            }
+
+            // The segments meet with square edges, and only follow the rounded ends of the track.
+            let background = background.scale_alpha(opacity);
+
+            self.fill_part(renderer, bounds, segment, style.border, false, background);
+
+            if let Some(legend) = &self.legend {
+                renderer.fill_text(
+                    Text {
+                        content: legend(index, *amount),
+                        bounds: segment.size(),
+                        size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                        line_height: self.text_line_height,
+                        font: self.font.unwrap_or_else(|| renderer.default_font()),
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
                     },
-                    ..renderer::Quad::default()
-                },
-                style.bar,
-            );
+                    segment.center(),
+                    style.color,
+                    segment.intersection(viewport).unwrap_or(segment),
+                );
+            }
+        }
+
+        if self.is_striped {
+            let filled = self.filled(bounds, value);
+
+            if filled.width > 0.0 && filled.height > 0.0 {
+                self.draw_stripes(renderer, filled, elapsed * STRIPE_SPEED, style.stripes);
+            }
         }
 
+        self.draw_ticks(renderer, bounds, style.tick);
+
         if self.show_percentage {
             let (x, align_x) = match self.alignment {
                 alignment::Horizontal::Left => (bounds.x + self.padding.left, text::Alignment::Left),
                 alignment::Horizontal::Center => (bounds.x + (bounds.width / 2.0), text::Alignment::Center),
                 alignment::Horizontal::Right => (bounds.x + bounds.width - self.padding.right, text::Alignment::Right),
             };
-            renderer.fill_text(
-                Text {
-                    content: format!("{}%", self.value),
-                    bounds: Size::new(f32::INFINITY, bounds.height),
-                    size: self.text_size.unwrap_or_else(|| renderer.default_size()),
-                    line_height: self.text_line_height,
-                    font: self.font.unwrap_or_else(|| renderer.default_font()),
-                    align_x,
-                    align_y: alignment::Vertical::Center,
-                    shaping: text::Shaping::Basic,
-                    wrapping: text::Wrapping::default(),
-                },
-                Point::new(x, bounds.center_y()),
-                theme.style(&self.class).color,
-                *viewport,
-            );
+            let y = match self.text_alignment_vertical {
+                alignment::Vertical::Top => bounds.y + self.padding.top,
+                alignment::Vertical::Center => bounds.center_y(),
+                alignment::Vertical::Bottom => bounds.y + bounds.height - self.padding.bottom,
+            };
+
+            // The text stays horizontal in a vertical bar, laid out across its girth.
+            let text = Text {
+                content: format!("{}%", value),
+                bounds: Size::new(f32::INFINITY, bounds.height),
+                size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                line_height: self.text_line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                align_x,
+                align_y: self.text_alignment_vertical,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::default(),
+            };
+
+            if let Some(text_position) = self.outer_text_position() {
+                let outer = layout.bounds();
+                let line_height = self.line_height(renderer);
+
+                let (position, align_x, align_y) = match text_position {
+                    TextPosition::Above => (Point::new(x, outer.y), align_x, alignment::Vertical::Top),
+                    TextPosition::Below => (
+                        Point::new(x, outer.y + outer.height - line_height),
+                        align_x,
+                        alignment::Vertical::Top,
+                    ),
+                    TextPosition::Inside | TextPosition::Trailing => (
+                        Point::new(bounds.x + bounds.width + STEP_LABEL_SPACING, bounds.center_y()),
+                        text::Alignment::Left,
+                        alignment::Vertical::Center,
+                    ),
+                };
+
+                // Outside of the bar, the text is over the same background as the captions.
+                renderer.fill_text(
+                    Text {
+                        align_x,
+                        align_y,
+                        ..text
+                    },
+                    position,
+                    style.caption,
+                    *viewport,
+                );
+            } else {
+                // The text is drawn twice, clipped to either side of the end of the fill, so the
+                // part over the fill stays readable in its own color.
+                let filled = self.filled(bounds, value);
+                let unfilled = self.span(bounds, value, *self.range.end());
+
+                for (clip_bounds, color) in [(filled, style.filled_color), (unfilled, style.color)] {
+                    if let Some(clip_bounds) = clip_bounds.intersection(viewport) {
+                        renderer.fill_text(text.clone(), Point::new(x, y), color, clip_bounds);
+                    }
+                }
+            }
         }
+
+        self.draw_captions(renderer, bounds, style.caption, viewport);
     }
 }
 
-impl<'a, Message, Theme, Renderer> From<ProgressBar<'a, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+impl<'a, Message, Theme, Renderer> From<ProgressBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
     Theme: 'a + Catalog,
     Renderer: 'a + text::Renderer,
 {
-    fn from(progress_bar: ProgressBar<'a, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+    fn from(progress_bar: ProgressBar<'a, Message, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
         Element::new(progress_bar)
     }
 }
 
+/// The progress of an operation reported from outside the view, like an async task.
+///
+/// A [`Controller`] lives in the application state. Report every new value with
+/// [`Controller::set`], advance it with [`Controller::tick`] on every frame, and build
+/// the [`ProgressBar`] with [`Controller::bar`] to get smoothing and an ETA for free.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Controller {
+    range: RangeInclusive<f32>,
+    value: f32,
+    displayed: f32,
+    rate: Option<f32>,
+    smoothing: f32,
+    easing: Duration,
+    last_set: Option<Instant>,
+    last_tick: Option<Instant>,
+}
+
+impl Controller {
+    /// The default weight of a new sample of the rate, from `0.0` to `1.0`.
+    pub const DEFAULT_SMOOTHING: f32 = 0.3;
+
+    /// The default time the displayed value takes to catch up with the reported one.
+    pub const DEFAULT_EASING: Duration = Duration::from_millis(250);
+
+    /// Creates a new [`Controller`] with the given inclusive range of values, starting at its start.
+    pub fn new(range: RangeInclusive<f32>) -> Self {
+        Self {
+            value: *range.start(),
+            displayed: *range.start(),
+            range,
+            rate: None,
+            smoothing: Self::DEFAULT_SMOOTHING,
+            easing: Self::DEFAULT_EASING,
+            last_set: None,
+            last_tick: None,
+        }
+    }
+
+    /// Sets the weight of a new sample when averaging the rate, from `0.0` to `1.0`.
+    ///
+    /// Lower values give a steadier ETA, higher values follow changes of speed faster.
+    pub fn smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the time the displayed value takes to catch up with the reported one.
+    ///
+    /// A zero duration displays every reported value right away.
+    pub fn easing(mut self, easing: Duration) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Reports a new value of the operation at the given instant.
+    pub fn set(&mut self, value: f32, now: Instant) {
+        let value = value.clamp(*self.range.start(), *self.range.end());
+
+        if let Some(last_set) = self.last_set {
+            let elapsed = now.saturating_duration_since(last_set).as_secs_f32();
+
+            if elapsed > 0.0 {
+                let sample = (value - self.value) / elapsed;
+
+                self.rate = Some(match self.rate {
+                    Some(rate) => rate + (sample - rate) * self.smoothing,
+                    None => sample,
+                });
+            }
+        }
+
+        if self.easing.is_zero() {
+            self.displayed = value;
+        }
+
+        self.value = value;
+        self.last_set = Some(now);
+    }
+
+    /// Moves the displayed value towards the reported one, returning whether it is still moving.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let elapsed = self
+            .last_tick
+            .map_or(0.0, |last_tick| now.saturating_duration_since(last_tick).as_secs_f32());
+
+        self.last_tick = Some(now);
+
+        if self.easing.is_zero() {
+            self.displayed = self.value;
+        } else {
+            let t = (elapsed / self.easing.as_secs_f32()).min(1.0);
+            self.displayed += (self.value - self.displayed) * t;
+
+            // Snaps once the difference is not visible anymore.
+            if (self.value - self.displayed).abs() <= (self.range.end() - self.range.start()).abs() * 1e-4 {
+                self.displayed = self.value;
+            }
+        }
+
+        self.is_animating()
+    }
+
+    /// Starts over from the start of the range, forgetting the rate.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.range.clone())
+            .smoothing(self.smoothing)
+            .easing(self.easing);
+    }
+
+    /// Returns the last reported value.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Returns the displayed value, which eases towards the last reported one.
+    pub fn displayed(&self) -> f32 {
+        self.displayed
+    }
+
+    /// Returns whether the displayed value has not caught up with the reported one yet.
+    pub fn is_animating(&self) -> bool {
+        self.displayed != self.value
+    }
+
+    /// Returns the averaged change of the value per second, if known.
+    pub fn rate(&self) -> Option<f32> {
+        self.rate
+    }
+
+    /// Returns the estimated time until the end of the range is reached, if known.
+    pub fn eta(&self) -> Option<Duration> {
+        let rate = self.rate.filter(|rate| *rate > 0.0)?;
+        let remaining = (self.range.end() - self.value).max(0.0);
+
+        Duration::try_from_secs_f32(remaining / rate).ok()
+    }
+
+    /// Returns whether the end of the range has been reached.
+    pub fn is_finished(&self) -> bool {
+        self.value >= *self.range.end()
+    }
+
+    /// Creates a [`ProgressBar`] showing the displayed value of the [`Controller`].
+    pub fn bar<'a, Message, Theme, Renderer>(&self) -> ProgressBar<'a, Message, Theme, Renderer>
+    where
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        ProgressBar::new(self.range.clone(), self.displayed)
+    }
+}
+
 /// The appearance of a progress bar.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
@@ -324,10 +1457,46 @@ pub struct Style {
     pub background: Background,
     /// The [`Background`] of the bar of the progress bar.
     pub bar: Background,
+    /// The [`Background`] of the bar of the secondary value of the progress bar.
+    pub secondary_bar: Background,
+    /// The [`Color`] of the stripes of a striped progress bar.
+    pub stripes: Color,
+    /// The [`Color`] of the tick marks of the progress bar.
+    pub tick: Color,
+    /// The [`Color`] of the captions of the start and the end of the range.
+    pub caption: Color,
     /// The [`Border`] of the progress bar.
     pub border: Border,
     /// The [`Color`] of the progress bar percentage.
     pub color: Color,
+    /// The [`Color`] of the part of the percentage over the bar.
+    pub filled_color: Color,
+}
+
+#[cfg(feature = "stock-styles")]
+impl From<Style> for iced_widget::progress_bar::Style {
+    fn from(style: Style) -> Self {
+        Self {
+            background: style.background,
+            bar: style.bar,
+            border: style.border,
+        }
+    }
+}
+
+/// The status of a [`ProgressBar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`ProgressBar`] is making progress.
+    Normal,
+    /// The [`ProgressBar`] is [`paused`](ProgressBar::paused).
+    Paused,
+    /// The operation of the [`ProgressBar`] [`errored`](ProgressBar::errored).
+    Errored,
+    /// The [`ProgressBar`] reached the end of its range.
+    Completed,
+    /// The [`ProgressBar`] is being hovered.
+    Hovered,
 }
 
 /// The theme catalog of a [`ProgressBar`].
@@ -339,13 +1508,13 @@ pub trait Catalog: Sized {
     fn default<'a>() -> Self::Class<'a>;
 
     /// The [`Style`] of a class with the given status.
-    fn style(&self, class: &Self::Class<'_>) -> Style;
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
 }
 
 /// A styling function for a [`ProgressBar`].
 ///
 /// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
-pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
     type Class<'a> = StyleFn<'a, Self>;
@@ -354,71 +1523,231 @@ impl Catalog for Theme {
         Box::new(primary)
     }
 
-    fn style(&self, class: &Self::Class<'_>) -> Style {
-        class(self)
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
     }
 }
 
 /// The primary style of a [`ProgressBar`].
-pub fn primary(theme: &Theme) -> Style {
+pub fn primary(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
 
     styled(
-        palette.background.strong.color,
-        palette.primary.base.color,
+        palette,
+        status,
+        palette.primary.base,
+        palette.primary.strong,
         palette.background.strongest.text,
     )
 }
 
 /// The secondary style of a [`ProgressBar`].
-pub fn secondary(theme: &Theme) -> Style {
+pub fn secondary(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
 
     styled(
-        palette.background.strong.color,
-        palette.secondary.base.color,
+        palette,
+        status,
+        palette.secondary.base,
+        palette.secondary.strong,
         palette.background.weak.text,
     )
 }
 
 /// The success style of a [`ProgressBar`].
-pub fn success(theme: &Theme) -> Style {
+pub fn success(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
 
     styled(
-        palette.background.strong.color,
-        palette.success.base.color,
+        palette,
+        status,
+        palette.success.base,
+        palette.success.strong,
         palette.background.weak.text,
     )
 }
 
 /// The warning style of a [`ProgressBar`].
-pub fn warning(theme: &Theme) -> Style {
+pub fn warning(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
 
     styled(
-        palette.background.strong.color,
-        palette.warning.base.color,
+        palette,
+        status,
+        palette.warning.base,
+        palette.warning.strong,
         palette.background.weak.text,
     )
 }
 
 /// The danger style of a [`ProgressBar`].
-pub fn danger(theme: &Theme) -> Style {
+pub fn danger(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
 
     styled(
-        palette.background.strong.color,
-        palette.danger.base.color,
+        palette,
+        status,
+        palette.danger.base,
+        palette.danger.strong,
         palette.background.weak.text,
     )
 }
 
-fn styled(background: impl Into<Background>, bar: impl Into<Background>, color: Color) -> Style {
+fn styled(
+    palette: &palette::Extended,
+    status: Status,
+    base: palette::Pair,
+    hovered: palette::Pair,
+    color: Color,
+) -> Style {
+    let bar = match status {
+        Status::Normal => base,
+        Status::Hovered => hovered,
+        Status::Paused => palette.background.strongest,
+        Status::Errored => palette.danger.base,
+        Status::Completed => palette.success.base,
+    };
+    let (bar, filled_color) = (bar.color, bar.text);
+
     Style {
-        background: background.into(),
+        background: palette.background.strong.color.into(),
         bar: bar.into(),
+        secondary_bar: Color { a: bar.a * 0.4, ..bar }.into(),
+        stripes: Color { a: 0.2, ..Color::WHITE },
+        tick: Color { a: 0.3, ..color },
+        caption: color,
         border: border::rounded(2),
         color,
+        filled_color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::time::{Duration, Instant};
+
+    use super::{Controller, Scale};
+
+    fn seconds(start: Instant, seconds: u64) -> Instant {
+        start + Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn rate_is_an_exponential_average_of_the_samples() {
+        let start = Instant::now();
+        let mut controller = Controller::new(0.0..=100.0).smoothing(0.5);
+
+        controller.set(0.0, start);
+        assert_eq!(controller.rate(), None);
+
+        // The first sample is taken as is, and every next one moves the rate halfway to it.
+        controller.set(10.0, seconds(start, 1));
+        assert_eq!(controller.rate(), Some(10.0));
+
+        controller.set(30.0, seconds(start, 2));
+        assert_eq!(controller.rate(), Some(15.0));
+
+        controller.set(34.0, seconds(start, 3));
+        assert_eq!(controller.rate(), Some(9.5));
+
+        // A value reported at the same instant has no rate to sample.
+        controller.set(50.0, seconds(start, 3));
+        assert_eq!(controller.rate(), Some(9.5));
+    }
+
+    #[test]
+    fn zero_easing_displays_every_value_right_away() {
+        let start = Instant::now();
+        let mut controller = Controller::new(0.0..=100.0).easing(Duration::ZERO);
+
+        controller.set(40.0, start);
+
+        assert_eq!(controller.displayed(), 40.0);
+        assert!(!controller.is_animating());
+        assert!(!controller.tick(start));
+
+        let mut controller = Controller::new(0.0..=100.0);
+
+        controller.set(40.0, start);
+
+        assert_eq!(controller.displayed(), 0.0);
+        assert!(controller.tick(start));
+        assert!(!controller.tick(start + Controller::DEFAULT_EASING));
+        assert_eq!(controller.displayed(), 40.0);
+    }
+
+    #[test]
+    fn eta_is_only_known_while_moving_forward() {
+        let start = Instant::now();
+        let mut controller = Controller::new(0.0..=100.0);
+
+        controller.set(50.0, start);
+        assert_eq!(controller.eta(), None);
+
+        controller.set(50.0, seconds(start, 1));
+        assert_eq!(controller.rate(), Some(0.0));
+        assert_eq!(controller.eta(), None);
+
+        let mut controller = Controller::new(0.0..=100.0).smoothing(1.0);
+
+        controller.set(50.0, start);
+        controller.set(40.0, seconds(start, 1));
+        assert_eq!(controller.rate(), Some(-10.0));
+        assert_eq!(controller.eta(), None);
+
+        controller.set(50.0, seconds(start, 2));
+        assert_eq!(controller.eta(), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn reset_keeps_the_smoothing_and_the_easing() {
+        let start = Instant::now();
+        let mut controller = Controller::new(0.0..=100.0).smoothing(0.5).easing(Duration::ZERO);
+
+        controller.set(20.0, start);
+        controller.set(40.0, seconds(start, 1));
+        controller.reset();
+
+        assert_eq!(controller.value(), 0.0);
+        assert_eq!(controller.rate(), None);
+        assert_eq!(controller.eta(), None);
+
+        controller.set(10.0, seconds(start, 2));
+        assert_eq!(controller.displayed(), 10.0);
+
+        controller.set(20.0, seconds(start, 3));
+        controller.set(40.0, seconds(start, 4));
+        assert_eq!(controller.rate(), Some(15.0));
+    }
+
+    #[test]
+    fn linear_scales_fill_evenly_within_the_range() {
+        assert_eq!(Scale::Linear.fraction(&(10.0..=20.0), 15.0), 0.5);
+        assert_eq!(Scale::Linear.fraction(&(10.0..=20.0), 0.0), 0.0);
+        assert_eq!(Scale::Linear.fraction(&(10.0..=20.0), 30.0), 1.0);
+        assert_eq!(Scale::Custom(|value| value * value).fraction(&(0.0..=10.0), 5.0), 0.25);
+    }
+
+    #[test]
+    fn empty_and_reversed_ranges_are_never_filled() {
+        for scale in [Scale::Linear, Scale::Log, Scale::Custom(f32::exp)] {
+            assert_eq!(scale.fraction(&(5.0..=5.0), 5.0), 0.0);
+            assert_eq!(scale.fraction(&(10.0..=0.0), 5.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn log_scales_fill_the_same_way_whatever_the_units() {
+        let is_close = |a: f32, b: f32| (a - b).abs() < 0.01;
+
+        for end in [1.0, 1000.0, 1e6] {
+            let range = 0.0..=end;
+
+            assert_eq!(Scale::Log.fraction(&range, 0.0), 0.0);
+            assert!(is_close(Scale::Log.fraction(&range, end / 1000.0), 0.1));
+            assert!(is_close(Scale::Log.fraction(&range, end / 100.0), 0.35));
+            assert!(is_close(Scale::Log.fraction(&range, end / 10.0), 0.67));
+            assert_eq!(Scale::Log.fraction(&range, end), 1.0);
+        }
     }
 }
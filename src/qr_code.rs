@@ -0,0 +1,263 @@
+//! Display a QR code, like a link to pair a device or to pay.
+//!
+//! A [`QrCode`] draws a [`Matrix`] of modules as quads, surrounded by its quiet zone. The
+//! [`Matrix`] can be built from modules encoded elsewhere, or encoded from data with the
+//! `qrcode` feature.
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Size;
+use iced_core::Theme;
+use iced_core::Widget;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::widget::Tree;
+
+/// The square grid of modules of a QR code, from the top left corner, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    width: usize,
+    modules: Vec<bool>,
+}
+
+impl Matrix {
+    /// Creates a new [`Matrix`] with the given amount of modules per side, and whether every
+    /// module is dark, row by row.
+    ///
+    /// Missing modules are light, and extra ones are ignored.
+    pub fn new(width: usize, modules: impl IntoIterator<Item = bool>) -> Self {
+        let mut modules: Vec<bool> = modules.into_iter().take(width * width).collect();
+        modules.resize(width * width, false);
+
+        Self { width, modules }
+    }
+
+    /// Encodes the given data into a [`Matrix`], picking the smallest version that fits it.
+    #[cfg(feature = "qrcode")]
+    pub fn encode(data: impl AsRef<[u8]>) -> Result<Self, qrcode::types::QrError> {
+        let code = qrcode::QrCode::new(data)?;
+
+        Ok(Self::new(
+            code.width(),
+            code.to_colors().into_iter().map(|color| color == qrcode::Color::Dark),
+        ))
+    }
+
+    /// Returns the amount of modules per side of the [`Matrix`].
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns whether the module at the given column and row is dark.
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.width && self.modules.get(y * self.width + x).copied().unwrap_or(false)
+    }
+}
+
+/// A QR code drawn from the modules of a [`Matrix`].
+pub struct QrCode<'a, Theme>
+where
+    Theme: Catalog,
+{
+    matrix: &'a Matrix,
+    module_size: f32,
+    quiet_zone: usize,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> QrCode<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of a module of a [`QrCode`].
+    pub const DEFAULT_MODULE_SIZE: f32 = 4.0;
+
+    /// The default width of the quiet zone of a [`QrCode`], in modules, as required by the
+    /// specification for scanners to find the code.
+    pub const DEFAULT_QUIET_ZONE: usize = 4;
+
+    /// Creates a new [`QrCode`] drawing the given [`Matrix`].
+    pub fn new(matrix: &'a Matrix) -> Self {
+        Self {
+            matrix,
+            module_size: Self::DEFAULT_MODULE_SIZE,
+            quiet_zone: Self::DEFAULT_QUIET_ZONE,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the size of a module of the [`QrCode`].
+    pub fn module_size(mut self, module_size: impl Into<Pixels>) -> Self {
+        self.module_size = module_size.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the width of the light border around the [`QrCode`], in modules.
+    pub fn quiet_zone(mut self, quiet_zone: usize) -> Self {
+        self.quiet_zone = quiet_zone;
+        self
+    }
+
+    /// Sets the style of the [`QrCode`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`QrCode`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the length of a side of the [`QrCode`], including its quiet zone.
+    fn side(&self) -> f32 {
+        (self.matrix.width() + 2 * self.quiet_zone) as f32 * self.module_size
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for QrCode<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let side = self.side();
+
+        layout::atomic(limits, side, side)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            style.light,
+        );
+
+        let origin = Point::new(
+            bounds.x + self.quiet_zone as f32 * self.module_size,
+            bounds.y + self.quiet_zone as f32 * self.module_size,
+        );
+        let width = self.matrix.width();
+
+        // Consecutive dark modules of a row are drawn as a single quad.
+        for y in 0..width {
+            let mut x = 0;
+
+            while x < width {
+                if !self.matrix.is_dark(x, y) {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+
+                while x < width && self.matrix.is_dark(x, y) {
+                    x += 1;
+                }
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(
+                            Point::new(
+                                origin.x + start as f32 * self.module_size,
+                                origin.y + y as f32 * self.module_size,
+                            ),
+                            Size::new((x - start) as f32 * self.module_size, self.module_size),
+                        ),
+                        ..renderer::Quad::default()
+                    },
+                    style.dark,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<QrCode<'a, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(qr_code: QrCode<'a, Theme>) -> Self {
+        Element::new(qr_code)
+    }
+}
+
+/// The appearance of a [`QrCode`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the dark modules.
+    pub dark: Color,
+    /// The [`Color`] of the light modules and of the quiet zone.
+    pub light: Color,
+}
+
+/// The theme catalog of a [`QrCode`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`QrCode`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`QrCode`].
+///
+/// Scanners expect dark modules on a light background, so it does not follow the palette of
+/// dark themes.
+pub fn default(_theme: &Theme) -> Style {
+    Style {
+        dark: Color::BLACK,
+        light: Color::WHITE,
+    }
+}
@@ -0,0 +1,304 @@
+//! Build recurrence rules out of pick lists.
+//!
+//! Every pick list of a [`ScheduleBuilder`] edits one part of its [`Schedule`], like the
+//! unit or the weekdays, and publishes the whole rule. Only the text typed in the
+//! interval field is kept by the widget, until it parses.
+use std::fmt;
+use std::rc::Rc;
+
+use iced_core::Element;
+use iced_core::text;
+use iced_widget::Column;
+use iced_widget::Row;
+use iced_widget::pick_list;
+use iced_widget::text_input;
+
+use crate::MultiPickList;
+use crate::multi_pick_list;
+use crate::parsed_input::ParsedInput;
+
+/// A recurrence rule, like "every 2 weeks on Mon, Wed at 09:30".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    /// The amount of units between two occurrences, at least one.
+    pub interval: u32,
+    /// The [`Unit`] of the interval.
+    pub unit: Unit,
+    /// The days of the week a weekly [`Schedule`] occurs on, or every day if empty.
+    pub weekdays: Vec<Weekday>,
+    /// The hour and minute a daily or weekly [`Schedule`] occurs at.
+    pub at: (u8, u8),
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Self {
+            interval: 1,
+            unit: Unit::Days,
+            weekdays: Vec::new(),
+            at: (9, 0),
+        }
+    }
+}
+
+impl Schedule {
+    /// Returns whether the [`Schedule`] occurs at a time of the day.
+    pub fn has_time(&self) -> bool {
+        matches!(self.unit, Unit::Days | Unit::Weeks)
+    }
+
+    /// Returns a human-readable summary of the [`Schedule`].
+    pub fn summary(&self) -> String {
+        let mut summary = if self.interval == 1 {
+            format!("Every {}", self.unit.singular())
+        } else {
+            format!("Every {} {}", self.interval, self.unit)
+        };
+
+        if self.unit == Unit::Weeks && !self.weekdays.is_empty() {
+            // Weekdays are listed in calendar order, regardless of the order they were picked in.
+            let weekdays: Vec<_> = Weekday::ALL
+                .iter()
+                .filter(|weekday| self.weekdays.contains(weekday))
+                .map(Weekday::to_string)
+                .collect();
+
+            summary.push_str(&format!(" on {}", weekdays.join(", ")));
+        }
+
+        if self.has_time() {
+            summary.push_str(&format!(" at {:02}:{:02}", self.at.0, self.at.1));
+        }
+
+        summary
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.summary())
+    }
+}
+
+/// The unit of the interval of a [`Schedule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+}
+
+impl Unit {
+    /// Every [`Unit`], from the shortest to the longest.
+    pub const ALL: [Unit; 4] = [Unit::Minutes, Unit::Hours, Unit::Days, Unit::Weeks];
+
+    fn singular(self) -> &'static str {
+        match self {
+            Unit::Minutes => "minute",
+            Unit::Hours => "hour",
+            Unit::Days => "day",
+            Unit::Weeks => "week",
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = match self {
+            Unit::Minutes => "minutes",
+            Unit::Hours => "hours",
+            Unit::Days => "days",
+            Unit::Weeks => "weeks",
+        };
+
+        f.write_str(unit)
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Every [`Weekday`], starting on Monday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let weekday = match self {
+            Weekday::Monday => "Mon",
+            Weekday::Tuesday => "Tue",
+            Weekday::Wednesday => "Wed",
+            Weekday::Thursday => "Thu",
+            Weekday::Friday => "Fri",
+            Weekday::Saturday => "Sat",
+            Weekday::Sunday => "Sun",
+        };
+
+        f.write_str(weekday)
+    }
+}
+
+const HOURS: [u8; 24] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+];
+
+const MINUTES: [u8; 12] = [0, 5, 10, 15, 20, 25, 30, 35, 40, 45, 50, 55];
+
+/// A row of fields editing a [`Schedule`], followed by its summary.
+pub struct ScheduleBuilder<'a, Message> {
+    schedule: &'a Schedule,
+    on_change: Rc<dyn Fn(Schedule) -> Message + 'a>,
+    spacing: f32,
+    show_summary: bool,
+}
+
+impl<'a, Message> ScheduleBuilder<'a, Message> {
+    /// The default spacing between the fields of a [`ScheduleBuilder`].
+    pub const DEFAULT_SPACING: f32 = 8.0;
+
+    /// Creates a new [`ScheduleBuilder`] editing the given [`Schedule`], and the message to
+    /// produce with the changed one.
+    pub fn new(schedule: &'a Schedule, on_change: impl Fn(Schedule) -> Message + 'a) -> Self {
+        Self {
+            schedule,
+            on_change: Rc::new(on_change),
+            spacing: Self::DEFAULT_SPACING,
+            show_summary: true,
+        }
+    }
+
+    /// Sets the spacing between the fields of the [`ScheduleBuilder`].
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets whether the summary of the [`Schedule`] is shown below its fields.
+    pub fn summary(mut self, show_summary: bool) -> Self {
+        self.show_summary = show_summary;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ScheduleBuilder<'a, Message>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: text_input::Catalog + pick_list::Catalog + multi_pick_list::Catalog + iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(builder: ScheduleBuilder<'a, Message>) -> Self {
+        let schedule = builder.schedule;
+
+        let interval = {
+            let change = Rc::clone(&builder.on_change);
+            let current = schedule.clone();
+
+            ParsedInput::new("1", schedule.interval, move |interval: u32| {
+                change(Schedule {
+                    interval: interval.max(1),
+                    ..current.clone()
+                })
+            })
+            .width(60)
+        };
+
+        let unit = {
+            let change = Rc::clone(&builder.on_change);
+            let current = schedule.clone();
+
+            pick_list(&Unit::ALL[..], Some(schedule.unit), move |unit| {
+                change(Schedule {
+                    unit,
+                    ..current.clone()
+                })
+            })
+        };
+
+        let mut fields = Row::new()
+            .push(iced_widget::text("Every"))
+            .push(interval)
+            .push(unit)
+            .spacing(builder.spacing)
+            .align_y(iced_core::alignment::Vertical::Center);
+
+        if schedule.unit == Unit::Weeks {
+            let change = Rc::clone(&builder.on_change);
+            let current = schedule.clone();
+
+            fields = fields.push(
+                MultiPickList::new(&Weekday::ALL[..], &schedule.weekdays[..], move |weekday| {
+                    let mut changed = current.clone();
+
+                    if let Some(index) = changed.weekdays.iter().position(|picked| *picked == weekday) {
+                        changed.weekdays.remove(index);
+                    } else {
+                        changed.weekdays.push(weekday);
+                    }
+
+                    change(changed)
+                })
+                .placeholder("Any day")
+                .width(160),
+            );
+        }
+
+        if schedule.has_time() {
+            let hour = {
+                let change = Rc::clone(&builder.on_change);
+                let current = schedule.clone();
+
+                pick_list(&HOURS[..], Some(schedule.at.0), move |hour| {
+                    change(Schedule {
+                        at: (hour, current.at.1),
+                        ..current.clone()
+                    })
+                })
+            };
+
+            let minute = {
+                let change = Rc::clone(&builder.on_change);
+                let current = schedule.clone();
+
+                pick_list(&MINUTES[..], Some(schedule.at.1), move |minute| {
+                    change(Schedule {
+                        at: (current.at.0, minute),
+                        ..current.clone()
+                    })
+                })
+            };
+
+            fields = fields
+                .push(iced_widget::text("at"))
+                .push(hour)
+                .push(iced_widget::text(":"))
+                .push(minute);
+        }
+
+        let mut content = Column::new().push(fields).spacing(builder.spacing);
+
+        if builder.show_summary {
+            content = content.push(iced_widget::text(schedule.summary()));
+        }
+
+        content.into()
+    }
+}
@@ -0,0 +1,532 @@
+//! Capture freehand strokes, like a signature.
+//!
+//! The strokes of a [`SignaturePad`] are owned by the application as a [`Signature`]. The pad
+//! only keeps the stroke being drawn, and produces it once the pointer is lifted. Points are
+//! normalized to the bounds of the pad, from `0.0` to `1.0`, so a [`Signature`] survives the
+//! pad being resized and can be exported as is.
+use iced_core::Background;
+use iced_core::Border;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Theme;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::touch;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+
+/// A continuous line drawn on a [`SignaturePad`], in normalized coordinates.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Stroke {
+    pub points: Vec<Point>,
+}
+
+/// The strokes drawn on a [`SignaturePad`], from the oldest to the newest.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Signature {
+    strokes: Vec<Stroke>,
+}
+
+impl Signature {
+    /// Creates a new empty [`Signature`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a finished [`Stroke`] to the [`Signature`].
+    pub fn push(&mut self, stroke: Stroke) {
+        if !stroke.points.is_empty() {
+            self.strokes.push(stroke);
+        }
+    }
+
+    /// Removes the last [`Stroke`] of the [`Signature`], returning it.
+    pub fn undo(&mut self) -> Option<Stroke> {
+        self.strokes.pop()
+    }
+
+    /// Removes every [`Stroke`] of the [`Signature`].
+    pub fn clear(&mut self) {
+        self.strokes.clear();
+    }
+
+    /// Returns whether nothing was drawn.
+    pub fn is_empty(&self) -> bool {
+        self.strokes.is_empty()
+    }
+
+    /// Returns the strokes of the [`Signature`].
+    pub fn strokes(&self) -> &[Stroke] {
+        &self.strokes
+    }
+
+    /// Returns the points of every stroke, normalized to the bounds of the pad.
+    pub fn normalized(&self) -> Vec<Vec<Point>> {
+        self.strokes.iter().map(|stroke| stroke.points.clone()).collect()
+    }
+}
+
+/// A pad capturing freehand strokes.
+pub struct SignaturePad<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    signature: &'a Signature,
+    on_stroke: Box<dyn Fn(Stroke) -> Message + 'a>,
+    width: Length,
+    height: Length,
+    stroke_width: f32,
+    smoothing: usize,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> SignaturePad<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default width of the strokes of a [`SignaturePad`].
+    pub const DEFAULT_STROKE_WIDTH: f32 = 2.5;
+
+    /// The default amount of smoothing passes over a finished stroke.
+    pub const DEFAULT_SMOOTHING: usize = 2;
+
+    /// The default height of a [`SignaturePad`].
+    const DEFAULT_HEIGHT: f32 = 160.0;
+
+    /// Creates a new [`SignaturePad`] showing the given [`Signature`], and the message to produce
+    /// with every finished [`Stroke`].
+    pub fn new(signature: &'a Signature, on_stroke: impl Fn(Stroke) -> Message + 'a) -> Self {
+        Self {
+            signature,
+            on_stroke: Box::new(on_stroke),
+            width: Length::Fill,
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            stroke_width: Self::DEFAULT_STROKE_WIDTH,
+            smoothing: Self::DEFAULT_SMOOTHING,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`SignaturePad`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`SignaturePad`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the width of the strokes of the [`SignaturePad`].
+    pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = stroke_width.max(0.5);
+        self
+    }
+
+    /// Sets the amount of smoothing passes over a finished stroke.
+    ///
+    /// Every pass averages each point with its neighbours, evening out the jitter of the pointer.
+    /// Zero keeps the points as they were captured.
+    pub fn smoothing(mut self, smoothing: usize) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Sets the style of the [`SignaturePad`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`SignaturePad`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the given points, averaged with their neighbours once per smoothing pass.
+    ///
+    /// The ends of the stroke are kept where they were.
+    fn smooth(&self, mut points: Vec<Point>) -> Vec<Point> {
+        for _ in 0..self.smoothing {
+            if points.len() < 3 {
+                break;
+            }
+
+            points = points
+                .iter()
+                .enumerate()
+                .map(|(index, point)| {
+                    if index == 0 || index == points.len() - 1 {
+                        *point
+                    } else {
+                        let (previous, next) = (points[index - 1], points[index + 1]);
+
+                        Point::new(
+                            (previous.x + 2.0 * point.x + next.x) / 4.0,
+                            (previous.y + 2.0 * point.y + next.y) / 4.0,
+                        )
+                    }
+                })
+                .collect();
+        }
+
+        points
+    }
+
+    /// Draws the given points, in normalized coordinates, as a line of round dots.
+    fn draw_stroke<Renderer>(&self, renderer: &mut Renderer, bounds: Rectangle, points: &[Point], color: Color)
+    where
+        Renderer: renderer::Renderer,
+    {
+        let radius = self.stroke_width / 2.0;
+
+        let dot = |renderer: &mut Renderer, center: Point| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        center - Vector::new(radius, radius),
+                        Size::new(self.stroke_width, self.stroke_width),
+                    ),
+                    border: Border {
+                        radius: radius.into(),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        };
+
+        let mut points = points.iter().map(|point| denormalize(*point, bounds));
+
+        let Some(mut previous) = points.next() else {
+            return;
+        };
+
+        dot(renderer, previous);
+
+        // Dots are close enough to overlap, so segments look continuous at any angle.
+        let step = (radius / 2.0).max(0.5);
+
+        for point in points {
+            let distance = previous.distance(point);
+            let steps = (distance / step).ceil().max(1.0) as usize;
+
+            for index in 1..=steps {
+                let progress = index as f32 / steps as f32;
+
+                dot(
+                    renderer,
+                    Point::new(
+                        previous.x + (point.x - previous.x) * progress,
+                        previous.y + (point.y - previous.y) * progress,
+                    ),
+                );
+            }
+
+            previous = point;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    stroke: Option<Vec<Point>>,
+    is_hovered: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for SignaturePad<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.stroke = Some(vec![normalize(position, bounds)]);
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(stroke) = &mut state.stroke else {
+                    let is_hovered = cursor.is_over(bounds);
+
+                    if is_hovered != state.is_hovered {
+                        state.is_hovered = is_hovered;
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                // The stroke keeps following the pointer outside of the pad, clamped to its edges.
+                if let Some(position) = cursor.position() {
+                    let point = normalize(position, bounds);
+
+                    // Points closer than a fraction of the stroke width add nothing but jitter.
+                    let is_far_enough = stroke.last().is_none_or(|last| {
+                        denormalize(*last, bounds).distance(denormalize(point, bounds)) >= self.stroke_width / 2.0
+                    });
+
+                    if is_far_enough {
+                        stroke.push(point);
+                        shell.request_redraw();
+                    }
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(points) = state.stroke.take() {
+                    let points = self.smooth(points);
+
+                    shell.publish((self.on_stroke)(Stroke { points }));
+                    shell.capture_event();
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.stroke.take().is_some() {
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if state.stroke.is_some() {
+            Status::Drawing
+        } else if state.is_hovered && cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let Some(clip_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            if let Some(baseline) = style.baseline {
+                let margin = bounds.width * 0.1;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + margin,
+                            y: bounds.y + bounds.height * 0.75,
+                            width: bounds.width - 2.0 * margin,
+                            height: 1.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    baseline,
+                );
+            }
+
+            for stroke in self.signature.strokes() {
+                self.draw_stroke(renderer, bounds, &stroke.points, style.stroke_color);
+            }
+
+            if let Some(stroke) = &state.stroke {
+                self.draw_stroke(renderer, bounds, stroke, style.stroke_color);
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SignaturePad<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(pad: SignaturePad<'a, Message, Theme>) -> Self {
+        Self::new(pad)
+    }
+}
+
+/// Returns the given screen position relative to the bounds, clamped between `0.0` and `1.0`.
+fn normalize(position: Point, bounds: Rectangle) -> Point {
+    Point::new(
+        ((position.x - bounds.x) / bounds.width.max(1.0)).clamp(0.0, 1.0),
+        ((position.y - bounds.y) / bounds.height.max(1.0)).clamp(0.0, 1.0),
+    )
+}
+
+/// Returns the screen position of the given normalized point inside the bounds.
+fn denormalize(point: Point, bounds: Rectangle) -> Point {
+    Point::new(bounds.x + point.x * bounds.width, bounds.y + point.y * bounds.height)
+}
+
+/// The status of a [`SignaturePad`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`SignaturePad`] is idle.
+    Active,
+    /// The [`SignaturePad`] is hovered.
+    Hovered,
+    /// A stroke is being drawn.
+    Drawing,
+}
+
+/// The appearance of a [`SignaturePad`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the pad.
+    pub background: Background,
+    /// The [`Border`] of the pad.
+    pub border: Border,
+    /// The [`Color`] of the strokes.
+    pub stroke_color: Color,
+    /// The [`Color`] of the line to sign on, if any.
+    pub baseline: Option<Color>,
+}
+
+/// The theme catalog of a [`SignaturePad`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`SignaturePad`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`SignaturePad`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        stroke_color: palette.background.base.text,
+        baseline: Some(palette.background.strong.color),
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered | Status::Drawing => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+    }
+}
@@ -0,0 +1,653 @@
+//! Adjust many values on a shared scale side by side, like the bands of an equalizer.
+//!
+//! A [`SliderBank`] draws a vertical slider for every value, over the ticks of their shared
+//! range, with an optional label below each of them. Dragging a slider produces a message
+//! with its index and its new value. While linked, dragging a slider also nudges its
+//! neighbors, producing a message for every slider that moved.
+use std::ops::RangeInclusive;
+
+use iced_core::Background;
+use iced_core::Border;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Text;
+use iced_core::Theme;
+use iced_core::Widget;
+use iced_core::alignment;
+use iced_core::border;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::touch;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+
+/// Vertical sliders side by side, sharing the same range.
+pub struct SliderBank<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    values: &'a [f32],
+    range: RangeInclusive<f32>,
+    step: Option<f32>,
+    on_change: Box<dyn Fn(usize, f32) -> Message + 'a>,
+    on_release: Option<Message>,
+    labels: Vec<String>,
+    link: Option<f32>,
+    ticks: usize,
+    width: Length,
+    height: Length,
+    slider_width: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> SliderBank<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default width of a slider of a [`SliderBank`].
+    pub const DEFAULT_SLIDER_WIDTH: f32 = 24.0;
+
+    /// The default space between the sliders of a [`SliderBank`].
+    pub const DEFAULT_SPACING: f32 = 8.0;
+
+    /// The default height of a [`SliderBank`].
+    pub const DEFAULT_HEIGHT: f32 = 160.0;
+
+    /// The default amount of intervals between the ticks of a [`SliderBank`].
+    pub const DEFAULT_TICKS: usize = 4;
+
+    /// The height of the handle of a slider.
+    const HANDLE_HEIGHT: f32 = 10.0;
+
+    /// The width of the rail of a slider.
+    const RAIL_WIDTH: f32 = 4.0;
+
+    /// Creates a new [`SliderBank`] with a slider for every value in the given range, and the
+    /// message to produce with the index and the new value of a slider that moved.
+    pub fn new(range: RangeInclusive<f32>, values: &'a [f32], on_change: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        Self {
+            values,
+            range,
+            step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            labels: Vec::new(),
+            link: None,
+            ticks: Self::DEFAULT_TICKS,
+            width: Length::Shrink,
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            slider_width: Self::DEFAULT_SLIDER_WIDTH,
+            spacing: Self::DEFAULT_SPACING,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the step every value is rounded to.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = (step > 0.0).then_some(step);
+        self
+    }
+
+    /// Sets the message produced once a slider is released.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the labels drawn below the sliders, like the frequency of every band.
+    ///
+    /// Sliders without a label are left blank.
+    pub fn labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Links the sliders of the [`SliderBank`], so dragging one nudges its neighbors.
+    ///
+    /// The neighbors move by the same amount as the dragged slider, scaled by the given strength,
+    /// between `0.0` and `1.0`, once for every slider between them.
+    pub fn linked(mut self, strength: f32) -> Self {
+        self.link = Some(strength.clamp(0.0, 1.0)).filter(|strength| *strength > 0.0);
+        self
+    }
+
+    /// Sets the amount of intervals between the ticks drawn across the [`SliderBank`].
+    ///
+    /// Zero draws no ticks.
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets the width of the [`SliderBank`].
+    ///
+    /// By default, it is as wide as its sliders, which spread out when it is wider.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`SliderBank`], including its labels.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the width of a slider of the [`SliderBank`].
+    pub fn slider_width(mut self, slider_width: impl Into<Pixels>) -> Self {
+        self.slider_width = slider_width.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the space between the sliders of the [`SliderBank`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0.max(0.0);
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the labels.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`SliderBank`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`SliderBank`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the height of the row of labels, or zero without labels.
+    fn label_height(&self, renderer: &Renderer) -> f32 {
+        if self.labels.is_empty() {
+            return 0.0;
+        }
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(text::LineHeight::default().to_absolute(size)) + self.spacing
+    }
+
+    /// Returns the bounds of the column of every slider, above the labels.
+    fn columns(&self, bounds: Rectangle, renderer: &Renderer) -> Vec<Rectangle> {
+        let count = self.values.len();
+        let width = (bounds.width - self.spacing * count.saturating_sub(1) as f32) / count.max(1) as f32;
+        let height = (bounds.height - self.label_height(renderer)).max(0.0);
+
+        (0..count)
+            .map(|index| Rectangle {
+                x: bounds.x + index as f32 * (width + self.spacing),
+                y: bounds.y,
+                width,
+                height,
+            })
+            .collect()
+    }
+
+    /// Returns the vertical span traveled by the center of a handle in the given column.
+    fn track(&self, column: Rectangle) -> (f32, f32) {
+        let top = column.y + Self::HANDLE_HEIGHT / 2.0;
+        let bottom = (column.y + column.height - Self::HANDLE_HEIGHT / 2.0).max(top);
+
+        (top, bottom)
+    }
+
+    /// Returns the vertical position of the given value in the given column.
+    fn position_of(&self, column: Rectangle, value: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let (top, bottom) = self.track(column);
+        let progress = if end > start {
+            ((value - start) / (end - start)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        bottom - progress * (bottom - top)
+    }
+
+    /// Returns the value at the given vertical position in the given column, rounded to the step.
+    fn value_at(&self, column: Rectangle, y: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let (top, bottom) = self.track(column);
+        let progress = if bottom > top {
+            ((bottom - y) / (bottom - top)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        self.round(start + progress * (end - start))
+    }
+
+    /// Rounds the given value to the step, keeping it in the range.
+    fn round(&self, value: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let value = match self.step {
+            Some(step) => start + ((value - start) / step).round() * step,
+            None => value,
+        };
+
+        value.clamp(start, end.max(start))
+    }
+
+    /// Moves the slider at the given index to the given value, nudging its neighbors when
+    /// linked.
+    fn change(&self, index: usize, value: f32, shell: &mut Shell<'_, Message>) {
+        let Some(current) = self.values.get(index) else {
+            return;
+        };
+
+        let delta = value - current;
+
+        if delta == 0.0 {
+            return;
+        }
+
+        shell.publish((self.on_change)(index, value));
+
+        let Some(strength) = self.link else {
+            return;
+        };
+
+        for (neighbor, current) in self.values.iter().enumerate() {
+            let distance = neighbor.abs_diff(index);
+
+            if distance == 0 {
+                continue;
+            }
+
+            let nudged = self.round(current + delta * strength.powi(distance as i32));
+
+            if nudged != *current {
+                shell.publish((self.on_change)(neighbor, nudged));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    dragged: Option<usize>,
+    hovered: Option<usize>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for SliderBank<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let count = self.values.len() as f32;
+        let intrinsic = Size::new(
+            (count * self.slider_width + (count - 1.0) * self.spacing).max(0.0),
+            Self::DEFAULT_HEIGHT,
+        );
+
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let columns = self.columns(layout.bounds(), renderer);
+        let column_at = |position: Point| columns.iter().position(|column| column.contains(position));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position()
+                    && let Some(index) = column_at(position)
+                {
+                    state.dragged = Some(index);
+                    self.change(index, self.value_at(columns[index], position.y), shell);
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let position = cursor.position();
+
+                // A dragged slider follows the cursor, even outside of its column.
+                if let Some(index) = state.dragged
+                    && let Some(position) = position
+                {
+                    self.change(index, self.value_at(columns[index], position.y), shell);
+                    shell.capture_event();
+                }
+
+                let hovered = position.and_then(column_at);
+
+                if state.hovered != hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.dragged.take().is_some() {
+                    if let Some(on_release) = &self.on_release {
+                        shell.publish(on_release.clone());
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.dragged.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let is_over_slider = cursor.position().is_some_and(|position| {
+            self.columns(layout.bounds(), renderer)
+                .iter()
+                .any(|column| column.contains(position))
+        });
+
+        if is_over_slider {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let columns = self.columns(bounds, renderer);
+        let hovered = state.hovered.filter(|_| cursor.is_over(bounds));
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        // The ticks span every slider, on the track of the first one.
+        if let Some(first) = columns.first()
+            && self.ticks > 0
+        {
+            let color = theme.style(&self.class, Status::Active).tick_color;
+
+            for tick in 0..=self.ticks {
+                let value = start + (end - start) * tick as f32 / self.ticks as f32;
+                let y = self.position_of(*first, value).round();
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(Point::new(bounds.x, y), Size::new(bounds.width, 1.0)),
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+        }
+
+        for (index, (column, value)) in columns.iter().zip(self.values).enumerate() {
+            let status = if state.dragged == Some(index) {
+                Status::Dragged
+            } else if hovered == Some(index) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let style = theme.style(&self.class, status);
+            let (top, bottom) = self.track(*column);
+            let rail = Rectangle {
+                x: column.center_x() - Self::RAIL_WIDTH / 2.0,
+                y: top,
+                width: Self::RAIL_WIDTH,
+                height: bottom - top,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: rail,
+                    border: border::rounded(Self::RAIL_WIDTH / 2.0),
+                    ..renderer::Quad::default()
+                },
+                style.rail,
+            );
+
+            // The rail fills from zero when the range spans it, like the gain of a band.
+            let handle_y = self.position_of(*column, *value);
+            let origin_y = self.position_of(*column, 0.0_f32.clamp(start, end.max(start)));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: handle_y.min(origin_y),
+                        height: (handle_y - origin_y).abs(),
+                        ..rail
+                    },
+                    border: border::rounded(Self::RAIL_WIDTH / 2.0),
+                    ..renderer::Quad::default()
+                },
+                style.fill,
+            );
+
+            let handle_width = self.slider_width.min(column.width);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: column.center_x() - handle_width / 2.0,
+                        y: handle_y - Self::HANDLE_HEIGHT / 2.0,
+                        width: handle_width,
+                        height: Self::HANDLE_HEIGHT,
+                    },
+                    border: style.handle_border,
+                    ..renderer::Quad::default()
+                },
+                style.handle,
+            );
+
+            let Some(label) = self.labels.get(index).filter(|label| !label.is_empty()) else {
+                continue;
+            };
+
+            let label_bounds = Rectangle {
+                y: column.y + column.height + self.spacing,
+                height: (bounds.y + bounds.height - column.y - column.height - self.spacing).max(0.0),
+                ..*column
+            };
+
+            renderer.fill_text(
+                Text {
+                    content: label.clone(),
+                    bounds: label_bounds.size(),
+                    size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                    line_height: text::LineHeight::default(),
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                label_bounds.center(),
+                style.text_color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SliderBank<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(slider_bank: SliderBank<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(slider_bank)
+    }
+}
+
+/// The possible status of a slider of a [`SliderBank`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The slider is idle.
+    Active,
+    /// The slider is hovered.
+    Hovered,
+    /// The slider is being dragged.
+    Dragged,
+}
+
+/// The appearance of a slider of a [`SliderBank`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the rail.
+    pub rail: Background,
+    /// The [`Background`] of the part of the rail between zero and the value.
+    pub fill: Background,
+    /// The [`Background`] of the handle.
+    pub handle: Background,
+    /// The [`Border`] of the handle.
+    pub handle_border: Border,
+    /// The [`Color`] of the ticks across the sliders.
+    pub tick_color: Color,
+    /// The [`Color`] of the labels.
+    pub text_color: Color,
+}
+
+/// The theme catalog of a [`SliderBank`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`SliderBank`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`SliderBank`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        rail: palette.background.strong.color.into(),
+        fill: palette.primary.base.color.into(),
+        handle: palette.primary.base.color.into(),
+        handle_border: Border {
+            radius: 2.0.into(),
+            ..Border::default()
+        },
+        tick_color: palette.background.weak.color,
+        text_color: palette.background.base.text,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            handle: palette.primary.strong.color.into(),
+            ..active
+        },
+        Status::Dragged => Style {
+            fill: palette.primary.strong.color.into(),
+            handle: palette.primary.strong.color.into(),
+            handle_border: Border {
+                color: palette.primary.weak.color,
+                width: 2.0,
+                ..active.handle_border
+            },
+            ..active
+        },
+    }
+}
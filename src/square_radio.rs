@@ -16,6 +16,7 @@ use iced_core::mouse;
 use iced_core::mouse::Button;
 use iced_core::renderer;
 use iced_core::text;
+use iced_core::touch;
 use iced_core::widget::Tree;
 use iced_core::widget::Widget;
 use iced_core::widget::tree;
@@ -258,7 +259,9 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
-        if let iced_core::Event::Mouse(mouse::Event::ButtonPressed(Button::Left)) = event
+        // Touch presses are handled too, since they are all there is on mobile browsers.
+        if let iced_core::Event::Mouse(mouse::Event::ButtonPressed(Button::Left))
+        | iced_core::Event::Touch(touch::Event::FingerPressed { .. }) = event
             && cursor.is_over(layout.bounds())
         {
             shell.publish(self.on_click.clone());
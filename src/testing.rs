@@ -0,0 +1,646 @@
+//! Drive the widgets of the crate without a window, to test the interactions of an application.
+//!
+//! A [`Harness`] lays out an [`Element`] with the headless `()` renderer, feeds it synthetic
+//! events, and keeps the messages it publishes. Overlays, like the menu of a
+//! [`MultiPickList`], receive the events before the widget below them, just like in a window.
+//!
+//! Hosted with a [`Recorder`] instead, an [`Element`] can be drawn too, and the [`Primitive`]s
+//! it draws compared with the expected ones, like the rows a menu shows and where their
+//! checkboxes are.
+//!
+//! [`MultiPickList`]: crate::MultiPickList
+use iced_core::Background;
+use iced_core::Border;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Transformation;
+use iced_core::Vector;
+use iced_core::alignment;
+use iced_core::clipboard;
+use iced_core::image;
+use iced_core::keyboard;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::text::Paragraph;
+use iced_core::time::Instant;
+use iced_core::widget::Tree;
+use iced_core::window;
+
+/// A headless host of an [`Element`], feeding it events and collecting its messages.
+///
+/// ```
+/// use iced_ext::MultiPickList;
+/// use iced_ext::testing::Harness;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Message {
+///     Toggled(&'static str),
+/// }
+///
+/// let fruits = ["Apple", "Banana", "Cherry"];
+/// let pick_list = MultiPickList::new(&fruits[..], &[][..], Message::Toggled).width(200);
+/// let mut harness: Harness<'_, Message> = Harness::new(pick_list);
+///
+/// harness.click((10.0, 10.0));
+/// assert!(harness.has_overlay());
+///
+/// // A click outside of the menu closes it, without toggling anything.
+/// harness.click((400.0, 500.0));
+/// assert!(!harness.has_overlay());
+/// assert!(harness.messages().is_empty());
+/// ```
+pub struct Harness<'a, Message, Theme = iced_core::Theme, Renderer = ()> {
+    element: Element<'a, Message, Theme, Renderer>,
+    tree: Tree,
+    renderer: Renderer,
+    size: Size,
+    cursor: mouse::Cursor,
+    messages: Vec<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> Harness<'a, Message, Theme, Renderer>
+where
+    Renderer: iced_core::Renderer,
+{
+    /// The default size of the window of a [`Harness`].
+    pub const DEFAULT_SIZE: Size = Size::new(800.0, 600.0);
+
+    /// Creates a new [`Harness`] hosting the given [`Element`].
+    pub fn new(element: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self
+    where
+        Renderer: Default,
+    {
+        Self::with_renderer(element, Renderer::default())
+    }
+
+    /// Creates a new [`Harness`] hosting the given [`Element`] with the given renderer, like
+    /// a [`Recorder`] to inspect what it draws.
+    pub fn with_renderer(element: impl Into<Element<'a, Message, Theme, Renderer>>, renderer: Renderer) -> Self {
+        let element = element.into();
+        let tree = Tree::new(element.as_widget());
+
+        Self {
+            element,
+            tree,
+            renderer,
+            size: Self::DEFAULT_SIZE,
+            cursor: mouse::Cursor::Unavailable,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Sets the size of the window the [`Element`] is laid out in.
+    pub fn size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Replaces the hosted [`Element`], like a new `view` after the application changed,
+    /// keeping the state of the widgets that did not change.
+    pub fn rebuild(&mut self, element: impl Into<Element<'a, Message, Theme, Renderer>>) {
+        self.element = element.into();
+        self.tree.diff(self.element.as_widget());
+    }
+
+    fn layout(&mut self) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, self.size);
+
+        self.element
+            .as_widget_mut()
+            .layout(&mut self.tree, &self.renderer, &limits)
+    }
+
+    /// Feeds the given [`Event`] to the [`Element`] and its overlay, returning whether it
+    /// was captured.
+    pub fn event(&mut self, event: Event) -> bool {
+        let node = self.layout();
+        let mut shell = Shell::new(&mut self.messages);
+        let viewport = Rectangle::with_size(self.size);
+
+        if let Some(mut overlay) = self.element.as_widget_mut().overlay(
+            &mut self.tree,
+            Layout::new(&node),
+            &self.renderer,
+            &viewport,
+            Vector::ZERO,
+        ) {
+            let overlay_node = overlay.as_overlay_mut().layout(&self.renderer, self.size);
+
+            overlay.as_overlay_mut().update(
+                &event,
+                Layout::new(&overlay_node),
+                self.cursor,
+                &self.renderer,
+                &mut clipboard::Null,
+                &mut shell,
+            );
+        }
+
+        // Like in a window, the events captured by the overlay do not reach the widget below it.
+        if !shell.is_event_captured() {
+            self.element.as_widget_mut().update(
+                &mut self.tree,
+                &event,
+                Layout::new(&node),
+                self.cursor,
+                &self.renderer,
+                &mut clipboard::Null,
+                &mut shell,
+                &viewport,
+            );
+        }
+
+        shell.is_event_captured()
+    }
+
+    /// Moves the cursor to the given position.
+    pub fn move_cursor(&mut self, position: impl Into<Point>) {
+        let position = position.into();
+
+        self.cursor = mouse::Cursor::Available(position);
+        self.event(Event::Mouse(mouse::Event::CursorMoved { position }));
+    }
+
+    /// Moves the cursor to the given position and clicks the left button there.
+    pub fn click(&mut self, position: impl Into<Point>) {
+        self.move_cursor(position);
+        self.event(Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)));
+        self.event(Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)));
+    }
+
+    /// Scrolls the given amount of lines at the position of the cursor.
+    pub fn scroll(&mut self, lines: f32) {
+        self.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { x: 0.0, y: lines },
+        }));
+    }
+
+    /// Presses and releases the given key, with the given modifiers.
+    pub fn press_key(&mut self, key: impl Into<keyboard::Key>, modifiers: keyboard::Modifiers) {
+        let key = key.into();
+        let text = match &key {
+            keyboard::Key::Character(character) if !modifiers.command() => Some(character.clone()),
+            _ => None,
+        };
+        let physical_key = keyboard::key::Physical::Unidentified(keyboard::key::NativeCode::Unidentified);
+
+        self.event(Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            modified_key: key.clone(),
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers,
+            text,
+            repeat: false,
+        }));
+        self.event(Event::Keyboard(keyboard::Event::KeyReleased {
+            key: key.clone(),
+            modified_key: key,
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers,
+        }));
+    }
+
+    /// Presses and releases the key of every character of the given text.
+    pub fn type_text(&mut self, text: &str) {
+        for character in text.chars() {
+            self.press_key(
+                keyboard::Key::Character(character.to_string().into()),
+                keyboard::Modifiers::default(),
+            );
+        }
+    }
+
+    /// Requests a redraw at the given instant, advancing animations and delays.
+    pub fn redraw_at(&mut self, now: Instant) {
+        self.event(Event::Window(window::Event::RedrawRequested(now)));
+    }
+
+    /// Returns whether the [`Element`] currently shows an overlay, like an open menu.
+    pub fn has_overlay(&mut self) -> bool {
+        let node = self.layout();
+        let viewport = Rectangle::with_size(self.size);
+
+        self.element
+            .as_widget_mut()
+            .overlay(
+                &mut self.tree,
+                Layout::new(&node),
+                &self.renderer,
+                &viewport,
+                Vector::ZERO,
+            )
+            .is_some()
+    }
+
+    /// Draws the [`Element`] and its overlay with the given [`Theme`], like a window does on
+    /// every frame.
+    ///
+    /// [`Theme`]: iced_core::Theme
+    pub fn draw(&mut self, theme: &Theme) {
+        let node = self.layout();
+        let viewport = Rectangle::with_size(self.size);
+        let style = renderer::Style {
+            text_color: Color::BLACK,
+        };
+
+        self.element.as_widget().draw(
+            &self.tree,
+            &mut self.renderer,
+            theme,
+            &style,
+            Layout::new(&node),
+            self.cursor,
+            &viewport,
+        );
+
+        if let Some(mut overlay) = self.element.as_widget_mut().overlay(
+            &mut self.tree,
+            Layout::new(&node),
+            &self.renderer,
+            &viewport,
+            Vector::ZERO,
+        ) {
+            let overlay_node = overlay.as_overlay_mut().layout(&self.renderer, self.size);
+
+            overlay.as_overlay().draw(
+                &mut self.renderer,
+                theme,
+                &style,
+                Layout::new(&overlay_node),
+                self.cursor,
+            );
+        }
+    }
+
+    /// Returns the renderer of the [`Harness`].
+    pub fn renderer(&self) -> &Renderer {
+        &self.renderer
+    }
+
+    /// Returns the renderer of the [`Harness`] mutably, like to take what a [`Recorder`] drew.
+    pub fn renderer_mut(&mut self) -> &mut Renderer {
+        &mut self.renderer
+    }
+
+    /// Returns the messages published so far.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Takes the messages published so far, to assert on them or feed them to the application.
+    pub fn take_messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+}
+
+/// Something drawn by an [`Element`], as recorded by a [`Recorder`].
+///
+/// Positions are absolute, with the translations of scrollables and overlays applied.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Primitive {
+    /// A quad, like the background of a row or a checkbox.
+    Quad {
+        /// The bounds of the quad.
+        bounds: Rectangle,
+        /// The border of the quad.
+        border: Border,
+        /// The background of the quad.
+        background: Background,
+    },
+    /// A text shaped when drawn, like a checkmark or an arrow.
+    Text {
+        /// The content of the text.
+        content: String,
+        /// The position of the text, relative to its alignment.
+        position: Point,
+        /// The bounds the text is laid out in.
+        bounds: Size,
+        /// The size of the text.
+        size: Pixels,
+        /// The color of the text.
+        color: Color,
+        /// The bounds the text is clipped to.
+        clip_bounds: Rectangle,
+    },
+    /// A paragraph shaped ahead of time, like the label of an option.
+    Paragraph {
+        /// The content of the paragraph, with the texts of its spans joined.
+        content: String,
+        /// The position of the paragraph, relative to its alignment.
+        position: Point,
+        /// The size of the shaped paragraph.
+        size: Size,
+        /// The color of the paragraph.
+        color: Color,
+        /// The bounds the paragraph is clipped to.
+        clip_bounds: Rectangle,
+    },
+}
+
+/// A renderer recording the [`Primitive`]s drawn with it, and drawing them with another
+/// renderer, which is the headless `()` renderer by default.
+#[derive(Debug, Default)]
+pub struct Recorder<Renderer = ()> {
+    renderer: Renderer,
+    primitives: Vec<Primitive>,
+    translations: Vec<Vector>,
+}
+
+impl<Renderer> Recorder<Renderer> {
+    /// Creates a new [`Recorder`] drawing with the given renderer.
+    pub fn new(renderer: Renderer) -> Self {
+        Self {
+            renderer,
+            primitives: Vec::new(),
+            translations: Vec::new(),
+        }
+    }
+
+    /// Returns the [`Primitive`]s recorded so far, in the order they were drawn.
+    pub fn primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+
+    /// Takes the [`Primitive`]s recorded so far, to compare them with the expected ones.
+    pub fn take(&mut self) -> Vec<Primitive> {
+        std::mem::take(&mut self.primitives)
+    }
+
+    fn translation(&self) -> Vector {
+        self.translations.last().copied().unwrap_or(Vector::ZERO)
+    }
+}
+
+impl<Renderer> iced_core::Renderer for Recorder<Renderer>
+where
+    Renderer: iced_core::Renderer,
+{
+    fn start_layer(&mut self, bounds: Rectangle) {
+        self.renderer.start_layer(bounds);
+    }
+
+    fn end_layer(&mut self) {
+        self.renderer.end_layer();
+    }
+
+    fn start_transformation(&mut self, transformation: Transformation) {
+        self.translations
+            .push(self.translation() + transformation.translation());
+        self.renderer.start_transformation(transformation);
+    }
+
+    fn end_transformation(&mut self) {
+        self.translations.pop();
+        self.renderer.end_transformation();
+    }
+
+    fn fill_quad(&mut self, quad: renderer::Quad, background: impl Into<Background>) {
+        let background = background.into();
+
+        self.primitives.push(Primitive::Quad {
+            bounds: quad.bounds + self.translation(),
+            border: quad.border,
+            background,
+        });
+        self.renderer.fill_quad(quad, background);
+    }
+
+    fn reset(&mut self, new_bounds: Rectangle) {
+        self.primitives.clear();
+        self.translations.clear();
+        self.renderer.reset(new_bounds);
+    }
+
+    fn allocate_image(
+        &mut self,
+        handle: &image::Handle,
+        callback: impl FnOnce(Result<image::Allocation, image::Error>) + Send + 'static,
+    ) {
+        self.renderer.allocate_image(handle, callback);
+    }
+}
+
+impl<Renderer> text::Renderer for Recorder<Renderer>
+where
+    Renderer: text::Renderer,
+{
+    type Font = Renderer::Font;
+    type Paragraph = RecordedParagraph<Renderer::Paragraph>;
+    type Editor = Renderer::Editor;
+
+    const ICON_FONT: Self::Font = Renderer::ICON_FONT;
+    const CHECKMARK_ICON: char = Renderer::CHECKMARK_ICON;
+    const ARROW_DOWN_ICON: char = Renderer::ARROW_DOWN_ICON;
+    const SCROLL_UP_ICON: char = Renderer::SCROLL_UP_ICON;
+    const SCROLL_DOWN_ICON: char = Renderer::SCROLL_DOWN_ICON;
+    const SCROLL_LEFT_ICON: char = Renderer::SCROLL_LEFT_ICON;
+    const SCROLL_RIGHT_ICON: char = Renderer::SCROLL_RIGHT_ICON;
+    const ICED_LOGO: char = Renderer::ICED_LOGO;
+
+    fn default_font(&self) -> Self::Font {
+        self.renderer.default_font()
+    }
+
+    fn default_size(&self) -> Pixels {
+        self.renderer.default_size()
+    }
+
+    fn fill_paragraph(&mut self, paragraph: &Self::Paragraph, position: Point, color: Color, clip_bounds: Rectangle) {
+        let translation = self.translation();
+
+        self.primitives.push(Primitive::Paragraph {
+            content: paragraph.content.clone(),
+            position: position + translation,
+            size: paragraph.min_bounds(),
+            color,
+            clip_bounds: clip_bounds + translation,
+        });
+        self.renderer
+            .fill_paragraph(&paragraph.raw, position, color, clip_bounds);
+    }
+
+    fn fill_editor(&mut self, editor: &Self::Editor, position: Point, color: Color, clip_bounds: Rectangle) {
+        self.renderer.fill_editor(editor, position, color, clip_bounds);
+    }
+
+    fn fill_text(
+        &mut self,
+        text: text::Text<String, Self::Font>,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        let translation = self.translation();
+
+        self.primitives.push(Primitive::Text {
+            content: text.content.clone(),
+            position: position + translation,
+            bounds: text.bounds,
+            size: text.size,
+            color,
+            clip_bounds: clip_bounds + translation,
+        });
+        self.renderer.fill_text(text, position, color, clip_bounds);
+    }
+}
+
+/// A paragraph shaped with a [`Recorder`], keeping its content so it can be recorded when
+/// drawn. The paragraphs of a renderer, like the headless one, do not keep it themselves.
+#[derive(Debug, Clone, Default)]
+pub struct RecordedParagraph<Paragraph> {
+    content: String,
+    raw: Paragraph,
+}
+
+impl<P> RecordedParagraph<P> {
+    /// Returns the content of the [`RecordedParagraph`].
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+impl<P> text::Paragraph for RecordedParagraph<P>
+where
+    P: text::Paragraph,
+{
+    type Font = P::Font;
+
+    fn with_text(text: text::Text<&str, Self::Font>) -> Self {
+        Self {
+            content: text.content.to_owned(),
+            raw: P::with_text(text),
+        }
+    }
+
+    fn with_spans<Link>(text: text::Text<&[text::Span<'_, Link, Self::Font>], Self::Font>) -> Self {
+        Self {
+            content: text.content.iter().map(|span| span.text.as_ref()).collect(),
+            raw: P::with_spans(text),
+        }
+    }
+
+    fn resize(&mut self, new_bounds: Size) {
+        self.raw.resize(new_bounds);
+    }
+
+    fn compare(&self, text: text::Text<(), Self::Font>) -> text::Difference {
+        self.raw.compare(text)
+    }
+
+    fn size(&self) -> Pixels {
+        self.raw.size()
+    }
+
+    fn font(&self) -> Self::Font {
+        self.raw.font()
+    }
+
+    fn line_height(&self) -> text::LineHeight {
+        self.raw.line_height()
+    }
+
+    fn align_x(&self) -> text::Alignment {
+        self.raw.align_x()
+    }
+
+    fn align_y(&self) -> alignment::Vertical {
+        self.raw.align_y()
+    }
+
+    fn wrapping(&self) -> text::Wrapping {
+        self.raw.wrapping()
+    }
+
+    fn shaping(&self) -> text::Shaping {
+        self.raw.shaping()
+    }
+
+    fn bounds(&self) -> Size {
+        self.raw.bounds()
+    }
+
+    fn min_bounds(&self) -> Size {
+        self.raw.min_bounds()
+    }
+
+    fn hit_test(&self, point: Point) -> Option<text::Hit> {
+        self.raw.hit_test(point)
+    }
+
+    fn hit_span(&self, point: Point) -> Option<usize> {
+        self.raw.hit_span(point)
+    }
+
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle> {
+        self.raw.span_bounds(index)
+    }
+
+    fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
+        self.raw.grapheme_position(line, index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_core::Theme;
+    use iced_core::time::Duration;
+
+    use super::Harness;
+    use super::Primitive;
+    use super::Recorder;
+    use crate::MultiPickList;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggled(&'static str),
+    }
+
+    const FRUITS: [&str; 3] = ["Apple", "Banana", "Cherry"];
+
+    #[test]
+    fn clicking_an_option_toggles_it() {
+        let pick_list = MultiPickList::new(&FRUITS[..], &[][..], Message::Toggled)
+            .width(200)
+            .menu_animation(Duration::ZERO);
+        let mut harness: Harness<'_, Message, Theme, Recorder> = Harness::with_renderer(pick_list, Recorder::default());
+
+        assert!(!harness.has_overlay());
+
+        harness.click((10.0, 10.0));
+        assert!(harness.has_overlay());
+        assert!(harness.take_messages().is_empty());
+
+        harness.draw(&Theme::Light);
+
+        let banana = harness
+            .renderer_mut()
+            .take()
+            .into_iter()
+            .find_map(|primitive| match primitive {
+                Primitive::Paragraph { content, position, .. } if content == "Banana" => Some(position),
+                _ => None,
+            })
+            .expect("the menu draws every option");
+
+        harness.click(banana);
+        assert_eq!(harness.take_messages(), [Message::Toggled("Banana")]);
+
+        // Picking several options, the menu stays open until a click outside of it.
+        assert!(harness.has_overlay());
+
+        harness.click((400.0, 500.0));
+        assert!(!harness.has_overlay());
+        assert!(harness.take_messages().is_empty());
+    }
+}
@@ -0,0 +1,823 @@
+//! Browse nested rows in columns, like the dependencies of a project or the sizes of a folder.
+//!
+//! A [`TreeTable`] lays the [`Node`]s of a tree out as rows, indented in its first [`Column`]
+//! by their depth. Rows with children can be expanded, and children can be loaded lazily: a
+//! [`Node`] with [`Children::Unloaded`] produces the message of [`TreeTable::on_load`] once
+//! expanded, and shows a loading row until the application replaces them. Sorting a column
+//! sorts every group of siblings, so children stay below their parent.
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use iced_core::Background;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Padding;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Theme;
+use iced_core::Widget;
+use iced_core::alignment;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text;
+use iced_core::touch;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+
+/// A row of a [`TreeTable`], with the rows nested below it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node<T> {
+    /// The value shown in the row.
+    pub value: T,
+    /// The rows nested below the row.
+    pub children: Children<T>,
+}
+
+impl<T> Node<T> {
+    /// Creates a new [`Node`] without children.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            children: Children::None,
+        }
+    }
+
+    /// Creates a new [`Node`] with the given children.
+    pub fn with_children(value: T, children: impl IntoIterator<Item = Node<T>>) -> Self {
+        Self {
+            value,
+            children: Children::Loaded(children.into_iter().collect()),
+        }
+    }
+
+    /// Creates a new [`Node`] with children that are loaded once it is expanded.
+    pub fn unloaded(value: T) -> Self {
+        Self {
+            value,
+            children: Children::Unloaded,
+        }
+    }
+}
+
+/// The children of a [`Node`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Children<T> {
+    /// The [`Node`] cannot be expanded.
+    #[default]
+    None,
+    /// The [`Node`] has children that are not loaded yet.
+    Unloaded,
+    /// The [`Node`] has the given children.
+    Loaded(Vec<Node<T>>),
+}
+
+/// A column of a [`TreeTable`], showing a text for the value of every row.
+pub struct Column<'a, T> {
+    title: String,
+    cell: Box<dyn Fn(&T) -> String + 'a>,
+    compare: Option<Box<dyn Fn(&T, &T) -> Ordering + 'a>>,
+    width: Length,
+    align_x: text::Alignment,
+}
+
+impl<'a, T> Column<'a, T> {
+    /// Creates a new [`Column`] with the given title, and the closure producing the text of
+    /// every row.
+    pub fn new(title: impl Into<String>, cell: impl Fn(&T) -> String + 'a) -> Self {
+        Self {
+            title: title.into(),
+            cell: Box::new(cell),
+            compare: None,
+            width: Length::Fill,
+            align_x: text::Alignment::Default,
+        }
+    }
+
+    /// Makes the [`Column`] sortable with the given comparator, by pressing its title.
+    pub fn sortable(mut self, compare: impl Fn(&T, &T) -> Ordering + 'a) -> Self {
+        self.compare = Some(Box::new(compare));
+        self
+    }
+
+    /// Sets the width of the [`Column`].
+    ///
+    /// Columns of a fixed width are laid out first, and the others share the rest by their fill
+    /// factor.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the texts of the [`Column`], like to the right for sizes.
+    pub fn align_x(mut self, align_x: impl Into<text::Alignment>) -> Self {
+        self.align_x = align_x.into();
+        self
+    }
+}
+
+/// The direction a [`TreeTable`] is sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// From the smallest value to the largest.
+    Ascending,
+    /// From the largest value to the smallest.
+    Descending,
+}
+
+/// Nested rows laid out in columns, with expandable parents and sortable columns.
+pub struct TreeTable<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    roots: &'a [Node<T>],
+    columns: Vec<Column<'a, T>>,
+    on_load: Option<Box<dyn Fn(&T) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(&T) -> Message + 'a>>,
+    width: Length,
+    row_height: Option<f32>,
+    indent: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    loading_text: String,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> TreeTable<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default indentation of a level of a [`TreeTable`].
+    pub const DEFAULT_INDENT: f32 = 16.0;
+
+    /// The default [`Padding`] of a cell of a [`TreeTable`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        bottom: 4.0,
+        right: 8.0,
+        left: 8.0,
+    };
+
+    /// Creates a new [`TreeTable`] with the given top level [`Node`]s, and no columns.
+    pub fn new(roots: &'a [Node<T>]) -> Self {
+        Self {
+            roots,
+            columns: Vec::new(),
+            on_load: None,
+            on_select: None,
+            width: Length::Fill,
+            row_height: None,
+            indent: Self::DEFAULT_INDENT,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            loading_text: String::from("Loading\u{2026}"),
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a [`Column`] to the [`TreeTable`].
+    ///
+    /// The first [`Column`] holds the indentation and the expand arrows.
+    pub fn column(mut self, column: Column<'a, T>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Adds the given columns to the [`TreeTable`].
+    pub fn columns(mut self, columns: impl IntoIterator<Item = Column<'a, T>>) -> Self {
+        self.columns.extend(columns);
+        self
+    }
+
+    /// Sets the message produced when a [`Node`] with [`Children::Unloaded`] is expanded, to load
+    /// its children.
+    pub fn on_load(mut self, on_load: impl Fn(&T) -> Message + 'a) -> Self {
+        self.on_load = Some(Box::new(on_load));
+        self
+    }
+
+    /// Sets the message produced when a row is pressed outside of its expand arrow.
+    ///
+    /// Without it, pressing anywhere on a row expands or collapses it.
+    pub fn on_select(mut self, on_select: impl Fn(&T) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the width of the [`TreeTable`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of every row of the [`TreeTable`], including its header.
+    ///
+    /// By default, rows are as tall as a line of text and their padding.
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = Some(row_height.into().0.max(1.0));
+        self
+    }
+
+    /// Sets the indentation of every level of the [`TreeTable`].
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0.max(0.0);
+        self
+    }
+
+    /// Sets the [`Padding`] of every cell of the [`TreeTable`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TreeTable`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the [`TreeTable`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the text of the row shown below a [`Node`] while its children are loaded.
+    pub fn loading_text(mut self, loading_text: impl Into<String>) -> Self {
+        self.loading_text = loading_text.into();
+        self
+    }
+
+    /// Sets the style of the [`TreeTable`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TreeTable`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn text<C>(&self, content: C, renderer: &Renderer) -> text::Text<C, Renderer::Font> {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        text::Text {
+            content,
+            bounds: Size::INFINITE,
+            size,
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::default(),
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    fn resolve_row_height(&self, renderer: &Renderer) -> f32 {
+        self.row_height.unwrap_or_else(|| {
+            let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+            f32::from(text::LineHeight::default().to_absolute(size)) + self.padding.y()
+        })
+    }
+
+    /// Returns the width of every column, sharing the width left by the fixed ones.
+    fn widths(&self, width: f32) -> Vec<f32> {
+        let fixed: f32 = self
+            .columns
+            .iter()
+            .filter_map(|column| match column.width {
+                Length::Fixed(width) => Some(width),
+                _ => None,
+            })
+            .sum();
+        let portions: f32 = self
+            .columns
+            .iter()
+            .map(|column| f32::from(column.width.fill_factor()))
+            .sum();
+        let remaining = (width - fixed).max(0.0);
+
+        self.columns
+            .iter()
+            .map(|column| match column.width {
+                Length::Fixed(width) => width,
+                _ if portions > 0.0 => remaining * f32::from(column.width.fill_factor()) / portions,
+                _ => 0.0,
+            })
+            .collect()
+    }
+
+    /// Returns the index of the column under the given horizontal offset.
+    fn column_at(&self, widths: &[f32], x: f32) -> Option<usize> {
+        let mut end = 0.0;
+
+        widths.iter().position(|width| {
+            end += width;
+
+            x < end
+        })
+    }
+
+    /// Returns the visible rows of the [`TreeTable`], from top to bottom.
+    fn rows(&self, state: &State) -> Vec<Row<'a, T>> {
+        let mut rows = Vec::new();
+
+        self.visit(self.roots, &mut Vec::new(), state, &mut rows);
+
+        rows
+    }
+
+    /// Pushes the given siblings and their expanded children, in the order they are sorted in.
+    fn visit(&self, nodes: &'a [Node<T>], path: &mut Vec<usize>, state: &State, rows: &mut Vec<Row<'a, T>>) {
+        let mut order: Vec<usize> = (0..nodes.len()).collect();
+
+        if let Some((column, direction)) = state.sort
+            && let Some(compare) = self.columns.get(column).and_then(|column| column.compare.as_ref())
+        {
+            order.sort_by(|a, b| {
+                let ordering = compare(&nodes[*a].value, &nodes[*b].value);
+
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+
+        for index in order {
+            let node = &nodes[index];
+
+            path.push(index);
+
+            let is_expanded = state.expanded.contains(path.as_slice());
+
+            rows.push(Row {
+                node: Some(node),
+                path: path.clone(),
+                depth: path.len() - 1,
+                is_expanded,
+            });
+
+            if is_expanded {
+                match &node.children {
+                    Children::None => {}
+                    Children::Unloaded => rows.push(Row {
+                        node: None,
+                        path: path.clone(),
+                        depth: path.len(),
+                        is_expanded: false,
+                    }),
+                    Children::Loaded(children) => self.visit(children, path, state, rows),
+                }
+            }
+
+            path.pop();
+        }
+    }
+
+    /// Returns the horizontal offset of the label of a row of the given depth in the first
+    /// column, after its expand arrow.
+    fn leading(&self, depth: usize) -> f32 {
+        (depth + 1) as f32 * self.indent
+    }
+}
+
+/// A visible row of a [`TreeTable`], or the loading row below a [`Node`] without the node.
+struct Row<'a, T> {
+    node: Option<&'a Node<T>>,
+    path: Vec<usize>,
+    depth: usize,
+    is_expanded: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    /// The paths of the expanded nodes, from their top level node.
+    expanded: HashSet<Vec<usize>>,
+    sort: Option<(usize, SortDirection)>,
+    hovered: Option<usize>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for TreeTable<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+        let rows = self.rows(state).len();
+
+        layout::atomic(
+            limits,
+            self.width,
+            (rows + 1) as f32 * self.resolve_row_height(renderer),
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let row_height = self.resolve_row_height(renderer);
+        let position = cursor.position_in(bounds);
+
+        // The header is the row above the first one.
+        let row_at = |position: Point| (position.y / row_height).floor() as usize;
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let hovered = position.map(row_at).and_then(|row| row.checked_sub(1));
+
+                if state.hovered != hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = position else {
+                    return;
+                };
+
+                let widths = self.widths(bounds.width);
+
+                let Some(row) = row_at(position).checked_sub(1) else {
+                    // Sorting cycles from ascending to descending, then back to the given order.
+                    if let Some(column) = self.column_at(&widths, position.x)
+                        && self.columns[column].compare.is_some()
+                    {
+                        state.sort = match state.sort {
+                            Some((sorted, SortDirection::Ascending)) if sorted == column => {
+                                Some((column, SortDirection::Descending))
+                            }
+                            Some((sorted, SortDirection::Descending)) if sorted == column => None,
+                            _ => Some((column, SortDirection::Ascending)),
+                        };
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+
+                    return;
+                };
+
+                let rows = self.rows(state);
+
+                let Some(Row {
+                    node: Some(node),
+                    path,
+                    depth,
+                    is_expanded,
+                }) = rows.get(row)
+                else {
+                    return;
+                };
+
+                let arrow = self.padding.left + self.leading(*depth) - self.indent;
+                let is_on_arrow = (arrow..arrow + self.indent).contains(&position.x);
+                let is_expandable = !matches!(node.children, Children::None);
+
+                if is_expandable && (is_on_arrow || self.on_select.is_none()) {
+                    if *is_expanded {
+                        state.expanded.remove(path);
+                    } else {
+                        state.expanded.insert(path.clone());
+
+                        if matches!(node.children, Children::Unloaded)
+                            && let Some(on_load) = &self.on_load
+                        {
+                            shell.publish(on_load(&node.value));
+                        }
+                    }
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                } else if let Some(on_select) = &self.on_select {
+                    shell.publish(on_select(&node.value));
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let Some(position) = cursor.position_in(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        let row = (position.y / self.resolve_row_height(renderer)).floor() as usize;
+
+        let is_pressable = match row.checked_sub(1) {
+            None => self
+                .column_at(&self.widths(bounds.width), position.x)
+                .is_some_and(|column| self.columns[column].compare.is_some()),
+            Some(row) => self
+                .rows(state)
+                .get(row)
+                .and_then(|row| row.node)
+                .is_some_and(|node| self.on_select.is_some() || !matches!(node.children, Children::None)),
+        };
+
+        if is_pressable {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+        let text_color = style.text_color.unwrap_or(defaults.text_color);
+        let row_height = self.resolve_row_height(renderer);
+        let widths = self.widths(bounds.width);
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let header = Rectangle {
+            height: row_height,
+            ..bounds
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: header,
+                ..renderer::Quad::default()
+            },
+            style.header_background,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(
+                    Point::new(bounds.x, header.y + header.height - 1.0),
+                    Size::new(bounds.width, 1.0),
+                ),
+                ..renderer::Quad::default()
+            },
+            style.divider,
+        );
+
+        // Cells are laid out from the left edge of every column, and clipped to it.
+        let cells = |y: f32| {
+            widths.iter().scan(bounds.x, move |x, width| {
+                let cell = Rectangle::new(Point::new(*x, y), Size::new(*width, row_height));
+
+                *x += width;
+
+                Some(cell)
+            })
+        };
+
+        let fill_cell = |renderer: &mut Renderer,
+                         cell: Rectangle,
+                         leading: f32,
+                         content: String,
+                         align_x: text::Alignment,
+                         color: Color| {
+            let x = match align_x {
+                text::Alignment::Center => cell.center_x() + leading / 2.0,
+                text::Alignment::Right => cell.x + cell.width - self.padding.right,
+                _ => cell.x + self.padding.left + leading,
+            };
+
+            let Some(clip) = cell.intersection(viewport) else {
+                return;
+            };
+
+            renderer.fill_text(
+                text::Text {
+                    bounds: Size::new((cell.width - self.padding.x() - leading).max(0.0), row_height),
+                    align_x,
+                    ..self.text(content, renderer)
+                },
+                Point::new(x, cell.center_y()),
+                color,
+                clip,
+            );
+        };
+
+        for ((column, cell), index) in self.columns.iter().zip(cells(header.y)).zip(0..) {
+            let title = match state.sort {
+                Some((sorted, SortDirection::Ascending)) if sorted == index => format!("{} \u{25B4}", column.title),
+                Some((sorted, SortDirection::Descending)) if sorted == index => format!("{} \u{25BE}", column.title),
+                _ => column.title.clone(),
+            };
+
+            fill_cell(renderer, cell, 0.0, title, column.align_x, style.header_text_color);
+        }
+
+        let rows = self.rows(state);
+        let body = bounds.y + row_height;
+
+        // Only the rows in the viewport are drawn, so large trees stay cheap while scrolled.
+        let first = ((viewport.y - body) / row_height).floor().max(0.0) as usize;
+        let last = (((viewport.y + viewport.height - body) / row_height).ceil().max(0.0) as usize).min(rows.len());
+        let hovered = state.hovered.filter(|_| cursor.is_over(bounds));
+
+        for (index, row) in rows.iter().enumerate().take(last).skip(first) {
+            let y = body + index as f32 * row_height;
+
+            if hovered == Some(index) && row.node.is_some() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(Point::new(bounds.x, y), Size::new(bounds.width, row_height)),
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered_row_background,
+                );
+            }
+
+            let leading = self.leading(row.depth);
+
+            let Some(node) = row.node else {
+                if let Some(cell) = cells(y).next() {
+                    fill_cell(
+                        renderer,
+                        cell,
+                        leading,
+                        self.loading_text.clone(),
+                        text::Alignment::Default,
+                        style.placeholder_color,
+                    );
+                }
+
+                continue;
+            };
+
+            for ((column, cell), index) in self.columns.iter().zip(cells(y)).zip(0..) {
+                if index == 0 && !matches!(node.children, Children::None) {
+                    let arrow = if row.is_expanded { '\u{25BE}' } else { '\u{25B8}' };
+
+                    fill_cell(
+                        renderer,
+                        cell,
+                        leading - self.indent,
+                        arrow.to_string(),
+                        text::Alignment::Default,
+                        text_color,
+                    );
+                }
+
+                let leading = if index == 0 { leading } else { 0.0 };
+
+                fill_cell(
+                    renderer,
+                    cell,
+                    leading,
+                    (column.cell)(&node.value),
+                    column.align_x,
+                    text_color,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<TreeTable<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tree_table: TreeTable<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(tree_table)
+    }
+}
+
+/// The appearance of a [`TreeTable`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the rows, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the rows, if it differs from the default one.
+    pub text_color: Option<Color>,
+    /// The [`Background`] of the header.
+    pub header_background: Background,
+    /// The text [`Color`] of the titles of the header.
+    pub header_text_color: Color,
+    /// The [`Background`] of the hovered row.
+    pub hovered_row_background: Background,
+    /// The [`Color`] of the line below the header.
+    pub divider: Color,
+    /// The [`Color`] of the text of a loading row.
+    pub placeholder_color: Color,
+}
+
+/// The theme catalog of a [`TreeTable`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`TreeTable`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`TreeTable`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: None,
+        text_color: None,
+        header_background: palette.background.weak.color.into(),
+        header_text_color: palette.background.weak.text,
+        hovered_row_background: palette.primary.weak.color.scale_alpha(0.3).into(),
+        divider: palette.background.strong.color,
+        placeholder_color: palette.background.strong.color,
+    }
+}
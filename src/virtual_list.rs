@@ -0,0 +1,295 @@
+//! Lazily build the rows of long lists.
+//!
+//! A [`VirtualList`] only builds the rows that are visible inside its parent
+//! `scrollable` (plus a few rows of overscan), which keeps settings screens with
+//! hundreds of [`SquareRadio`] or checkbox rows fast.
+//!
+//! [`SquareRadio`]: crate::SquareRadio
+use std::ops::Range;
+
+use iced_core::Clipboard;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Vector;
+use iced_core::Widget;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::widget::Operation;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+
+/// A list of fixed height rows that are built on demand.
+///
+/// It is meant to be placed inside a vertical `scrollable`.
+pub struct VirtualList<'a, Message, Theme, Renderer> {
+    count: usize,
+    row_height: f32,
+    width: Length,
+    overscan: usize,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    rows: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// The default amount of rows built beyond each edge of the viewport.
+    pub const DEFAULT_OVERSCAN: usize = 5;
+
+    /// The amount of rows built before the viewport of the [`VirtualList`] is known.
+    const INITIAL_ROWS: usize = 50;
+
+    /// Creates a new [`VirtualList`] with the given amount of rows, the height of
+    /// every row, and the closure building the row at a given index.
+    pub fn new(
+        count: usize,
+        row_height: impl Into<Pixels>,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            count,
+            row_height: row_height.into().0,
+            width: Length::Fill,
+            overscan: Self::DEFAULT_OVERSCAN,
+            view: Box::new(view),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the amount of rows built beyond each edge of the viewport.
+    ///
+    /// By default, it is [`Self::DEFAULT_OVERSCAN`].
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// The range of rows visible in the given viewport.
+    fn visible(&self, bounds: Rectangle, viewport: &Rectangle) -> Range<usize> {
+        if self.row_height <= 0.0 {
+            return 0..self.count;
+        }
+
+        let start = ((viewport.y - bounds.y) / self.row_height).floor().max(0.0) as usize;
+        let end = ((viewport.y + viewport.height - bounds.y) / self.row_height).ceil().max(0.0) as usize;
+
+        start.min(self.count)..end.min(self.count)
+    }
+
+    /// The range of rows to build for the given visible range.
+    fn built(&self, visible: Option<&Range<usize>>) -> Range<usize> {
+        match visible {
+            Some(visible) => {
+                visible.start.saturating_sub(self.overscan)..(visible.end + self.overscan).min(self.count)
+            }
+            None => 0..self.count.min(Self::INITIAL_ROWS),
+        }
+    }
+}
+
+struct State {
+    visible: Option<Range<usize>>,
+    built: Range<usize>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for VirtualList<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            visible: None,
+            built: 0..0,
+        })
+    }
+
+    fn diff(&self, _tree: &mut Tree) {
+        // Rows are reconciled in `layout`, once the visible range is known.
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        let built = self.built(state.visible.as_ref());
+
+        self.rows = built.clone().map(|index| (self.view)(index)).collect();
+
+        let mut previous: Vec<Option<Tree>> = std::mem::take(&mut tree.children).into_iter().map(Some).collect();
+
+        tree.children = self
+            .rows
+            .iter()
+            .zip(built.clone())
+            .map(|(row, index)| {
+                let reused = index
+                    .checked_sub(state.built.start)
+                    .filter(|_| state.built.contains(&index))
+                    .and_then(|offset| previous.get_mut(offset))
+                    .and_then(Option::take);
+
+                match reused {
+                    Some(mut tree) => {
+                        tree.diff(row.as_widget());
+                        tree
+                    }
+                    None => Tree::new(row.as_widget()),
+                }
+            })
+            .collect();
+
+        state.built = built.clone();
+
+        let limits = limits.width(self.width);
+        let row_limits = layout::Limits::new(
+            Size::new(0.0, self.row_height),
+            Size::new(limits.max().width, self.row_height),
+        );
+
+        let mut intrinsic_width: f32 = 0.0;
+
+        let children = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(built)
+            .map(|((row, tree), index)| {
+                let node = row.as_widget_mut().layout(tree, renderer, &row_limits);
+                intrinsic_width = intrinsic_width.max(node.size().width);
+
+                node.move_to((0.0, self.row_height * index as f32))
+            })
+            .collect();
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(intrinsic_width, self.row_height * self.count as f32),
+        );
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let visible = self.visible(layout.bounds(), viewport);
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.visible.as_ref() != Some(&visible) {
+            // Only rebuild when the viewport moves past the overscan.
+            if visible.start < state.built.start || visible.end > state.built.end {
+                shell.invalidate_layout();
+            }
+
+            state.visible = Some(visible);
+        }
+
+        for ((row, tree), layout) in self.rows.iter_mut().zip(&mut tree.children).zip(layout.children()) {
+            row.as_widget_mut()
+                .update(tree, event, layout, cursor, renderer, clipboard, shell, viewport);
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((row, tree), layout) in self.rows.iter().zip(&tree.children).zip(layout.children()) {
+            if layout.bounds().intersects(viewport) {
+                row.as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((row, tree), layout)| {
+                row.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((row, tree), layout) in self.rows.iter_mut().zip(&mut tree.children).zip(layout.children()) {
+                row.as_widget_mut().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.rows, tree, layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<VirtualList<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(list: VirtualList<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
@@ -0,0 +1,690 @@
+//! Paint the available time ranges of every day of the week.
+//!
+//! A [`WeekPlanner`] shows the days of the week side by side, with the hours of the day
+//! running down. Dragging over free time paints a range, dragging over painted time erases
+//! it. The whole [`WeekPlan`] is published once the drag is released, never while painting.
+use iced_core::Background;
+use iced_core::Border;
+use iced_core::Clipboard;
+use iced_core::Color;
+use iced_core::Element;
+use iced_core::Event;
+use iced_core::Layout;
+use iced_core::Length;
+use iced_core::Pixels;
+use iced_core::Point;
+use iced_core::Rectangle;
+use iced_core::Shell;
+use iced_core::Size;
+use iced_core::Theme;
+use iced_core::Widget;
+use iced_core::alignment;
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::text::Paragraph;
+use iced_core::text::{self};
+use iced_core::touch;
+use iced_core::widget::Tree;
+use iced_core::widget::tree;
+
+use crate::schedule_builder::Weekday;
+
+/// The minutes in a day.
+const DAY: u16 = 24 * 60;
+
+/// A range of time in a day, in minutes since midnight, with an exclusive end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimeRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl TimeRange {
+    /// Creates a new [`TimeRange`] between the given minutes, clamped to the day.
+    pub fn new(start: u16, end: u16) -> Self {
+        let (start, end) = (start.min(DAY), end.min(DAY));
+
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+        }
+    }
+
+    /// Returns whether the given minute is in the [`TimeRange`].
+    pub fn contains(&self, minute: u16) -> bool {
+        (self.start..self.end).contains(&minute)
+    }
+
+    /// Returns whether the [`TimeRange`] lasts no time at all.
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+}
+
+/// The painted time ranges of every day of the week, starting on Monday.
+///
+/// The ranges of a day are kept sorted, without overlapping or touching ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WeekPlan {
+    days: [Vec<TimeRange>; 7],
+}
+
+impl WeekPlan {
+    /// Creates a new empty [`WeekPlan`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ranges of the given day.
+    pub fn day(&self, weekday: Weekday) -> &[TimeRange] {
+        &self.days[index(weekday)]
+    }
+
+    /// Returns the ranges of every day, starting on Monday.
+    pub fn days(&self) -> impl Iterator<Item = (Weekday, &[TimeRange])> {
+        Weekday::ALL.into_iter().zip(self.days.iter().map(Vec::as_slice))
+    }
+
+    /// Adds the given range to a day, merging it with the ranges it overlaps or touches.
+    pub fn add(&mut self, weekday: Weekday, range: TimeRange) {
+        if range.is_empty() {
+            return;
+        }
+
+        let day = &mut self.days[index(weekday)];
+        let mut merged = range;
+
+        day.retain(|other| {
+            let is_merged = other.start <= merged.end && merged.start <= other.end;
+
+            if is_merged {
+                merged = TimeRange::new(merged.start.min(other.start), merged.end.max(other.end));
+            }
+
+            !is_merged
+        });
+
+        day.push(merged);
+        day.sort_unstable();
+    }
+
+    /// Removes the given range from a day, splitting the ranges it falls inside of.
+    pub fn remove(&mut self, weekday: Weekday, range: TimeRange) {
+        if range.is_empty() {
+            return;
+        }
+
+        let day = &mut self.days[index(weekday)];
+
+        *day = day
+            .iter()
+            .flat_map(|other| {
+                [
+                    TimeRange::new(other.start, other.end.min(range.start)),
+                    TimeRange::new(other.start.max(range.end), other.end),
+                ]
+            })
+            .filter(|part| !part.is_empty())
+            .collect();
+    }
+
+    /// Removes every range of a day.
+    pub fn clear(&mut self, weekday: Weekday) {
+        self.days[index(weekday)].clear();
+    }
+
+    /// Returns whether the given minute of a day is in one of its ranges.
+    pub fn contains(&self, weekday: Weekday, minute: u16) -> bool {
+        self.day(weekday).iter().any(|range| range.contains(minute))
+    }
+}
+
+fn index(weekday: Weekday) -> usize {
+    Weekday::ALL
+        .iter()
+        .position(|other| *other == weekday)
+        .unwrap_or_default()
+}
+
+/// A grid of the days of the week and the hours of the day, painting a [`WeekPlan`].
+pub struct WeekPlanner<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    plan: &'a WeekPlan,
+    on_change: Box<dyn Fn(WeekPlan) -> Message + 'a>,
+    snap: u16,
+    width: Length,
+    hour_height: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> WeekPlanner<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default interval painted ranges snap to, in minutes.
+    pub const DEFAULT_SNAP: u16 = 30;
+
+    /// The default height of an hour of a [`WeekPlanner`].
+    pub const DEFAULT_HOUR_HEIGHT: f32 = 24.0;
+
+    /// The space between the labels and the grid.
+    const LABEL_SPACING: f32 = 6.0;
+
+    /// Creates a new [`WeekPlanner`] painting the given [`WeekPlan`], and the message to
+    /// produce with the changed one.
+    pub fn new(plan: &'a WeekPlan, on_change: impl Fn(WeekPlan) -> Message + 'a) -> Self {
+        Self {
+            plan,
+            on_change: Box::new(on_change),
+            snap: Self::DEFAULT_SNAP,
+            width: Length::Fill,
+            hour_height: Self::DEFAULT_HOUR_HEIGHT,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the interval painted ranges snap to, in minutes.
+    ///
+    /// It is clamped between a minute and an hour.
+    pub fn snap(mut self, minutes: u16) -> Self {
+        self.snap = minutes.clamp(1, 60);
+        self
+    }
+
+    /// Sets the width of the [`WeekPlanner`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of an hour of the [`WeekPlanner`].
+    pub fn hour_height(mut self, hour_height: impl Into<Pixels>) -> Self {
+        self.hour_height = hour_height.into().0;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the labels.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`WeekPlanner`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`WeekPlanner`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn text<C>(&self, content: C, renderer: &Renderer) -> text::Text<C, Renderer::Font> {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        text::Text {
+            content,
+            bounds: Size::INFINITE,
+            size,
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::default(),
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(text::LineHeight::default().to_absolute(size))
+    }
+
+    /// Returns the bounds of the grid, leaving room for the labels.
+    fn grid(&self, bounds: Rectangle, state: &State) -> Rectangle {
+        Rectangle {
+            x: bounds.x + state.gutter.width,
+            y: bounds.y + state.gutter.height,
+            width: (bounds.width - state.gutter.width).max(0.0),
+            height: 24.0 * self.hour_height,
+        }
+    }
+
+    /// Returns the day and the snapped slot at the given position, clamped to the grid.
+    fn slot_at(&self, grid: Rectangle, position: Point) -> (usize, u16) {
+        let column = ((position.x - grid.x) / (grid.width / 7.0)).floor().clamp(0.0, 6.0) as usize;
+
+        let slots = DAY / self.snap;
+        let minute = (position.y - grid.y) / self.hour_height * 60.0;
+        let slot = (minute / f32::from(self.snap)).floor().clamp(0.0, f32::from(slots - 1)) as u16;
+
+        (column, slot)
+    }
+
+    /// Returns the [`WeekPlan`] after painting or erasing the dragged rectangle.
+    fn apply(&self, drag: &Drag) -> WeekPlan {
+        let mut plan = self.plan.clone();
+        let (days, range) = drag.span(self.snap);
+
+        for day in days {
+            let weekday = Weekday::ALL[day];
+
+            if drag.paint {
+                plan.add(weekday, range);
+            } else {
+                plan.remove(weekday, range);
+            }
+        }
+
+        plan
+    }
+}
+
+/// A rectangle of slots being dragged over the grid.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    anchor: (usize, u16),
+    current: (usize, u16),
+    paint: bool,
+}
+
+impl Drag {
+    /// Returns the days and the range of time covered by the [`Drag`].
+    fn span(&self, snap: u16) -> (std::ops::RangeInclusive<usize>, TimeRange) {
+        let days = self.anchor.0.min(self.current.0)..=self.anchor.0.max(self.current.0);
+        let start = self.anchor.1.min(self.current.1) * snap;
+        let end = (self.anchor.1.max(self.current.1) + 1) * snap;
+
+        (days, TimeRange::new(start, end))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    drag: Option<Drag>,
+    /// The room taken by the labels, left of and above the grid.
+    gutter: Size,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for WeekPlanner<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        let label = Renderer::Paragraph::with_text(self.text("00:00", renderer));
+        let line_height = self.line_height(renderer);
+
+        state.gutter = Size::new(
+            label.min_width() + Self::LABEL_SPACING,
+            line_height + Self::LABEL_SPACING,
+        );
+
+        let intrinsic = Size::new(
+            state.gutter.width + 7.0 * 48.0,
+            state.gutter.height + 24.0 * self.hour_height,
+        );
+
+        layout::Node::new(limits.resolve(self.width, Length::Shrink, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let grid = self.grid(layout.bounds(), state);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(grid) {
+                    let (day, slot) = self.slot_at(grid, position);
+
+                    // Dragging from painted time erases, dragging from free time paints.
+                    let paint = !self.plan.contains(Weekday::ALL[day], slot * self.snap);
+
+                    state.drag = Some(Drag {
+                        anchor: (day, slot),
+                        current: (day, slot),
+                        paint,
+                    });
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    if let Some(position) = cursor.position() {
+                        let current = self.slot_at(grid, position);
+
+                        if current != drag.current {
+                            drag.current = current;
+                            shell.request_redraw();
+                        }
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(drag) = state.drag.take() {
+                    let plan = self.apply(&drag);
+
+                    if plan != *self.plan {
+                        shell.publish((self.on_change)(plan));
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let grid = self.grid(bounds, state);
+        let column_width = grid.width / 7.0;
+
+        let status = if state.drag.is_some() {
+            Status::Dragged
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+        let text_color = style.text_color.unwrap_or(defaults.text_color);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: grid,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        for (day, weekday) in Weekday::ALL.iter().enumerate() {
+            let x = grid.x + day as f32 * column_width;
+
+            renderer.fill_text(
+                text::Text {
+                    bounds: Size::new(column_width, state.gutter.height),
+                    align_x: text::Alignment::Center,
+                    ..self.text(weekday.to_string(), renderer)
+                },
+                Point::new(
+                    x + column_width / 2.0,
+                    bounds.y + (state.gutter.height - Self::LABEL_SPACING) / 2.0,
+                ),
+                text_color,
+                *viewport,
+            );
+
+            if day > 0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(Point::new(x, grid.y), Size::new(1.0, grid.height)),
+                        ..renderer::Quad::default()
+                    },
+                    style.grid_color,
+                );
+            }
+        }
+
+        // Labels sit on the line starting their hour, and only every third one is kept when crammed.
+        let label_every = if self.hour_height >= self.line_height(renderer) {
+            1
+        } else {
+            3
+        };
+
+        for hour in 0..24 {
+            let y = grid.y + hour as f32 * self.hour_height;
+
+            if hour % label_every == 0 {
+                renderer.fill_text(
+                    text::Text {
+                        bounds: Size::new(state.gutter.width, self.hour_height),
+                        ..self.text(format!("{hour:02}:00"), renderer)
+                    },
+                    Point::new(bounds.x, y),
+                    text_color,
+                    *viewport,
+                );
+            }
+
+            if hour > 0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(Point::new(grid.x, y), Size::new(grid.width, 1.0)),
+                        ..renderer::Quad::default()
+                    },
+                    style.grid_color,
+                );
+            }
+        }
+
+        let range_bounds = |day: usize, range: TimeRange| Rectangle {
+            x: grid.x + day as f32 * column_width + 1.0,
+            y: grid.y + f32::from(range.start) / 60.0 * self.hour_height,
+            width: (column_width - 2.0).max(0.0),
+            height: f32::from(range.end - range.start) / 60.0 * self.hour_height,
+        };
+
+        // The dragged rectangle previews its result instead of being drawn over the ranges.
+        let plan = state.drag.as_ref().map(|drag| self.apply(drag));
+        let plan = plan.as_ref().unwrap_or(self.plan);
+
+        for (day, (_, ranges)) in plan.days().enumerate() {
+            for range in ranges {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: range_bounds(day, *range),
+                        border: style.range_border,
+                        ..renderer::Quad::default()
+                    },
+                    style.range,
+                );
+            }
+        }
+
+        if let Some(drag) = &state.drag {
+            let (days, range) = drag.span(self.snap);
+
+            let first = range_bounds(*days.start(), range);
+            let last = range_bounds(*days.end(), range);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: first.union(&last),
+                    border: Border {
+                        color: style.range_border.color,
+                        width: 1.0,
+                        radius: style.range_border.radius,
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() || cursor.is_over(self.grid(layout.bounds(), state)) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<WeekPlanner<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(week_planner: WeekPlanner<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(week_planner)
+    }
+}
+
+/// The status of a [`WeekPlanner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`WeekPlanner`] is idle.
+    Active,
+    /// A range is being painted or erased.
+    Dragged,
+}
+
+/// The appearance of a [`WeekPlanner`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the grid.
+    pub background: Background,
+    /// The [`Border`] of the grid.
+    pub border: Border,
+    /// The [`Color`] of the lines between days and hours.
+    pub grid_color: Color,
+    /// The [`Background`] of a painted range.
+    pub range: Background,
+    /// The [`Border`] of a painted range.
+    pub range_border: Border,
+    /// The text [`Color`] of the labels.
+    pub text_color: Option<Color>,
+}
+
+/// The theme catalog of a [`WeekPlanner`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`WeekPlanner`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`WeekPlanner`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        grid_color: palette.background.weak.color,
+        range: palette.primary.weak.color.into(),
+        range_border: Border {
+            color: palette.primary.strong.color,
+            width: 1.0,
+            radius: 3.0.into(),
+        },
+        text_color: None,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Dragged => Style {
+            range: palette.primary.base.color.into(),
+            ..active
+        },
+    }
+}